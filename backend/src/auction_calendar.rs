@@ -0,0 +1,327 @@
+// Auction Calendar - iCalendar (RFC 5545) feed of sale dates and deadlines
+// Subscribable from Google Calendar / Outlook; UIDs are derived from listing ids so
+// edits to a listing update the existing events instead of duplicating them
+
+use chrono::{Duration, NaiveDate, Utc};
+
+use crate::auctions::{AuctionListing, AuctionStatus};
+
+const PRODID: &str = "-//Auction Intel//Tax Sale Calendar//EN";
+const UID_DOMAIN: &str = "auction-intel";
+
+// How far ahead of a deadline the reminder alarm fires
+const DEADLINE_ALARM: &str = "-P2D";
+
+// Escape TEXT values (RFC 5545 section 3.3.11)
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+// Fold content lines longer than 75 octets (RFC 5545 section 3.1)
+fn fold_line(line: &str, out: &mut String) {
+    let mut width = 0;
+    for ch in line.chars() {
+        let len = ch.len_utf8();
+        if width + len > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(ch);
+        width += len;
+    }
+    out.push_str("\r\n");
+}
+
+fn ics_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").ok()
+}
+
+// payment_deadline is free text: "Same day", "24 hours", "10 days", an ISO date, or "Varies"
+pub fn payment_due_date(listing: &AuctionListing) -> Option<NaiveDate> {
+    let sale = parse_date(&listing.sale_date)?;
+    let text = listing.payment_deadline.trim().to_lowercase();
+    if let Some(date) = parse_date(&text) {
+        return Some(date);
+    }
+    if text == "same day" {
+        return Some(sale);
+    }
+
+    let mut parts = text.split_whitespace();
+    let amount: i64 = parts.next()?.parse().ok()?;
+    match parts.next()? {
+        "day" | "days" => Some(sale + Duration::days(amount)),
+        "hour" | "hours" => Some(sale + Duration::days((amount + 23) / 24)),
+        _ => None,
+    }
+}
+
+struct CalendarEvent {
+    uid: String,
+    date: NaiveDate,
+    summary: String,
+    description: String,
+    url: String,
+    status: &'static str,
+    alarm: bool,
+}
+
+fn listing_title(listing: &AuctionListing) -> String {
+    let title = format!("{}, {} {}", listing.county, listing.state, listing.sale_type);
+    match listing.status_override {
+        Some(status) => format!("[{}] {}", status.label(), title),
+        None => title,
+    }
+}
+
+// VEVENT STATUS for manually overridden listings
+fn event_status(listing: &AuctionListing) -> &'static str {
+    match listing.status_override {
+        Some(AuctionStatus::Cancelled) => "CANCELLED",
+        Some(AuctionStatus::Postponed) => "TENTATIVE",
+        _ => "CONFIRMED",
+    }
+}
+
+fn listing_details(listing: &AuctionListing) -> String {
+    let mut lines = vec![
+        format!("Platform: {} ({})", listing.platform, listing.auction_type),
+        format!("Link: {}", listing.platform_url),
+        format!("Properties: {}", listing.property_count),
+        format!("Deposit: ${:.0}", listing.deposit_required),
+        format!("Registration deadline: {}", listing.registration_deadline),
+        format!("Payment due: {}", listing.payment_deadline),
+    ];
+    if !listing.interest_rate.is_empty() {
+        lines.push(format!("Rate: {}", listing.interest_rate));
+    }
+    if !listing.notes.is_empty() {
+        lines.push(listing.notes.clone());
+    }
+    lines.join("\n")
+}
+
+fn listing_events(listing: &AuctionListing) -> Vec<CalendarEvent> {
+    let mut events = Vec::new();
+    let title = listing_title(listing);
+    let details = listing_details(listing);
+
+    if let Some(date) = parse_date(&listing.sale_date) {
+        events.push(CalendarEvent {
+            uid: format!("{}-sale@{}", listing.id, UID_DOMAIN),
+            date,
+            summary: format!("Auction: {}", title),
+            description: details.clone(),
+            url: listing.platform_url.clone(),
+            status: event_status(listing),
+            alarm: false,
+        });
+    }
+    if let Some(date) = parse_date(&listing.registration_deadline) {
+        events.push(CalendarEvent {
+            uid: format!("{}-registration@{}", listing.id, UID_DOMAIN),
+            date,
+            summary: format!("Registration deadline: {}", title),
+            description: details.clone(),
+            url: listing.platform_url.clone(),
+            status: event_status(listing),
+            alarm: true,
+        });
+    }
+    if let Some(date) = payment_due_date(listing) {
+        events.push(CalendarEvent {
+            uid: format!("{}-payment@{}", listing.id, UID_DOMAIN),
+            date,
+            summary: format!("Payment due: {}", title),
+            description: details,
+            url: listing.platform_url.clone(),
+            status: event_status(listing),
+            alarm: true,
+        });
+    }
+
+    events
+}
+
+// Render the listings as a VCALENDAR document
+pub fn build_calendar(listings: &[AuctionListing], name: &str) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let mut out = String::new();
+
+    for line in [
+        "BEGIN:VCALENDAR",
+        "VERSION:2.0",
+        &format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN",
+        "METHOD:PUBLISH",
+        &format!("X-WR-CALNAME:{}", escape_text(name)),
+        // Ask subscribed clients to refresh a few times a day
+        "REFRESH-INTERVAL;VALUE=DURATION:PT6H",
+        "X-PUBLISHED-TTL:PT6H",
+    ] {
+        fold_line(line, &mut out);
+    }
+
+    for event in listings.iter().flat_map(listing_events) {
+        fold_line("BEGIN:VEVENT", &mut out);
+        fold_line(&format!("UID:{}", event.uid), &mut out);
+        fold_line(&format!("DTSTAMP:{}", stamp), &mut out);
+        fold_line(&format!("DTSTART;VALUE=DATE:{}", ics_date(event.date)), &mut out);
+        fold_line(&format!("DTEND;VALUE=DATE:{}", ics_date(event.date + Duration::days(1))), &mut out);
+        fold_line(&format!("SUMMARY:{}", escape_text(&event.summary)), &mut out);
+        fold_line(&format!("DESCRIPTION:{}", escape_text(&event.description)), &mut out);
+        if !event.url.is_empty() {
+            fold_line(&format!("URL:{}", event.url), &mut out);
+        }
+        fold_line(&format!("STATUS:{}", event.status), &mut out);
+        fold_line("TRANSP:TRANSPARENT", &mut out);
+        if event.alarm && event.status != "CANCELLED" {
+            fold_line("BEGIN:VALARM", &mut out);
+            fold_line("ACTION:DISPLAY", &mut out);
+            fold_line(&format!("DESCRIPTION:{}", escape_text(&event.summary)), &mut out);
+            fold_line(&format!("TRIGGER:{}", DEADLINE_ALARM), &mut out);
+            fold_line("END:VALARM", &mut out);
+        }
+        fold_line("END:VEVENT", &mut out);
+    }
+
+    fold_line("END:VCALENDAR", &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing() -> AuctionListing {
+        AuctionListing {
+            id: "FL-LEE-2027-05".to_string(),
+            state: "FL".to_string(),
+            county: "Lee".to_string(),
+            sale_type: "Tax Lien".to_string(),
+            sale_date: "2027-05-14".to_string(),
+            property_count: 900,
+            deposit_required: 1500.0,
+            registration_deadline: "2027-05-01".to_string(),
+            platform: "County".to_string(),
+            platform_url: "https://lee.example/taxsale".to_string(),
+            auction_type: "Online".to_string(),
+            notes: String::new(),
+            interest_rate: "18%".to_string(),
+            redemption_period: "2 years".to_string(),
+            bidding_method: "Bid down".to_string(),
+            min_bid: "Taxes + fees".to_string(),
+            payment_deadline: "Same day".to_string(),
+            status_override: None,
+        }
+    }
+
+    // Content lines with folding undone (RFC 5545 section 3.1)
+    fn unfold(ics: &str) -> Vec<String> {
+        ics.replace("\r\n ", "").split("\r\n").filter(|l| !l.is_empty()).map(str::to_string).collect()
+    }
+
+    fn property<'a>(lines: &'a [String], name: &str) -> Vec<&'a str> {
+        lines.iter().filter_map(|l| l.strip_prefix(name)?.strip_prefix(':')).collect()
+    }
+
+    #[test]
+    fn escapes_text_values() {
+        assert_eq!(escape_text(r"a,b;c\d"), r"a\,b\;c\\d");
+        assert_eq!(escape_text("one\ntwo\r\nthree"), r"one\ntwo\nthree");
+        // The backslash is escaped first so added escapes aren't doubled
+        assert_eq!(escape_text(r"\,"), r"\\\,");
+    }
+
+    #[test]
+    fn folds_at_75_octets() {
+        let mut out = String::new();
+        fold_line(&"x".repeat(75), &mut out);
+        assert_eq!(out, format!("{}\r\n", "x".repeat(75)));
+
+        for line in ["y".repeat(200), "é".repeat(100), format!("DESCRIPTION:{}", "20% → 30% → 40%; ".repeat(12))] {
+            let mut out = String::new();
+            fold_line(&line, &mut out);
+            assert!(out.ends_with("\r\n"));
+            let physical: Vec<&str> = out.trim_end_matches("\r\n").split("\r\n").collect();
+            assert!(physical.len() > 1);
+            assert!(physical.iter().all(|l| l.len() <= 75), "{:?}", physical);
+            assert!(physical[1..].iter().all(|l| l.starts_with(' ')));
+            assert_eq!(unfold(&out), [line]);
+        }
+    }
+
+    #[test]
+    fn payment_due_date_reads_free_text() {
+        let due = |text: &str| payment_due_date(&AuctionListing { payment_deadline: text.to_string(), ..listing() });
+        let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok();
+        assert_eq!(due("Same day"), date("2027-05-14"));
+        assert_eq!(due("24 hours"), date("2027-05-15"));
+        assert_eq!(due("25 hours"), date("2027-05-16"));
+        assert_eq!(due("10 days"), date("2027-05-24"));
+        assert_eq!(due("1 day"), date("2027-05-15"));
+        assert_eq!(due("2027-06-01"), date("2027-06-01"));
+        assert_eq!(due("Varies"), None);
+        assert_eq!(due("3 weeks"), None);
+        assert_eq!(due(""), None);
+    }
+
+    #[test]
+    fn builds_one_event_per_date() {
+        let ics = build_calendar(&[listing()], "Tax Sales, FL");
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n") && ics.ends_with("END:VCALENDAR\r\n"));
+        let lines = unfold(&ics);
+        assert_eq!(property(&lines, "X-WR-CALNAME"), [r"Tax Sales\, FL"]);
+        assert_eq!(property(&lines, "UID"), [
+            "FL-LEE-2027-05-sale@auction-intel",
+            "FL-LEE-2027-05-registration@auction-intel",
+            "FL-LEE-2027-05-payment@auction-intel",
+        ]);
+        assert_eq!(property(&lines, "DTSTART;VALUE=DATE"), ["20270514", "20270501", "20270514"]);
+        assert_eq!(property(&lines, "DTEND;VALUE=DATE"), ["20270515", "20270502", "20270515"]);
+        assert_eq!(property(&lines, "SUMMARY")[0], r"Auction: Lee\, FL Tax Lien");
+        assert_eq!(property(&lines, "TRIGGER"), [DEADLINE_ALARM, DEADLINE_ALARM]);
+
+        // Every physical line respects the octet limit
+        assert!(ics.split("\r\n").all(|l| l.len() <= 75));
+    }
+
+    #[test]
+    fn varies_payment_has_no_event() {
+        let varies = AuctionListing { payment_deadline: "Varies".to_string(), ..listing() };
+        let lines = unfold(&build_calendar(&[varies], "x"));
+        assert_eq!(property(&lines, "UID").len(), 2);
+        assert!(!lines.iter().any(|l| l.starts_with("SUMMARY:Payment due")));
+    }
+
+    #[test]
+    fn uids_survive_edits() {
+        let before = unfold(&build_calendar(&[listing()], "x"));
+        let edited = AuctionListing {
+            sale_date: "2027-06-11".to_string(),
+            notes: "Moved; see county site".to_string(),
+            status_override: Some(AuctionStatus::Postponed),
+            ..listing()
+        };
+        let after = unfold(&build_calendar(&[edited], "x"));
+        assert_eq!(property(&before, "UID"), property(&after, "UID"));
+        assert_eq!(property(&after, "STATUS"), ["TENTATIVE"; 3]);
+        assert_eq!(property(&after, "SUMMARY")[0], r"Auction: [Postponed] Lee\, FL Tax Lien");
+    }
+
+    #[test]
+    fn cancelled_listings_have_no_alarms() {
+        let cancelled = AuctionListing { status_override: Some(AuctionStatus::Cancelled), ..listing() };
+        let lines = unfold(&build_calendar(&[cancelled], "x"));
+        assert_eq!(property(&lines, "STATUS"), ["CANCELLED"; 3]);
+        assert!(!lines.iter().any(|l| l == "BEGIN:VALARM"));
+    }
+}
@@ -0,0 +1,337 @@
+// Auction Import - Bulk load county sale calendars from CSV or JSON
+// Rows map onto AuctionListing fields by name and are upserted on `id`
+
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use std::collections::HashSet;
+
+use crate::auction_store::AuctionRepository;
+use crate::auctions::{self, AuctionListing, AuctionStatus};
+use crate::payload_format::PayloadFormat;
+
+// Spreadsheet row: every column is optional so missing cells become
+// validation errors instead of failing the whole file
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ImportRow {
+    id: String,
+    state: String,
+    county: String,
+    sale_type: String,
+    sale_date: String,
+    #[serde(deserialize_with = "count_cell")]
+    property_count: i32,
+    #[serde(deserialize_with = "amount_cell")]
+    deposit_required: f64,
+    registration_deadline: String,
+    platform: String,
+    platform_url: String,
+    auction_type: String,
+    notes: String,
+    interest_rate: String,
+    redemption_period: String,
+    bidding_method: String,
+    min_bid: String,
+    payment_deadline: String,
+    #[serde(deserialize_with = "status_cell")]
+    status_override: Option<AuctionStatus>,
+}
+
+// Numeric cells may arrive as JSON numbers or spreadsheet text like "$1,500" or ""
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberCell {
+    Number(f64),
+    Text(String),
+}
+
+fn amount_cell<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    match NumberCell::deserialize(deserializer)? {
+        NumberCell::Number(n) => Ok(n),
+        NumberCell::Text(text) => {
+            let cleaned: String = text.chars().filter(|c| !matches!(c, '$' | ',' | ' ')).collect();
+            if cleaned.is_empty() {
+                return Ok(0.0);
+            }
+            cleaned.parse().map_err(|_| D::Error::custom(format!("'{}' is not a number", text)))
+        }
+    }
+}
+
+fn count_cell<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i32, D::Error> {
+    let value = amount_cell(deserializer)?;
+    if value.fract() != 0.0 || value < i32::MIN as f64 || value > i32::MAX as f64 {
+        return Err(D::Error::custom(format!("'{}' is not a whole number", value)));
+    }
+    Ok(value as i32)
+}
+
+// Blank = no override; otherwise a status label or slug such as "postponed"
+fn status_cell<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<AuctionStatus>, D::Error> {
+    let text = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+    if text.trim().is_empty() {
+        return Ok(None);
+    }
+    AuctionStatus::parse(&text)
+        .map(Some)
+        .ok_or_else(|| D::Error::custom(format!("'{}' is not a known auction status", text)))
+}
+
+impl From<ImportRow> for AuctionListing {
+    fn from(r: ImportRow) -> Self {
+        AuctionListing {
+            id: r.id,
+            state: r.state,
+            county: r.county,
+            sale_type: r.sale_type,
+            sale_date: r.sale_date,
+            property_count: r.property_count,
+            deposit_required: r.deposit_required,
+            registration_deadline: r.registration_deadline,
+            platform: r.platform,
+            platform_url: r.platform_url,
+            auction_type: r.auction_type,
+            notes: r.notes,
+            interest_rate: r.interest_rate,
+            redemption_period: r.redemption_period,
+            bidding_method: r.bidding_method,
+            min_bid: r.min_bid,
+            payment_deadline: r.payment_deadline,
+            status_override: r.status_override,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ImportRowResult {
+    pub row: usize,
+    pub id: String,
+    pub status: String, // "created", "updated", "invalid"
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub total_rows: usize,
+    pub valid: usize,
+    pub invalid: usize,
+    pub created: usize,
+    pub updated: usize,
+    pub rows: Vec<ImportRowResult>,
+}
+
+// Raw id of a row, kept so unreadable rows can still be identified in the report
+type ParsedRow = (String, Result<AuctionListing, String>);
+
+// Parse the payload into per-row results; a row that can't be decoded is an error for that row only
+fn parse_rows(format: PayloadFormat, body: &str) -> Result<Vec<ParsedRow>, String> {
+    match format {
+        PayloadFormat::Csv => {
+            // Flexible so a short row is still read far enough to report its id
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .flexible(true)
+                .from_reader(body.as_bytes());
+            let headers = reader.headers().map_err(|e| format!("Invalid CSV header: {}", e))?.clone();
+            let id_col = headers.iter().position(|h| h == "id")
+                .ok_or("CSV header must include an 'id' column")?;
+            Ok(reader
+                .records()
+                .map(|record| match record {
+                    Ok(record) => (
+                        record.get(id_col).unwrap_or("").to_string(),
+                        record.deserialize::<ImportRow>(Some(&headers))
+                            .map(AuctionListing::from)
+                            .map_err(|e| format!("Unreadable row: {}", e)),
+                    ),
+                    Err(e) => (String::new(), Err(format!("Unreadable row: {}", e))),
+                })
+                .collect())
+        }
+        PayloadFormat::Json => {
+            let values: Vec<serde_json::Value> =
+                serde_json::from_str(body).map_err(|e| format!("Expected a JSON array of listings: {}", e))?;
+            Ok(values
+                .into_iter()
+                .map(|v| {
+                    let id = v.get("id").and_then(|id| id.as_str()).unwrap_or("").to_string();
+                    let listing = serde_json::from_value::<ImportRow>(v)
+                        .map(AuctionListing::from)
+                        .map_err(|e| format!("Unreadable row: {}", e));
+                    (id, listing)
+                })
+                .collect())
+        }
+    }
+}
+
+// Validate every row, then (unless dry_run) upsert the valid ones in a single transaction
+pub fn import_listings(
+    store: &dyn AuctionRepository,
+    format: PayloadFormat,
+    body: &str,
+    dry_run: bool,
+) -> Result<ImportReport, String> {
+    let parsed = parse_rows(format, body)?;
+    let platforms = store.platform_names()?;
+
+    let mut results = Vec::with_capacity(parsed.len());
+    let mut valid_listings = Vec::new();
+    let mut valid_rows = Vec::new();
+    let mut seen_ids = HashSet::new();
+
+    for (i, (raw_id, row)) in parsed.into_iter().enumerate() {
+        // Row numbers are 1-based data rows (header excluded)
+        let row_number = i + 1;
+        let (id, errors, listing) = match row {
+            Ok(mut listing) => {
+                auctions::normalize_listing(&mut listing);
+                let mut errors = auctions::validate_listing(&listing, &platforms);
+                if !listing.id.is_empty() && !seen_ids.insert(listing.id.clone()) {
+                    errors.push(format!("duplicate id '{}' earlier in this file", listing.id));
+                }
+                (listing.id.clone(), errors, Some(listing))
+            }
+            Err(e) => (raw_id.trim().to_string(), vec![e], None),
+        };
+
+        if errors.is_empty() {
+            if let Some(listing) = listing {
+                valid_rows.push(results.len());
+                valid_listings.push(listing);
+            }
+        }
+        results.push(ImportRowResult {
+            row: row_number,
+            id,
+            status: if errors.is_empty() { String::new() } else { "invalid".to_string() },
+            errors,
+        });
+    }
+
+    // Dry runs still report whether each valid row would create or update
+    let created_flags = if dry_run {
+        valid_listings
+            .iter()
+            .map(|l| store.get_auction(&l.id).map(|existing| existing.is_none()))
+            .collect::<Result<Vec<bool>, String>>()?
+    } else {
+        store.upsert_auctions(&valid_listings)?
+    };
+
+    for (idx, created) in valid_rows.iter().zip(created_flags) {
+        results[*idx].status = if created { "created" } else { "updated" }.to_string();
+    }
+
+    let created = results.iter().filter(|r| r.status == "created").count();
+    let updated = results.iter().filter(|r| r.status == "updated").count();
+    Ok(ImportReport {
+        dry_run,
+        total_rows: results.len(),
+        valid: valid_listings.len(),
+        invalid: results.len() - valid_listings.len(),
+        created,
+        updated,
+        rows: results,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auction_store::SqliteAuctionStore;
+
+    const HEADER: &str = "id,state,county,sale_type,sale_date,registration_deadline,property_count,deposit_required,platform,auction_type,status_override";
+
+    fn store() -> SqliteAuctionStore {
+        SqliteAuctionStore::open(":memory:").unwrap()
+    }
+
+    fn csv(rows: &[&str]) -> String {
+        std::iter::once(HEADER).chain(rows.iter().copied()).collect::<Vec<_>>().join("\n")
+    }
+
+    #[test]
+    fn imports_valid_rows_and_reports_bad_ones() {
+        let store = store();
+        let body = csv(&[
+            "FL-LEE-2026-11, fl ,Lee,Tax Lien,2026-11-01,2026-10-20,120,\"$1,500\",County,Online,",
+            "PA-MONROE-2026-01,PA,Monroe,Tax Deed,2026-01-14,2026-01-07,10,500,County,In-Person,postponed",
+            "FL-LEE-2026-12,FL,Lee,Tax Lien,2026-12-01,2026-11-20,1.5,0,County,Online,",
+            "FL-LEE-2026-13,FL,Lee,Tax Lien,2026-12-01,2026-11-20,3,0,County,Online,soon",
+            "FL-LEE-2026-11,FL,Lee,Tax Lien,2026-11-01,2026-10-20,1,0,County,Online,",
+            ",ZZ,,Tax Lien,2026-12-01,2026-12-02,1,0,eBay,Online,",
+            "FL-LEE-2026-14,FL",
+        ]);
+        let report = import_listings(&store, PayloadFormat::Csv, &body, false).unwrap();
+
+        let statuses: Vec<(&str, &str)> = report.rows.iter().map(|r| (r.id.as_str(), r.status.as_str())).collect();
+        assert_eq!(statuses, [
+            ("FL-LEE-2026-11", "created"),
+            ("PA-MONROE-2026-01", "updated"),
+            ("FL-LEE-2026-12", "invalid"),
+            ("FL-LEE-2026-13", "invalid"),
+            ("FL-LEE-2026-11", "invalid"),
+            ("", "invalid"),
+            ("FL-LEE-2026-14", "invalid"),
+        ]);
+        assert_eq!(report.rows.iter().map(|r| r.row).collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!((report.total_rows, report.valid, report.invalid, report.created, report.updated), (7, 2, 5, 1, 1));
+
+        assert!(report.rows[2].errors[0].contains("not a whole number"), "{:?}", report.rows[2].errors);
+        assert!(report.rows[3].errors[0].contains("'soon' is not a known auction status"));
+        assert_eq!(report.rows[4].errors, ["duplicate id 'FL-LEE-2026-11' earlier in this file"]);
+        assert_eq!(report.rows[5].errors.len(), 5, "{:?}", report.rows[5].errors);
+        assert!(report.rows[6].errors[0].starts_with("Unreadable row"), "{:?}", report.rows[6].errors);
+
+        // Cells are trimmed, normalized and cleaned before saving
+        let created = store.get_auction("FL-LEE-2026-11").unwrap().unwrap();
+        assert_eq!((created.state.as_str(), created.deposit_required, created.property_count), ("FL", 1500.0, 120));
+        let updated = store.get_auction("PA-MONROE-2026-01").unwrap().unwrap();
+        assert_eq!(updated.status_override, Some(AuctionStatus::Postponed));
+        assert!(store.get_auction("FL-LEE-2026-12").unwrap().is_none());
+    }
+
+    #[test]
+    fn dry_run_writes_nothing() {
+        let store = store();
+        let body = csv(&["FL-LEE-2026-11,FL,Lee,Tax Lien,2026-11-01,2026-10-20,1,0,County,Online,"]);
+        let report = import_listings(&store, PayloadFormat::Csv, &body, true).unwrap();
+        assert!(report.dry_run);
+        assert_eq!(report.rows[0].status, "created");
+        assert!(store.get_auction("FL-LEE-2026-11").unwrap().is_none());
+
+        let report = import_listings(&store, PayloadFormat::Csv, &body, false).unwrap();
+        assert_eq!(report.created, 1);
+        let again = import_listings(&store, PayloadFormat::Csv, &body, true).unwrap();
+        assert_eq!(again.rows[0].status, "updated");
+    }
+
+    #[test]
+    fn imports_json_rows() {
+        let store = store();
+        let body = serde_json::json!([
+            {"id": "FL-LEE-2026-11", "state": "FL", "county": "Lee", "sale_type": "Tax Lien", "sale_date": "2026-11-01",
+             "registration_deadline": "2026-10-20", "property_count": 12, "deposit_required": "$2,000",
+             "platform": "County", "auction_type": "Hybrid"},
+            {"id": "FL-LEE-2026-12", "property_count": "many"},
+            {"id": 7},
+        ]).to_string();
+        let report = import_listings(&store, PayloadFormat::Json, &body, false).unwrap();
+        assert_eq!(report.rows.iter().map(|r| r.status.as_str()).collect::<Vec<_>>(), ["created", "invalid", "invalid"]);
+        assert_eq!(report.rows[1].id, "FL-LEE-2026-12");
+        assert!(report.rows[1].errors[0].starts_with("Unreadable row"));
+        assert_eq!(report.rows[2].id, "");
+        assert_eq!(store.get_auction("FL-LEE-2026-11").unwrap().unwrap().deposit_required, 2000.0);
+    }
+
+    #[test]
+    fn rejects_unreadable_files() {
+        let store = store();
+        let err = import_listings(&store, PayloadFormat::Csv, "state,county\nFL,Lee", false).unwrap_err();
+        assert_eq!(err, "CSV header must include an 'id' column");
+        let err = import_listings(&store, PayloadFormat::Json, "{\"id\": \"x\"}", false).unwrap_err();
+        assert!(err.starts_with("Expected a JSON array of listings"));
+    }
+}
@@ -0,0 +1,1017 @@
+// Auction Store - SQLite-backed repository for auction listings, schedules and platforms
+// Seeded on first run from the static data in auctions.rs
+
+use rusqlite::{params, types::Value, Connection, OptionalExtension, Row};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+use crate::auctions::{self, AuctionListing, AuctionPlatform, AuctionStatus, StateAuctionSchedule};
+use crate::statutes;
+
+// Default database location (override with AUCTION_DB_PATH)
+pub const DEFAULT_DB_PATH: &str = "auction_intel.db";
+
+// ============================================================================
+// AUCTION QUERIES - filters, sorting and cursor pagination
+// ============================================================================
+
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+pub const MAX_PAGE_SIZE: usize = 500;
+
+// Columns a listing query may sort on
+const SORTABLE_COLUMNS: [&str; 6] = [
+    "sale_date", "registration_deadline", "deposit_required", "property_count", "county", "state",
+];
+
+// Which side of today's date a query covers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Period {
+    #[default]
+    All,
+    Upcoming, // sale_date today or later
+    Past,     // sale_date before today
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuctionQuery {
+    pub state: Option<String>,
+    pub sale_date_from: Option<String>,   // inclusive, YYYY-MM-DD
+    pub sale_date_to: Option<String>,     // inclusive, YYYY-MM-DD
+    pub sale_type: Option<String>,
+    pub auction_type: Option<String>,     // "Online", "In-Person", "Hybrid"
+    pub platform: Option<String>,
+    pub min_deposit: Option<f64>,
+    pub max_deposit: Option<f64>,
+    pub min_properties: Option<i32>,
+    pub q: Option<String>,                // free text over county and notes
+    pub status: Option<String>,           // comma-separated AuctionStatus labels or slugs
+    pub sort: Option<String>,             // one of SORTABLE_COLUMNS (default sale_date)
+    pub order: Option<String>,            // "asc" (default) or "desc"
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+    #[serde(skip)]
+    pub period: Period,
+    #[serde(skip)]
+    pub today: Option<chrono::NaiveDate>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuctionPage {
+    pub total_matched: usize,
+    pub auctions: Vec<AuctionListing>,
+    pub next_cursor: Option<String>,
+}
+
+// Cursor = hex-encoded JSON of the last row's sort value and id
+#[derive(Debug, Serialize, Deserialize)]
+struct Cursor {
+    sort: String,
+    value: serde_json::Value,
+    id: String,
+}
+
+fn encode_cursor(cursor: &Cursor) -> String {
+    serde_json::to_vec(cursor)
+        .unwrap_or_default()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn decode_cursor(raw: &str) -> Option<Cursor> {
+    if !raw.len().is_multiple_of(2) {
+        return None;
+    }
+    let bytes = (0..raw.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(raw.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+fn json_to_sql(value: &serde_json::Value) -> Option<Value> {
+    match value {
+        serde_json::Value::String(s) => Some(Value::Text(s.clone())),
+        serde_json::Value::Number(n) if n.is_i64() => n.as_i64().map(Value::Integer),
+        serde_json::Value::Number(n) => n.as_f64().map(Value::Real),
+        _ => None,
+    }
+}
+
+fn sort_value(listing: &AuctionListing, column: &str) -> serde_json::Value {
+    match column {
+        "registration_deadline" => serde_json::json!(listing.registration_deadline),
+        "deposit_required" => serde_json::json!(listing.deposit_required),
+        "property_count" => serde_json::json!(listing.property_count),
+        "county" => serde_json::json!(listing.county),
+        "state" => serde_json::json!(listing.state),
+        _ => serde_json::json!(listing.sale_date),
+    }
+}
+
+// SQL equivalent of auctions::listing_status for one status, given today's date
+fn status_clause(status: AuctionStatus, today: &str, values: &mut Vec<Value>) -> String {
+    let derived = |clause: &str, n: usize, values: &mut Vec<Value>| {
+        for _ in 0..n {
+            values.push(Value::Text(today.to_string()));
+        }
+        format!("(status_override IS NULL AND {})", clause)
+    };
+    match status {
+        AuctionStatus::Cancelled | AuctionStatus::Postponed => {
+            values.push(Value::Text(status.label().to_string()));
+            "status_override = ?".to_string()
+        }
+        AuctionStatus::Completed => derived("sale_date < ?", 1, values),
+        AuctionStatus::LiveToday => derived("sale_date = ?", 1, values),
+        AuctionStatus::RegistrationClosed => derived("sale_date > ? AND registration_deadline < ?", 2, values),
+        AuctionStatus::RegistrationOpen => derived("sale_date > ? AND registration_deadline >= ?", 2, values),
+    }
+}
+
+fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
+
+impl AuctionQuery {
+    // Reject bad parameters up front so callers can report them as client errors
+    pub fn validate(&self) -> Result<(), String> {
+        let sort = self.sort_column()?;
+        self.descending()?;
+        self.filters()?;
+        if let Some(raw) = &self.cursor {
+            decode_cursor(raw)
+                .filter(|c| c.sort == sort && json_to_sql(&c.value).is_some())
+                .ok_or("cursor is invalid or was issued for a different sort")?;
+        }
+        Ok(())
+    }
+
+    fn sort_column(&self) -> Result<&str, String> {
+        match self.sort.as_deref() {
+            None => Ok("sale_date"),
+            Some(col) => SORTABLE_COLUMNS.iter().find(|c| **c == col).copied()
+                .ok_or_else(|| format!("sort must be one of: {}", SORTABLE_COLUMNS.join(", "))),
+        }
+    }
+
+    fn descending(&self) -> Result<bool, String> {
+        match self.order.as_deref().map(|o| o.to_lowercase()) {
+            None => Ok(false),
+            Some(o) if o == "asc" => Ok(false),
+            Some(o) if o == "desc" => Ok(true),
+            Some(o) => Err(format!("order must be 'asc' or 'desc', got '{}'", o)),
+        }
+    }
+
+    pub fn today(&self) -> chrono::NaiveDate {
+        self.today.unwrap_or_else(|| chrono::Utc::now().date_naive())
+    }
+
+    fn statuses(&self) -> Result<Vec<AuctionStatus>, String> {
+        let Some(raw) = &self.status else { return Ok(vec![]) };
+        raw.split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                AuctionStatus::parse(s).ok_or_else(|| {
+                    let labels: Vec<&str> = AuctionStatus::ALL.iter().map(|s| s.label()).collect();
+                    format!("status must be one of: {}", labels.join(", "))
+                })
+            })
+            .collect()
+    }
+
+    // WHERE clauses and bound values for every filter except the cursor
+    fn filters(&self) -> Result<(Vec<String>, Vec<Value>), String> {
+        let mut clauses = Vec::new();
+        let mut values = Vec::new();
+        let today = self.today().format("%Y-%m-%d").to_string();
+
+        match self.period {
+            Period::All => {}
+            Period::Upcoming => {
+                clauses.push("sale_date >= ?".to_string());
+                values.push(Value::Text(today.clone()));
+            }
+            Period::Past => {
+                clauses.push("sale_date < ?".to_string());
+                values.push(Value::Text(today.clone()));
+            }
+        }
+        let statuses = self.statuses()?;
+        if !statuses.is_empty() {
+            let parts: Vec<String> = statuses.into_iter()
+                .map(|status| status_clause(status, &today, &mut values))
+                .collect();
+            clauses.push(format!("({})", parts.join(" OR ")));
+        }
+
+        if let Some(state) = &self.state {
+            clauses.push("state = ?".to_string());
+            values.push(Value::Text(state.trim().to_uppercase()));
+        }
+        for (field, bound, op) in [
+            ("sale_date_from", &self.sale_date_from, ">="),
+            ("sale_date_to", &self.sale_date_to, "<="),
+        ] {
+            if let Some(date) = bound {
+                chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                    .map_err(|_| format!("{} must be an ISO date (YYYY-MM-DD), got '{}'", field, date))?;
+                clauses.push(format!("sale_date {} ?", op));
+                values.push(Value::Text(date.clone()));
+            }
+        }
+        for (column, filter) in [
+            ("sale_type", &self.sale_type),
+            ("auction_type", &self.auction_type),
+            ("platform", &self.platform),
+        ] {
+            if let Some(v) = filter {
+                clauses.push(format!("{} = ? COLLATE NOCASE", column));
+                values.push(Value::Text(v.trim().to_string()));
+            }
+        }
+        if let Some(min) = self.min_deposit {
+            clauses.push("deposit_required >= ?".to_string());
+            values.push(Value::Real(min));
+        }
+        if let Some(max) = self.max_deposit {
+            clauses.push("deposit_required <= ?".to_string());
+            values.push(Value::Real(max));
+        }
+        if let Some(min) = self.min_properties {
+            clauses.push("property_count >= ?".to_string());
+            values.push(Value::Integer(min as i64));
+        }
+        if let Some(q) = self.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
+            clauses.push("(county LIKE ? ESCAPE '\\' OR notes LIKE ? ESCAPE '\\')".to_string());
+            let pattern = format!("%{}%", escape_like(q));
+            values.push(Value::Text(pattern.clone()));
+            values.push(Value::Text(pattern));
+        }
+
+        Ok((clauses, values))
+    }
+}
+
+// ============================================================================
+// REPOSITORY TRAIT
+// ============================================================================
+
+pub trait AuctionRepository: Send + Sync {
+    // Filtered, sorted page of listings (see AuctionQuery::validate for parameter errors)
+    fn query_auctions(&self, query: &AuctionQuery) -> Result<AuctionPage, String>;
+    fn get_auction(&self, id: &str) -> Result<Option<AuctionListing>, String>;
+    // Returns false if a listing with the same id already exists
+    fn insert_auction(&self, listing: &AuctionListing) -> Result<bool, String>;
+    // Returns true if the listing was newly created rather than replaced
+    fn upsert_auction(&self, listing: &AuctionListing) -> Result<bool, String>;
+    // Upserts every listing in one transaction; returns a created flag per listing
+    fn upsert_auctions(&self, listings: &[AuctionListing]) -> Result<Vec<bool>, String>;
+    // Returns false if there was nothing to delete
+    fn delete_auction(&self, id: &str) -> Result<bool, String>;
+    fn list_platforms(&self) -> Result<Vec<AuctionPlatform>, String>;
+
+    // Every listing matching the filters, walking all pages
+    fn all_auctions(&self, query: &AuctionQuery) -> Result<Vec<AuctionListing>, String> {
+        let mut query = AuctionQuery { limit: Some(MAX_PAGE_SIZE), cursor: None, ..query.clone() };
+        let mut listings = Vec::new();
+        loop {
+            let page = self.query_auctions(&query)?;
+            listings.extend(page.auctions);
+            match page.next_cursor {
+                Some(cursor) => query.cursor = Some(cursor),
+                None => return Ok(listings),
+            }
+        }
+    }
+
+    fn platform_names(&self) -> Result<Vec<String>, String> {
+        Ok(self.list_platforms()?.into_iter().map(|p| p.name).collect())
+    }
+    fn get_schedule(&self, state: &str) -> Result<Option<StateAuctionSchedule>, String>;
+    fn list_schedules(&self) -> Result<Vec<StateAuctionSchedule>, String>;
+}
+
+// ============================================================================
+// MIGRATIONS
+// ============================================================================
+
+// Applied in order; PRAGMA user_version records how many have run
+const MIGRATIONS: &[&str] = &[
+    // 1: initial schema
+    "CREATE TABLE auctions (
+        id TEXT PRIMARY KEY,
+        state TEXT NOT NULL,
+        county TEXT NOT NULL,
+        sale_type TEXT NOT NULL,
+        sale_date TEXT NOT NULL,
+        property_count INTEGER NOT NULL,
+        deposit_required REAL NOT NULL,
+        registration_deadline TEXT NOT NULL,
+        platform TEXT NOT NULL,
+        platform_url TEXT NOT NULL,
+        auction_type TEXT NOT NULL,
+        notes TEXT NOT NULL,
+        interest_rate TEXT NOT NULL,
+        redemption_period TEXT NOT NULL,
+        bidding_method TEXT NOT NULL,
+        min_bid TEXT NOT NULL,
+        payment_deadline TEXT NOT NULL
+    );
+    CREATE INDEX idx_auctions_state ON auctions(state);
+    CREATE INDEX idx_auctions_sale_date ON auctions(sale_date);
+
+    CREATE TABLE state_schedules (
+        state TEXT PRIMARY KEY,
+        sale_type TEXT NOT NULL,
+        frequency TEXT NOT NULL,
+        typical_months TEXT NOT NULL,
+        typical_day TEXT NOT NULL,
+        interest_rate TEXT NOT NULL,
+        redemption_period TEXT NOT NULL,
+        bidding_method TEXT NOT NULL,
+        online_available INTEGER NOT NULL,
+        primary_platform TEXT NOT NULL,
+        deposit_range TEXT NOT NULL,
+        notes TEXT NOT NULL
+    );
+
+    CREATE TABLE platforms (
+        name TEXT PRIMARY KEY,
+        url TEXT NOT NULL,
+        states_covered TEXT NOT NULL,
+        auction_types TEXT NOT NULL,
+        registration_required INTEGER NOT NULL,
+        deposit_info TEXT NOT NULL
+    );",
+    // 2: manual lifecycle override (Cancelled / Postponed)
+    "ALTER TABLE auctions ADD COLUMN status_override TEXT;",
+];
+
+fn run_migrations(conn: &mut Connection) -> Result<(), String> {
+    let version: usize = conn
+        .query_row("PRAGMA user_version", [], |row| row.get::<_, i64>(0))
+        .map_err(|e| format!("Failed to read schema version: {}", e))? as usize;
+    if version >= MIGRATIONS.len() {
+        return Ok(());
+    }
+
+    // All pending migrations (and the first-run seed) apply atomically
+    let tx = conn.transaction().map_err(|e| format!("Failed to start migration: {}", e))?;
+    for (i, sql) in MIGRATIONS.iter().enumerate().skip(version) {
+        tx.execute_batch(sql).map_err(|e| format!("Migration {} failed: {}", i + 1, e))?;
+    }
+
+    // First run: load today's static data into the final schema
+    if version == 0 {
+        seed(&tx).map_err(|e| format!("Seeding auction store failed: {}", e))?;
+    }
+
+    tx.pragma_update(None, "user_version", MIGRATIONS.len() as i64)
+        .map_err(|e| format!("Failed to record schema version: {}", e))?;
+    tx.commit().map_err(|e| format!("Failed to commit migration: {}", e))
+}
+
+fn seed(conn: &Connection) -> rusqlite::Result<()> {
+    for listing in auctions::seed_auctions() {
+        insert_auction(conn, &listing)?;
+    }
+    for schedule in auctions::seed_schedules() {
+        insert_schedule(conn, &schedule)?;
+    }
+    for platform in auctions::seed_platforms() {
+        insert_platform(conn, &platform)?;
+    }
+    Ok(())
+}
+
+// Statute-derived schedule columns are owned by statutes.rs, not the database:
+// rewrite them on every open so a statute change ships with the binary
+fn sync_statutes(conn: &Connection) -> Result<(), String> {
+    for s in auctions::seed_schedules() {
+        conn.execute(
+            "UPDATE state_schedules SET sale_type = ?2, interest_rate = ?3, redemption_period = ?4, \
+             bidding_method = ?5 WHERE state = ?1",
+            params![s.state, s.sale_type, s.interest_rate, s.redemption_period, s.bidding_method],
+        )
+        .map_err(|e| format!("Failed to sync statutes for {}: {}", s.state, e))?;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// ROW MAPPING
+// ============================================================================
+
+const AUCTION_COLUMNS: &str = "id, state, county, sale_type, sale_date, property_count, deposit_required, \
+    registration_deadline, platform, platform_url, auction_type, notes, interest_rate, \
+    redemption_period, bidding_method, min_bid, payment_deadline, status_override";
+
+fn auction_from_row(row: &Row) -> rusqlite::Result<AuctionListing> {
+    Ok(AuctionListing {
+        id: row.get(0)?,
+        state: row.get(1)?,
+        county: row.get(2)?,
+        sale_type: row.get(3)?,
+        sale_date: row.get(4)?,
+        property_count: row.get(5)?,
+        deposit_required: row.get(6)?,
+        registration_deadline: row.get(7)?,
+        platform: row.get(8)?,
+        platform_url: row.get(9)?,
+        auction_type: row.get(10)?,
+        notes: row.get(11)?,
+        interest_rate: row.get(12)?,
+        redemption_period: row.get(13)?,
+        bidding_method: row.get(14)?,
+        min_bid: row.get(15)?,
+        payment_deadline: row.get(16)?,
+        status_override: row.get::<_, Option<String>>(17)?.as_deref().and_then(AuctionStatus::parse),
+    })
+}
+
+fn insert_auction(conn: &Connection, a: &AuctionListing) -> rusqlite::Result<usize> {
+    write_auction(conn, "INSERT", "", a)
+}
+
+fn write_auction(conn: &Connection, verb: &str, conflict: &str, a: &AuctionListing) -> rusqlite::Result<usize> {
+    conn.execute(
+        &format!(
+            "{} INTO auctions ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18){}",
+            verb, AUCTION_COLUMNS, conflict
+        ),
+        params![
+            a.id, a.state, a.county, a.sale_type, a.sale_date, a.property_count, a.deposit_required,
+            a.registration_deadline, a.platform, a.platform_url, a.auction_type, a.notes,
+            a.interest_rate, a.redemption_period, a.bidding_method, a.min_bid, a.payment_deadline,
+            a.status_override.map(|s| s.label()),
+        ],
+    )
+}
+
+// List columns are stored as JSON arrays
+fn json_list(row: &Row, idx: usize) -> rusqlite::Result<Vec<String>> {
+    let raw: String = row.get(idx)?;
+    serde_json::from_str(&raw).map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(idx, rusqlite::types::Type::Text, Box::new(e))
+    })
+}
+
+fn to_json_list(values: &[String]) -> String {
+    serde_json::to_string(values).unwrap_or_else(|_| "[]".to_string())
+}
+
+// Full replacement of every column except the primary key
+const AUCTION_UPSERT: &str = " ON CONFLICT(id) DO UPDATE SET state = excluded.state, county = excluded.county, \
+    sale_type = excluded.sale_type, sale_date = excluded.sale_date, property_count = excluded.property_count, \
+    deposit_required = excluded.deposit_required, registration_deadline = excluded.registration_deadline, \
+    platform = excluded.platform, platform_url = excluded.platform_url, auction_type = excluded.auction_type, \
+    notes = excluded.notes, interest_rate = excluded.interest_rate, redemption_period = excluded.redemption_period, \
+    bidding_method = excluded.bidding_method, min_bid = excluded.min_bid, payment_deadline = excluded.payment_deadline, \
+    status_override = excluded.status_override";
+
+const SCHEDULE_COLUMNS: &str = "state, sale_type, frequency, typical_months, typical_day, interest_rate, \
+    redemption_period, bidding_method, online_available, primary_platform, deposit_range, notes";
+
+fn schedule_from_row(row: &Row) -> rusqlite::Result<StateAuctionSchedule> {
+    Ok(StateAuctionSchedule {
+        state: row.get(0)?,
+        sale_type: row.get(1)?,
+        frequency: row.get(2)?,
+        typical_months: json_list(row, 3)?,
+        typical_day: row.get(4)?,
+        interest_rate: row.get(5)?,
+        redemption_period: row.get(6)?,
+        bidding_method: row.get(7)?,
+        online_available: row.get(8)?,
+        primary_platform: row.get(9)?,
+        deposit_range: row.get(10)?,
+        notes: row.get(11)?,
+        statute: statutes::get(&row.get::<_, String>(0)?),
+    })
+}
+
+fn insert_schedule(conn: &Connection, s: &StateAuctionSchedule) -> rusqlite::Result<usize> {
+    conn.execute(
+        &format!(
+            "INSERT INTO state_schedules ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+            SCHEDULE_COLUMNS
+        ),
+        params![
+            s.state, s.sale_type, s.frequency, to_json_list(&s.typical_months), s.typical_day,
+            s.interest_rate, s.redemption_period, s.bidding_method, s.online_available,
+            s.primary_platform, s.deposit_range, s.notes,
+        ],
+    )
+}
+
+const PLATFORM_COLUMNS: &str = "name, url, states_covered, auction_types, registration_required, deposit_info";
+
+fn platform_from_row(row: &Row) -> rusqlite::Result<AuctionPlatform> {
+    Ok(AuctionPlatform {
+        name: row.get(0)?,
+        url: row.get(1)?,
+        states_covered: json_list(row, 2)?,
+        auction_types: json_list(row, 3)?,
+        registration_required: row.get(4)?,
+        deposit_info: row.get(5)?,
+    })
+}
+
+fn insert_platform(conn: &Connection, p: &AuctionPlatform) -> rusqlite::Result<usize> {
+    conn.execute(
+        &format!("INSERT INTO platforms ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6)", PLATFORM_COLUMNS),
+        params![
+            p.name, p.url, to_json_list(&p.states_covered), to_json_list(&p.auction_types),
+            p.registration_required, p.deposit_info,
+        ],
+    )
+}
+
+// ============================================================================
+// SQLITE STORE
+// ============================================================================
+
+pub struct SqliteAuctionStore {
+    conn: Mutex<Connection>,
+}
+
+impl SqliteAuctionStore {
+    // Open (or create) the database file and bring the schema up to date
+    pub fn open(path: &str) -> Result<Self, String> {
+        let conn = Connection::open(path)
+            .map_err(|e| format!("Failed to open auction store at {}: {}", path, e))?;
+        Self::from_connection(conn)
+    }
+
+    fn from_connection(mut conn: Connection) -> Result<Self, String> {
+        run_migrations(&mut conn)?;
+        sync_statutes(&conn)?;
+        Ok(SqliteAuctionStore { conn: Mutex::new(conn) })
+    }
+
+    fn query<T>(
+        &self,
+        sql: &str,
+        args: &[&dyn rusqlite::ToSql],
+        map: fn(&Row) -> rusqlite::Result<T>,
+    ) -> Result<Vec<T>, String> {
+        let conn = self.conn.lock().map_err(|_| "Auction store lock poisoned".to_string())?;
+        let mut stmt = conn.prepare(sql).map_err(|e| format!("Auction store query failed: {}", e))?;
+        let rows = stmt
+            .query_map(args, map)
+            .map_err(|e| format!("Auction store query failed: {}", e))?;
+        rows.collect::<rusqlite::Result<Vec<T>>>()
+            .map_err(|e| format!("Auction store query failed: {}", e))
+    }
+
+    fn exists(conn: &Connection, id: &str) -> Result<bool, String> {
+        conn.query_row("SELECT 1 FROM auctions WHERE id = ?1", [id], |_| Ok(()))
+            .optional()
+            .map(|row| row.is_some())
+            .map_err(|e| format!("Auction store query failed: {}", e))
+    }
+
+    fn query_one<T>(
+        &self,
+        sql: &str,
+        args: &[&dyn rusqlite::ToSql],
+        map: fn(&Row) -> rusqlite::Result<T>,
+    ) -> Result<Option<T>, String> {
+        let conn = self.conn.lock().map_err(|_| "Auction store lock poisoned".to_string())?;
+        conn.query_row(sql, args, map)
+            .optional()
+            .map_err(|e| format!("Auction store query failed: {}", e))
+    }
+}
+
+impl AuctionRepository for SqliteAuctionStore {
+    fn query_auctions(&self, query: &AuctionQuery) -> Result<AuctionPage, String> {
+        let sort = query.sort_column()?;
+        let desc = query.descending()?;
+        let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+        let (mut clauses, mut values) = query.filters()?;
+
+        let where_sql = |clauses: &[String]| {
+            if clauses.is_empty() { String::new() } else { format!(" WHERE {}", clauses.join(" AND ")) }
+        };
+
+        let total_matched: i64 = {
+            let conn = self.conn.lock().map_err(|_| "Auction store lock poisoned".to_string())?;
+            conn.query_row(
+                &format!("SELECT COUNT(*) FROM auctions{}", where_sql(&clauses)),
+                rusqlite::params_from_iter(values.iter()),
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Auction store query failed: {}", e))?
+        };
+
+        // Keyset pagination: resume strictly after the (sort value, id) of the previous page
+        if let Some(raw) = &query.cursor {
+            let cursor = decode_cursor(raw)
+                .filter(|c| c.sort == sort)
+                .ok_or("cursor is invalid or was issued for a different sort")?;
+            let value = json_to_sql(&cursor.value).ok_or("cursor is invalid")?;
+            let op = if desc { "<" } else { ">" };
+            clauses.push(format!("({col} {op} ? OR ({col} = ? AND id {op} ?))", col = sort, op = op));
+            values.push(value.clone());
+            values.push(value);
+            values.push(Value::Text(cursor.id));
+        }
+
+        let dir = if desc { "DESC" } else { "ASC" };
+        let sql = format!(
+            "SELECT {} FROM auctions{} ORDER BY {} {dir}, id {dir} LIMIT {}",
+            AUCTION_COLUMNS,
+            where_sql(&clauses),
+            sort,
+            limit + 1,
+            dir = dir
+        );
+        let args: Vec<&dyn rusqlite::ToSql> = values.iter().map(|v| v as &dyn rusqlite::ToSql).collect();
+        let mut auctions = self.query(&sql, &args, auction_from_row)?;
+
+        let next_cursor = if auctions.len() > limit {
+            auctions.truncate(limit);
+            auctions.last().map(|last| encode_cursor(&Cursor {
+                sort: sort.to_string(),
+                value: sort_value(last, sort),
+                id: last.id.clone(),
+            }))
+        } else {
+            None
+        };
+
+        Ok(AuctionPage {
+            total_matched: total_matched as usize,
+            auctions,
+            next_cursor,
+        })
+    }
+
+    fn get_auction(&self, id: &str) -> Result<Option<AuctionListing>, String> {
+        self.query_one(
+            &format!("SELECT {} FROM auctions WHERE id = ?1", AUCTION_COLUMNS),
+            &[&id],
+            auction_from_row,
+        )
+    }
+
+    fn insert_auction(&self, listing: &AuctionListing) -> Result<bool, String> {
+        let conn = self.conn.lock().map_err(|_| "Auction store lock poisoned".to_string())?;
+        let inserted = write_auction(&conn, "INSERT OR IGNORE", "", listing)
+            .map_err(|e| format!("Failed to insert auction {}: {}", listing.id, e))?;
+        Ok(inserted > 0)
+    }
+
+    fn upsert_auction(&self, listing: &AuctionListing) -> Result<bool, String> {
+        let conn = self.conn.lock().map_err(|_| "Auction store lock poisoned".to_string())?;
+        let existed = Self::exists(&conn, &listing.id)?;
+        write_auction(&conn, "INSERT", AUCTION_UPSERT, listing)
+            .map_err(|e| format!("Failed to save auction {}: {}", listing.id, e))?;
+        Ok(!existed)
+    }
+
+    fn upsert_auctions(&self, listings: &[AuctionListing]) -> Result<Vec<bool>, String> {
+        let mut conn = self.conn.lock().map_err(|_| "Auction store lock poisoned".to_string())?;
+        let tx = conn.transaction().map_err(|e| format!("Failed to start import: {}", e))?;
+        let mut created = Vec::with_capacity(listings.len());
+        for listing in listings {
+            created.push(!Self::exists(&tx, &listing.id)?);
+            write_auction(&tx, "INSERT", AUCTION_UPSERT, listing)
+                .map_err(|e| format!("Failed to save auction {}: {}", listing.id, e))?;
+        }
+        tx.commit().map_err(|e| format!("Failed to commit import: {}", e))?;
+        Ok(created)
+    }
+
+    fn delete_auction(&self, id: &str) -> Result<bool, String> {
+        let conn = self.conn.lock().map_err(|_| "Auction store lock poisoned".to_string())?;
+        let deleted = conn.execute("DELETE FROM auctions WHERE id = ?1", [id])
+            .map_err(|e| format!("Failed to delete auction {}: {}", id, e))?;
+        Ok(deleted > 0)
+    }
+
+    fn list_platforms(&self) -> Result<Vec<AuctionPlatform>, String> {
+        self.query(
+            &format!("SELECT {} FROM platforms ORDER BY rowid", PLATFORM_COLUMNS),
+            &[],
+            platform_from_row,
+        )
+    }
+
+    fn get_schedule(&self, state: &str) -> Result<Option<StateAuctionSchedule>, String> {
+        self.query_one(
+            &format!("SELECT {} FROM state_schedules WHERE state = ?1", SCHEDULE_COLUMNS),
+            &[&state.to_uppercase()],
+            schedule_from_row,
+        )
+    }
+
+    fn list_schedules(&self) -> Result<Vec<StateAuctionSchedule>, String> {
+        self.query(
+            &format!("SELECT {} FROM state_schedules ORDER BY state", SCHEDULE_COLUMNS),
+            &[],
+            schedule_from_row,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> SqliteAuctionStore {
+        SqliteAuctionStore::from_connection(Connection::open_in_memory().unwrap()).unwrap()
+    }
+
+    fn user_version(conn: &Connection) -> i64 {
+        conn.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap()
+    }
+
+    fn count(conn: &Connection, table: &str) -> i64 {
+        conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn first_run_migrates_and_seeds() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        run_migrations(&mut conn).unwrap();
+        assert_eq!(user_version(&conn), MIGRATIONS.len() as i64);
+        assert_eq!(count(&conn, "auctions"), auctions::seed_auctions().len() as i64);
+        assert_eq!(count(&conn, "state_schedules"), 51);
+        assert_eq!(count(&conn, "platforms"), auctions::seed_platforms().len() as i64);
+
+        // Re-running is a no-op: nothing is seeded twice
+        conn.execute("DELETE FROM auctions WHERE id = 'PA-MONROE-2026-01'", []).unwrap();
+        run_migrations(&mut conn).unwrap();
+        assert_eq!(count(&conn, "auctions"), auctions::seed_auctions().len() as i64 - 1);
+    }
+
+    #[test]
+    fn upgrades_an_existing_database_without_reseeding() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(MIGRATIONS[0]).unwrap();
+        conn.pragma_update(None, "user_version", 1).unwrap();
+        let mut schedule = auctions::seed_schedules().into_iter().find(|s| s.state == "FL").unwrap();
+        schedule.interest_rate = "stale".to_string();
+        insert_schedule(&conn, &schedule).unwrap();
+
+        let store = SqliteAuctionStore::from_connection(conn).unwrap();
+        {
+            let conn = store.conn.lock().unwrap();
+            assert_eq!(user_version(&conn), MIGRATIONS.len() as i64);
+            assert_eq!(count(&conn, "auctions"), 0);
+        }
+
+        // Migration 2 added the override column
+        let mut listing = auctions::seed_auctions().remove(0);
+        listing.status_override = Some(AuctionStatus::Postponed);
+        assert!(store.insert_auction(&listing).unwrap());
+        assert_eq!(store.get_auction(&listing.id).unwrap().unwrap().status_override, Some(AuctionStatus::Postponed));
+
+        // Statute columns come from the binary, the rest from the database
+        let synced = store.get_schedule("fl").unwrap().unwrap();
+        let expected = auctions::seed_schedules().into_iter().find(|s| s.state == "FL").unwrap();
+        assert_eq!(synced.interest_rate, expected.interest_rate);
+        assert_eq!(synced.typical_months, expected.typical_months);
+        assert!(synced.statute.is_some());
+    }
+
+    #[test]
+    fn failed_migration_leaves_the_database_untouched() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE auctions (id TEXT PRIMARY KEY);").unwrap();
+        let err = run_migrations(&mut conn).unwrap_err();
+        assert!(err.starts_with("Migration 1 failed"), "{}", err);
+        assert_eq!(user_version(&conn), 0);
+        assert_eq!(count(&conn, "sqlite_master WHERE name = 'platforms'"), 0);
+    }
+
+    fn ids(listings: &[AuctionListing]) -> Vec<String> {
+        listings.iter().map(|l| l.id.clone()).collect()
+    }
+
+    fn query(sort: &str, order: &str) -> AuctionQuery {
+        AuctionQuery { sort: Some(sort.to_string()), order: Some(order.to_string()), ..Default::default() }
+    }
+
+    #[test]
+    fn cursor_round_trips() {
+        let cursor = Cursor { sort: "deposit_required".to_string(), value: serde_json::json!(1500.5), id: "TX-HARRIS-2026-02".to_string() };
+        let raw = encode_cursor(&cursor);
+        assert!(raw.chars().all(|c| c.is_ascii_hexdigit()));
+
+        let decoded = decode_cursor(&raw).unwrap();
+        assert_eq!(decoded.sort, cursor.sort);
+        assert_eq!(decoded.value, cursor.value);
+        assert_eq!(decoded.id, cursor.id);
+    }
+
+    #[test]
+    fn rejects_malformed_cursors() {
+        let raw = encode_cursor(&Cursor { sort: "sale_date".to_string(), value: serde_json::json!("2026-01-14"), id: "A".to_string() });
+        assert!(decode_cursor(&raw[..raw.len() - 1]).is_none(), "odd length");
+        assert!(decode_cursor(&format!("zz{}", &raw[2..])).is_none(), "not hex");
+        assert!(decode_cursor(&raw[..raw.len() - 2]).is_none(), "truncated JSON");
+        assert!(decode_cursor("").is_none());
+
+        // Valid hex of something other than a cursor
+        let tampered: String = b"{\"sort\":1}".iter().map(|b| format!("{:02x}", b)).collect();
+        assert!(decode_cursor(&tampered).is_none());
+
+        let with_cursor = |sort: Option<&str>, cursor: &str| AuctionQuery {
+            sort: sort.map(str::to_string),
+            cursor: Some(cursor.to_string()),
+            ..Default::default()
+        };
+        assert!(with_cursor(None, &raw).validate().is_ok());
+        assert!(with_cursor(Some("county"), &raw).validate().is_err(), "issued for another sort");
+        assert!(with_cursor(None, &raw[1..]).validate().is_err());
+        assert!(store().query_auctions(&with_cursor(None, &raw[1..])).is_err());
+
+        // Objects and nulls are not sortable values
+        let object = encode_cursor(&Cursor { sort: "sale_date".to_string(), value: serde_json::json!({}), id: "A".to_string() });
+        assert!(with_cursor(None, &object).validate().is_err());
+    }
+
+    #[test]
+    fn validate_reports_bad_parameters() {
+        assert!(AuctionQuery::default().validate().is_ok());
+        assert!(query("property_count", "DESC").validate().is_ok());
+
+        let err = query("notes", "asc").validate().unwrap_err();
+        assert!(err.starts_with("sort must be one of"), "{}", err);
+        assert!(query("sale_date", "sideways").validate().unwrap_err().contains("'sideways'"));
+
+        let bad_date = AuctionQuery { sale_date_from: Some("01/14/2026".to_string()), ..Default::default() };
+        assert!(bad_date.validate().unwrap_err().starts_with("sale_date_from must be an ISO date"));
+        let bad_status = AuctionQuery { status: Some("open,soon".to_string()), ..Default::default() };
+        assert!(bad_status.validate().unwrap_err().starts_with("status must be one of"));
+        let slugs = AuctionQuery { status: Some("registration_open, live-today".to_string()), ..Default::default() };
+        assert!(slugs.validate().is_ok());
+    }
+
+    #[test]
+    fn pagination_returns_every_row_exactly_once() {
+        let store = store();
+        for sort in SORTABLE_COLUMNS {
+            for order in ["asc", "desc"] {
+                let full = store.query_auctions(&AuctionQuery { limit: Some(MAX_PAGE_SIZE), ..query(sort, order) }).unwrap();
+                assert!(full.next_cursor.is_none());
+                assert_eq!(full.auctions.len(), full.total_matched);
+
+                // Page sizes that do and don't divide the row count evenly
+                for limit in [1, 7, full.total_matched] {
+                    let mut q = AuctionQuery { limit: Some(limit), ..query(sort, order) };
+                    let mut walked = Vec::new();
+                    loop {
+                        let page = store.query_auctions(&q).unwrap();
+                        assert_eq!(page.total_matched, full.total_matched);
+                        assert!(page.auctions.len() <= limit);
+                        walked.extend(page.auctions);
+                        match page.next_cursor {
+                            Some(cursor) => q.cursor = Some(cursor),
+                            None => break,
+                        }
+                    }
+                    assert_eq!(ids(&walked), ids(&full.auctions), "sort={} order={} limit={}", sort, order, limit);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sorts_with_id_as_tie_breaker() {
+        let store = store();
+        let desc = store.all_auctions(&query("state", "desc")).unwrap();
+        for pair in desc.windows(2) {
+            let (a, b) = (&pair[0], &pair[1]);
+            assert!(a.state > b.state || (a.state == b.state && a.id > b.id), "{} before {}", a.id, b.id);
+        }
+    }
+
+    #[test]
+    fn filters_combine() {
+        let store = store();
+        let tx = store.all_auctions(&AuctionQuery {
+            state: Some(" tx ".to_string()),
+            sale_date_from: Some("2026-02-03".to_string()),
+            sale_date_to: Some("2026-02-03".to_string()),
+            ..Default::default()
+        }).unwrap();
+        assert!(!tx.is_empty());
+        assert!(tx.iter().all(|l| l.state == "TX" && l.sale_date == "2026-02-03"));
+
+        // LIKE wildcards in free text are matched literally
+        let all = store.all_auctions(&AuctionQuery::default()).unwrap();
+        for q in ["%", "_", "18%"] {
+            let literal: Vec<&AuctionListing> = all.iter()
+                .filter(|l| l.county.contains(q) || l.notes.contains(q))
+                .collect();
+            let matched = store.query_auctions(&AuctionQuery { q: Some(q.to_string()), ..Default::default() }).unwrap();
+            assert_eq!(matched.total_matched, literal.len(), "q={}", q);
+            assert!(matched.total_matched < all.len(), "q={}", q);
+        }
+
+        let by_county = store.all_auctions(&AuctionQuery { q: Some("harris".to_string()), ..Default::default() }).unwrap();
+        assert_eq!(ids(&by_county), ["TX-HARRIS-2026-02"]);
+    }
+
+    #[test]
+    fn insert_upsert_and_delete() {
+        let store = store();
+        let mut listing = auctions::seed_auctions().remove(0);
+        assert!(!store.insert_auction(&listing).unwrap(), "seeded id already exists");
+
+        listing.id = "PA-MONROE-2026-07".to_string();
+        assert!(store.insert_auction(&listing).unwrap());
+        assert!(!store.insert_auction(&listing).unwrap());
+
+        // Upsert replaces every column, including clearing an override
+        listing.deposit_required = 2500.0;
+        listing.status_override = Some(AuctionStatus::Cancelled);
+        assert!(!store.upsert_auction(&listing).unwrap());
+        let saved = store.get_auction(&listing.id).unwrap().unwrap();
+        assert_eq!(saved.deposit_required, 2500.0);
+        assert_eq!(saved.status_override, Some(AuctionStatus::Cancelled));
+        listing.status_override = None;
+        store.upsert_auction(&listing).unwrap();
+        assert_eq!(store.get_auction(&listing.id).unwrap().unwrap().status_override, None);
+
+        assert!(store.delete_auction(&listing.id).unwrap());
+        assert!(!store.delete_auction(&listing.id).unwrap());
+        assert!(store.get_auction(&listing.id).unwrap().is_none());
+
+        listing.id = "PA-MONROE-2026-08".to_string();
+        assert!(store.upsert_auction(&listing).unwrap(), "upsert of a new id creates it");
+    }
+
+    #[test]
+    fn bulk_upsert_reports_created_per_listing() {
+        let store = store();
+        let existing = auctions::seed_auctions().remove(1);
+        let mut fresh = existing.clone();
+        fresh.id = "PA-PHILA-2026-09".to_string();
+        let before = store.query_auctions(&AuctionQuery::default()).unwrap().total_matched;
+
+        assert_eq!(store.upsert_auctions(&[existing, fresh]).unwrap(), [false, true]);
+        assert_eq!(store.query_auctions(&AuctionQuery::default()).unwrap().total_matched, before + 1);
+        assert!(store.upsert_auctions(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn reads_platforms_and_schedules() {
+        let store = store();
+        assert_eq!(store.platform_names().unwrap(), auctions::seed_platforms().into_iter().map(|p| p.name).collect::<Vec<_>>());
+
+        let schedules = store.list_schedules().unwrap();
+        assert_eq!(schedules.len(), 51);
+        assert!(schedules.windows(2).all(|w| w[0].state < w[1].state));
+        assert_eq!(store.get_schedule("tx").unwrap().unwrap().state, "TX");
+        assert!(store.get_schedule("ZZ").unwrap().is_none());
+    }
+
+    #[test]
+    fn status_filter_matches_listing_status() {
+        let store = store();
+        for (id, status) in [("PA-MONROE-2026-01", AuctionStatus::Cancelled), ("TX-DALLAS-2026-02", AuctionStatus::Postponed)] {
+            let mut listing = store.get_auction(id).unwrap().unwrap();
+            listing.status_override = Some(status);
+            store.upsert_auction(&listing).unwrap();
+        }
+        let all = store.all_auctions(&AuctionQuery::default()).unwrap();
+
+        // Days either side of a registration deadline and a sale date, plus before and after everything
+        let days = ["2025-12-01", "2026-01-26", "2026-01-27", "2026-01-28", "2026-02-02", "2026-02-03", "2026-02-04", "2027-01-01"];
+        for day in days {
+            let today = chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d").unwrap();
+            for status in AuctionStatus::ALL {
+                let expected: Vec<String> = all.iter()
+                    .filter(|l| auctions::listing_status(l, today) == status)
+                    .map(|l| l.id.clone())
+                    .collect();
+                let q = AuctionQuery { status: Some(status.label().to_string()), today: Some(today), ..Default::default() };
+                assert_eq!(ids(&store.all_auctions(&q).unwrap()), expected, "{} on {}", status.label(), day);
+            }
+
+            // Every listing lands in exactly one status
+            let every = "registration_open,registration_closed,live_today,completed,cancelled,postponed";
+            let q = AuctionQuery { status: Some(every.to_string()), today: Some(today), ..Default::default() };
+            assert_eq!(store.query_auctions(&q).unwrap().total_matched, all.len());
+
+            // Periods split on the sale date alone, overrides included
+            for (period, upcoming) in [(Period::Upcoming, true), (Period::Past, false)] {
+                let expected: Vec<String> = all.iter()
+                    .filter(|l| (l.sale_date.as_str() >= day) == upcoming)
+                    .map(|l| l.id.clone())
+                    .collect();
+                let q = AuctionQuery { period, today: Some(today), ..Default::default() };
+                assert_eq!(ids(&store.all_auctions(&q).unwrap()), expected, "{:?} on {}", period, day);
+            }
+        }
+    }
+}
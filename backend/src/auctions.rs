@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use once_cell::sync::Lazy;
 
+use crate::statutes::{self, StateStatute};
+
 // ============================================================================
 // DATA STRUCTURES
 // ============================================================================
@@ -29,6 +31,86 @@ pub struct AuctionListing {
     pub bidding_method: String,
     pub min_bid: String,
     pub payment_deadline: String,
+    // Manual lifecycle override (Cancelled / Postponed); None = derived from dates
+    #[serde(default)]
+    pub status_override: Option<AuctionStatus>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuctionStatus {
+    #[serde(rename = "Registration Open")]
+    RegistrationOpen,
+    #[serde(rename = "Registration Closed")]
+    RegistrationClosed,
+    #[serde(rename = "Live Today")]
+    LiveToday,
+    Completed,
+    Cancelled,
+    Postponed,
+}
+
+impl AuctionStatus {
+    pub const ALL: [AuctionStatus; 6] = [
+        AuctionStatus::RegistrationOpen,
+        AuctionStatus::RegistrationClosed,
+        AuctionStatus::LiveToday,
+        AuctionStatus::Completed,
+        AuctionStatus::Cancelled,
+        AuctionStatus::Postponed,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AuctionStatus::RegistrationOpen => "Registration Open",
+            AuctionStatus::RegistrationClosed => "Registration Closed",
+            AuctionStatus::LiveToday => "Live Today",
+            AuctionStatus::Completed => "Completed",
+            AuctionStatus::Cancelled => "Cancelled",
+            AuctionStatus::Postponed => "Postponed",
+        }
+    }
+
+    // Accepts labels or slugs: "Registration Open", "registration_open", "live-today"
+    pub fn parse(value: &str) -> Option<AuctionStatus> {
+        let key: String = value.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_lowercase();
+        AuctionStatus::ALL.into_iter().find(|s| {
+            s.label().chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_lowercase() == key
+        })
+    }
+
+    // Only these can be set by hand; the rest follow from the calendar
+    pub fn is_manual(&self) -> bool {
+        matches!(self, AuctionStatus::Cancelled | AuctionStatus::Postponed)
+    }
+}
+
+// Lifecycle status as of `today`: override first, then sale date, then registration deadline
+pub fn listing_status(listing: &AuctionListing, today: chrono::NaiveDate) -> AuctionStatus {
+    if let Some(status) = listing.status_override {
+        return status;
+    }
+    let parse = |d: &str| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok();
+    match (parse(&listing.sale_date), parse(&listing.registration_deadline)) {
+        (Some(sale), _) if sale < today => AuctionStatus::Completed,
+        (Some(sale), _) if sale == today => AuctionStatus::LiveToday,
+        (_, Some(deadline)) if deadline < today => AuctionStatus::RegistrationClosed,
+        _ => AuctionStatus::RegistrationOpen,
+    }
+}
+
+// Listing as returned by the API, with its status as of today
+#[derive(Debug, Clone, Serialize)]
+pub struct AuctionWithStatus {
+    #[serde(flatten)]
+    pub listing: AuctionListing,
+    pub status: AuctionStatus,
+}
+
+impl AuctionWithStatus {
+    pub fn new(listing: AuctionListing, today: chrono::NaiveDate) -> Self {
+        let status = listing_status(&listing, today);
+        AuctionWithStatus { listing, status }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,6 +127,9 @@ pub struct StateAuctionSchedule {
     pub primary_platform: String,
     pub deposit_range: String,
     pub notes: String,
+    // Typed rules behind sale_type / interest_rate / redemption_period / bidding_method
+    #[serde(skip_deserializing)]
+    pub statute: Option<&'static StateStatute>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -118,784 +203,144 @@ static AUCTION_PLATFORMS: Lazy<Vec<AuctionPlatform>> = Lazy::new(|| {
 // STATE AUCTION SCHEDULES - Accurate for all 51 jurisdictions
 // ============================================================================
 
+// Sale type, rate, redemption and bidding come from the typed statutes; the rest is market calendar
 static STATE_SCHEDULES: Lazy<HashMap<String, StateAuctionSchedule>> = Lazy::new(|| {
     let mut m = HashMap::new();
-    
-    // === LIEN STATES (26) ===
-    m.insert("AL".to_string(), StateAuctionSchedule {
-        state: "AL".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["May".to_string(), "June".to_string()],
-        typical_day: "Varies by county".to_string(),
-        interest_rate: "12%".to_string(),
-        redemption_period: "3 years".to_string(),
-        bidding_method: "Premium bidding".to_string(),
-        online_available: true,
-        primary_platform: "County".to_string(),
-        deposit_range: "$50-$200".to_string(),
-        notes: "12% interest from date of sale; premium not refunded if redeemed".to_string(),
-    });
-    
-    m.insert("AZ".to_string(), StateAuctionSchedule {
-        state: "AZ".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["February".to_string()],
-        typical_day: "2nd Tuesday".to_string(),
-        interest_rate: "16% max (bid down)".to_string(),
-        redemption_period: "3 years".to_string(),
-        bidding_method: "Bid down interest rate".to_string(),
-        online_available: true,
-        primary_platform: "RealAuction / County".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "Most counties online; Maricopa is largest sale in US; bid starts at 16% and goes down".to_string(),
-    });
-    
-    m.insert("CO".to_string(), StateAuctionSchedule {
-        state: "CO".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["November".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "Federal discount + 9%".to_string(),
-        redemption_period: "3 years".to_string(),
-        bidding_method: "Premium bidding".to_string(),
-        online_available: true,
-        primary_platform: "RealAuction".to_string(),
-        deposit_range: "$200-$1,000".to_string(),
-        notes: "Rate tied to federal discount rate; premium lost if redeemed".to_string(),
-    });
 
-    m.insert("FL".to_string(), StateAuctionSchedule {
-        state: "FL".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["May".to_string(), "June".to_string()],
-        typical_day: "Varies by county".to_string(),
-        interest_rate: "18% max (bid down) - 5% minimum guaranteed".to_string(),
-        redemption_period: "2 years".to_string(),
-        bidding_method: "Bid down interest rate".to_string(),
-        online_available: true,
-        primary_platform: "RealAuction".to_string(),
-        deposit_range: "$500-$2,500".to_string(),
-        notes: "Guaranteed 5% minimum return; most competitive state; major counties all online".to_string(),
-    });
-    
-    m.insert("GA".to_string(), StateAuctionSchedule {
-        state: "GA".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Monthly".to_string(),
-        typical_months: vec!["Year-round".to_string()],
-        typical_day: "1st Tuesday of month".to_string(),
-        interest_rate: "20% (escalates to 30%, then 40%)".to_string(),
-        redemption_period: "1 year".to_string(),
-        bidding_method: "Premium bidding".to_string(),
-        online_available: true,
-        primary_platform: "County / Bid4Assets".to_string(),
-        deposit_range: "$1,000-$5,000".to_string(),
-        notes: "Highest escalating rate in US; 20% year 1, 30% year 2, 40% year 3".to_string(),
-    });
-    
-    m.insert("IL".to_string(), StateAuctionSchedule {
-        state: "IL".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["October".to_string(), "November".to_string()],
-        typical_day: "Varies by county".to_string(),
-        interest_rate: "18% (bid down)".to_string(),
-        redemption_period: "2-3 years".to_string(),
-        bidding_method: "Bid down interest rate".to_string(),
-        online_available: true,
-        primary_platform: "GovEase / County".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "Cook County largest sale; graduated penalty system on redemption".to_string(),
-    });
-    
-    m.insert("IN".to_string(), StateAuctionSchedule {
-        state: "IN".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual/Semi-annual".to_string(),
-        typical_months: vec!["September".to_string(), "October".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "10-15% graduated".to_string(),
-        redemption_period: "1 year".to_string(),
-        bidding_method: "A/B/C tiered sales".to_string(),
-        online_available: true,
-        primary_platform: "GovEase / SRI".to_string(),
-        deposit_range: "$200-$500".to_string(),
-        notes: "A sale = certified; B/C = commissioner sales; complex system".to_string(),
-    });
-    
-    m.insert("IA".to_string(), StateAuctionSchedule {
-        state: "IA".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["June".to_string()],
-        typical_day: "3rd Monday".to_string(),
-        interest_rate: "24% (HIGHEST IN US)".to_string(),
-        redemption_period: "1 year 9 months".to_string(),
-        bidding_method: "Bid down ownership percentage".to_string(),
-        online_available: false,
-        primary_platform: "County (in-person)".to_string(),
-        deposit_range: "$200-$500".to_string(),
-        notes: "Highest rate in nation; bid for smallest undivided interest; must negotiate deed".to_string(),
-    });
-    
-    m.insert("KY".to_string(), StateAuctionSchedule {
-        state: "KY".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["July".to_string(), "August".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "12%".to_string(),
-        redemption_period: "1 year".to_string(),
-        bidding_method: "First-come or auction".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$100-$300".to_string(),
-        notes: "12% from date of issuance; smaller market".to_string(),
-    });
-    
-    m.insert("LA".to_string(), StateAuctionSchedule {
-        state: "LA".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Varies by parish".to_string(),
-        typical_months: vec!["June".to_string(), "July".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "Bid-down (2024-2025 reform)".to_string(),
-        redemption_period: "3 years".to_string(),
-        bidding_method: "Bid down interest rate (new system)".to_string(),
-        online_available: true,
-        primary_platform: "CivicSource".to_string(),
-        deposit_range: "$300-$1,000".to_string(),
-        notes: "Major 2024-2025 reform: now bid-down system; previously 12% + 5% penalty".to_string(),
-    });
-    
-    m.insert("MD".to_string(), StateAuctionSchedule {
-        state: "MD".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["May".to_string(), "June".to_string()],
-        typical_day: "Varies by county".to_string(),
-        interest_rate: "18-24%".to_string(),
-        redemption_period: "6 months".to_string(),
-        bidding_method: "Bid down or premium".to_string(),
-        online_available: true,
-        primary_platform: "Bid4Assets".to_string(),
-        deposit_range: "$1,000-$2,500".to_string(),
-        notes: "Short 6-month redemption; near DC; high rates; competitive".to_string(),
-    });
-    
-    m.insert("NJ".to_string(), StateAuctionSchedule {
-        state: "NJ".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Varies by municipality".to_string(),
-        typical_months: vec!["October".to_string(), "November".to_string(), "December".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "18% (bid down)".to_string(),
-        redemption_period: "2 years".to_string(),
-        bidding_method: "Bid down interest rate".to_string(),
-        online_available: true,
-        primary_platform: "Zeusauction / Bid4Assets".to_string(),
-        deposit_range: "$1,000-$5,000".to_string(),
-        notes: "Active market; high property values; each municipality runs own sale".to_string(),
-    });
-    
-    m.insert("CT".to_string(), StateAuctionSchedule {
-        state: "CT".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["June".to_string(), "July".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "18%".to_string(),
-        redemption_period: "6 months".to_string(),
-        bidding_method: "Premium bidding".to_string(),
-        online_available: false,
-        primary_platform: "County (in-person)".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "Combined lien/deed format; larger towns only; limited availability".to_string(),
-    });
-    
-    m.insert("DC".to_string(), StateAuctionSchedule {
-        state: "DC".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["July".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "18%".to_string(),
-        redemption_period: "6 months".to_string(),
-        bidding_method: "Premium bidding".to_string(),
-        online_available: true,
-        primary_platform: "DC Government".to_string(),
-        deposit_range: "$1,000-$5,000".to_string(),
-        notes: "Short 6-month redemption; premium bidding; high competition".to_string(),
-    });
-    
-    m.insert("MA".to_string(), StateAuctionSchedule {
-        state: "MA".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["Varies".to_string()],
-        typical_day: "Varies by town".to_string(),
-        interest_rate: "16%".to_string(),
-        redemption_period: "Collector's deed".to_string(),
-        bidding_method: "Bid down ownership percentage".to_string(),
-        online_available: false,
-        primary_platform: "Town Collector".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "Bid for smallest undivided part; complex process; high property values".to_string(),
-    });
-    
-    m.insert("MS".to_string(), StateAuctionSchedule {
-        state: "MS".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["August".to_string()],
-        typical_day: "Last Monday".to_string(),
-        interest_rate: "18%".to_string(),
-        redemption_period: "2 years".to_string(),
-        bidding_method: "Premium bidding".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$100-$500".to_string(),
-        notes: "Less competition; in-person required; smaller market".to_string(),
-    });
-    
-    m.insert("MO".to_string(), StateAuctionSchedule {
-        state: "MO".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["August".to_string()],
-        typical_day: "4th Monday".to_string(),
-        interest_rate: "10%".to_string(),
-        redemption_period: "2 years".to_string(),
-        bidding_method: "Premium bidding".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$100-$500".to_string(),
-        notes: "10% interest; 18% penalty each year delinquent; in-person sales".to_string(),
-    });
-    
-    m.insert("MT".to_string(), StateAuctionSchedule {
-        state: "MT".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["July".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "10%".to_string(),
-        redemption_period: "2-3 years".to_string(),
-        bidding_method: "Premium bidding".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$100-$500".to_string(),
-        notes: "5/6 of 1% per month (10% annual); small market; rural properties".to_string(),
-    });
-    
-    m.insert("NE".to_string(), StateAuctionSchedule {
-        state: "NE".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["March".to_string()],
-        typical_day: "1st Monday".to_string(),
-        interest_rate: "14%".to_string(),
-        redemption_period: "3 years".to_string(),
-        bidding_method: "Premium bidding".to_string(),
-        online_available: true,
-        primary_platform: "GovEase / County".to_string(),
-        deposit_range: "$200-$500".to_string(),
-        notes: "14% annual interest; undivided interest system; moderate market".to_string(),
-    });
-    
-    m.insert("NH".to_string(), StateAuctionSchedule {
-        state: "NH".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["May".to_string(), "June".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "18%".to_string(),
-        redemption_period: "2 years".to_string(),
-        bidding_method: "Bid down ownership percentage".to_string(),
-        online_available: false,
-        primary_platform: "Town".to_string(),
-        deposit_range: "$500-$1,000".to_string(),
-        notes: "Bid for percentage of undivided interest; high property values".to_string(),
-    });
-    
-    m.insert("OK".to_string(), StateAuctionSchedule {
-        state: "OK".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["June".to_string()],
-        typical_day: "2nd Monday".to_string(),
-        interest_rate: "8%".to_string(),
-        redemption_period: "2 years".to_string(),
-        bidding_method: "Random drawing".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$100-$500".to_string(),
-        notes: "Unique random drawing for ties; 8% rate is lower; in-person".to_string(),
-    });
-    
-    m.insert("RI".to_string(), StateAuctionSchedule {
-        state: "RI".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["December".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "10% + 1%/month".to_string(),
-        redemption_period: "1 year".to_string(),
-        bidding_method: "Premium bidding".to_string(),
-        online_available: false,
-        primary_platform: "City/Town".to_string(),
-        deposit_range: "$500-$1,000".to_string(),
-        notes: "10% first 6 months, 1%/month after; Collector's Deed; small state".to_string(),
-    });
-    
-    m.insert("SC".to_string(), StateAuctionSchedule {
-        state: "SC".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["October".to_string(), "November".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "8% penalty".to_string(),
-        redemption_period: "1 year".to_string(),
-        bidding_method: "Highest and best bid".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$100-$500".to_string(),
-        notes: "8% penalty rather than interest; short 1-year redemption".to_string(),
-    });
-    
-    m.insert("SD".to_string(), StateAuctionSchedule {
-        state: "SD".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["December".to_string()],
-        typical_day: "3rd Tuesday".to_string(),
-        interest_rate: "12% (max 10% bid)".to_string(),
-        redemption_period: "3-4 years".to_string(),
-        bidding_method: "Bid down interest rate".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$100-$300".to_string(),
-        notes: "Bid down from 10% max; 12% statutory; long redemption".to_string(),
-    });
-    
-    m.insert("VT".to_string(), StateAuctionSchedule {
-        state: "VT".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["April".to_string(), "May".to_string(), "June".to_string(), "July".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "12%".to_string(),
-        redemption_period: "1 year".to_string(),
-        bidding_method: "Premium bidding".to_string(),
-        online_available: false,
-        primary_platform: "Town".to_string(),
-        deposit_range: "$200-$500".to_string(),
-        notes: "12% interest; 1-year redemption; small rural market".to_string(),
-    });
-    
-    m.insert("WV".to_string(), StateAuctionSchedule {
-        state: "WV".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["October".to_string(), "November".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "12%".to_string(),
-        redemption_period: "1 year".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$100-$500".to_string(),
-        notes: "12% interest; highest bidder at public auction; rural properties".to_string(),
-    });
-    
-    m.insert("WY".to_string(), StateAuctionSchedule {
-        state: "WY".to_string(),
-        sale_type: "Tax Lien".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["September".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "15% + 3% penalty".to_string(),
-        redemption_period: "4 years".to_string(),
-        bidding_method: "Premium bidding".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$100-$500".to_string(),
-        notes: "Longest redemption period; 15% interest + 3% penalty + fees".to_string(),
-    });
-    
+    macro_rules! add_schedule {
+        ($abbr:expr, $frequency:expr, [$($month:expr),*], $day:expr, $online:expr, $platform:expr, $deposit:expr, $notes:expr) => {
+            let statute = statutes::get($abbr).expect("every scheduled state has a statute");
+            m.insert($abbr.to_string(), StateAuctionSchedule {
+                state: $abbr.to_string(),
+                sale_type: statute.sale_kind.label().to_string(),
+                frequency: $frequency.to_string(),
+                typical_months: vec![$($month.to_string()),*],
+                typical_day: $day.to_string(),
+                interest_rate: statute.rate_label(),
+                redemption_period: statute.redemption.label(),
+                bidding_method: statute.bidding.label().to_string(),
+                online_available: $online,
+                primary_platform: $platform.to_string(),
+                deposit_range: $deposit.to_string(),
+                notes: $notes.to_string(),
+                statute: Some(statute),
+            });
+        };
+    }
+
+    // === LIEN STATES (26) ===
+    add_schedule!("AL", "Annual", ["May", "June"], "Varies by county", true, "County", "$50-$200",
+        "12% interest from date of sale; premium not refunded if redeemed");
+    add_schedule!("AZ", "Annual", ["February"], "2nd Tuesday", true, "RealAuction / County", "$500-$2,000",
+        "Most counties online; Maricopa is largest sale in US; bid starts at 16% and goes down");
+    add_schedule!("CO", "Annual", ["November"], "Varies", true, "RealAuction", "$200-$1,000",
+        "Rate tied to federal discount rate; premium lost if redeemed");
+    add_schedule!("FL", "Annual", ["May", "June"], "Varies by county", true, "RealAuction", "$500-$2,500",
+        "Guaranteed 5% minimum return; most competitive state; major counties all online");
+    add_schedule!("GA", "Monthly", ["Year-round"], "1st Tuesday of month", true, "County / Bid4Assets", "$1,000-$5,000",
+        "Highest escalating rate in US; 20% year 1, 30% year 2, 40% year 3");
+    add_schedule!("IL", "Annual", ["October", "November"], "Varies by county", true, "GovEase / County", "$500-$2,000",
+        "Cook County largest sale; graduated penalty system on redemption");
+    add_schedule!("IN", "Annual/Semi-annual", ["September", "October"], "Varies", true, "GovEase / SRI", "$200-$500",
+        "A sale = certified; B/C = commissioner sales; complex system");
+    add_schedule!("IA", "Annual", ["June"], "3rd Monday", false, "County (in-person)", "$200-$500",
+        "Highest rate in nation; bid for smallest undivided interest; must negotiate deed");
+    add_schedule!("KY", "Annual", ["July", "August"], "Varies", false, "County", "$100-$300",
+        "12% from date of issuance; smaller market");
+    add_schedule!("LA", "Varies by parish", ["June", "July"], "Varies", true, "CivicSource", "$300-$1,000",
+        "Major 2024-2025 reform: now bid-down system; previously 12% + 5% penalty");
+    add_schedule!("MD", "Annual", ["May", "June"], "Varies by county", true, "Bid4Assets", "$1,000-$2,500",
+        "Short 6-month redemption; near DC; high rates; competitive");
+    add_schedule!("NJ", "Varies by municipality", ["October", "November", "December"], "Varies", true, "Zeusauction / Bid4Assets", "$1,000-$5,000",
+        "Active market; high property values; each municipality runs own sale");
+    add_schedule!("CT", "Annual", ["June", "July"], "Varies", false, "County (in-person)", "$500-$2,000",
+        "Combined lien/deed format; larger towns only; limited availability");
+    add_schedule!("DC", "Annual", ["July"], "Varies", true, "DC Government", "$1,000-$5,000",
+        "Short 6-month redemption; premium bidding; high competition");
+    add_schedule!("MA", "Annual", ["Varies"], "Varies by town", false, "Town Collector", "$500-$2,000",
+        "Bid for smallest undivided part; complex process; high property values");
+    add_schedule!("MS", "Annual", ["August"], "Last Monday", false, "County", "$100-$500",
+        "Less competition; in-person required; smaller market");
+    add_schedule!("MO", "Annual", ["August"], "4th Monday", false, "County", "$100-$500",
+        "10% interest; 18% penalty each year delinquent; in-person sales");
+    add_schedule!("MT", "Annual", ["July"], "Varies", false, "County", "$100-$500",
+        "5/6 of 1% per month (10% annual); small market; rural properties");
+    add_schedule!("NE", "Annual", ["March"], "1st Monday", true, "GovEase / County", "$200-$500",
+        "14% annual interest; undivided interest system; moderate market");
+    add_schedule!("NH", "Annual", ["May", "June"], "Varies", false, "Town", "$500-$1,000",
+        "Bid for percentage of undivided interest; high property values");
+    add_schedule!("OK", "Annual", ["June"], "2nd Monday", false, "County", "$100-$500",
+        "Unique random drawing for ties; 8% rate is lower; in-person");
+    add_schedule!("RI", "Annual", ["December"], "Varies", false, "City/Town", "$500-$1,000",
+        "10% first 6 months, 1%/month after; Collector's Deed; small state");
+    add_schedule!("SC", "Annual", ["October", "November"], "Varies", false, "County", "$100-$500",
+        "8% penalty rather than interest; short 1-year redemption");
+    add_schedule!("SD", "Annual", ["December"], "3rd Tuesday", false, "County", "$100-$300",
+        "Bid down from 10% max; 12% statutory; long redemption");
+    add_schedule!("WV", "Annual", ["October", "November"], "Varies", false, "County", "$100-$500",
+        "12% interest; highest bidder at public auction; rural properties");
+    add_schedule!("WY", "Annual", ["September"], "Varies", false, "County", "$100-$500",
+        "Longest redemption period; 15% interest + 3% penalty + fees");
     // === DEED STATES (25) ===
-    m.insert("TX".to_string(), StateAuctionSchedule {
-        state: "TX".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Monthly".to_string(),
-        typical_months: vec!["Year-round".to_string()],
-        typical_day: "1st Tuesday of month".to_string(),
-        interest_rate: "25% penalty on redemption".to_string(),
-        redemption_period: "6 months (non-homestead) / 2 years (homestead)".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "County / RealAuction".to_string(),
-        deposit_range: "$2,000-$5,000".to_string(),
-        notes: "25% penalty if redeemed; largest deed state; no state income tax".to_string(),
-    });
-    
-    m.insert("CA".to_string(), StateAuctionSchedule {
-        state: "CA".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Varies".to_string(),
-        typical_months: vec!["March".to_string(), "April".to_string(), "September".to_string()],
-        typical_day: "Varies by county".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "5 years (pre-sale)".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "Bid4Assets".to_string(),
-        deposit_range: "$2,500-$5,000".to_string(),
-        notes: "Clear title; high values; 5-year pre-sale redemption; very competitive".to_string(),
-    });
-    
-    m.insert("MI".to_string(), StateAuctionSchedule {
-        state: "MI".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["July".to_string()],
-        typical_day: "3rd Tuesday".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "None after sale".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "GovEase / County".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "No redemption after sale; min bid = taxes + estimated FMV; Detroit challenges".to_string(),
-    });
-    
-    m.insert("OH".to_string(), StateAuctionSchedule {
-        state: "OH".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Varies".to_string(),
-        typical_months: vec!["Year-round".to_string()],
-        typical_day: "Varies by county".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "None after sale".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "GovEase / County".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "Sheriff's sale after judicial foreclosure; no redemption; large market".to_string(),
-    });
-    
-    m.insert("PA".to_string(), StateAuctionSchedule {
-        state: "PA".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Monthly/Quarterly".to_string(),
-        typical_months: vec!["Year-round".to_string()],
-        typical_day: "Varies by county".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "None after upset sale".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "Bid4Assets".to_string(),
-        deposit_range: "$500-$2,500".to_string(),
-        notes: "Upset sale then free & clear sale; no redemption after upset; Philadelphia active".to_string(),
-    });
-    
-    m.insert("NY".to_string(), StateAuctionSchedule {
-        state: "NY".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Varies".to_string(),
-        typical_months: vec!["Spring".to_string(), "Fall".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "2-4 years (varies by property type)".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "Zeusauction / County".to_string(),
-        deposit_range: "$1,000-$5,000".to_string(),
-        notes: "2yr standard; 3-4yr residential/farm; judicial process; high values".to_string(),
-    });
-    
-    m.insert("AK".to_string(), StateAuctionSchedule {
-        state: "AK".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Varies".to_string(),
-        typical_months: vec!["Varies".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "1 year".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: false,
-        primary_platform: "Borough/City".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "Municipal foreclosure; deeded to borough/city if unredeemed; limited sales".to_string(),
-    });
-    
-    m.insert("AR".to_string(), StateAuctionSchedule {
-        state: "AR".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Varies".to_string(),
-        typical_months: vec!["Varies".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "30 days".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$200-$1,000".to_string(),
-        notes: "Forfeited to state; limited warranty deed after 30 days; short redemption".to_string(),
-    });
-    
-    m.insert("DE".to_string(), StateAuctionSchedule {
-        state: "DE".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["Varies".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "15%".to_string(),
-        redemption_period: "60 days".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "Bid4Assets".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "Judicial foreclosure; 15% penalty on redemption; 60-day redemption".to_string(),
-    });
-    
-    m.insert("HI".to_string(), StateAuctionSchedule {
-        state: "HI".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Varies".to_string(),
-        typical_months: vec!["Varies".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "1 year".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$1,000-$5,000".to_string(),
-        notes: "3-year lien before auction; 1-year redemption after sale; high values".to_string(),
-    });
-    
-    m.insert("ID".to_string(), StateAuctionSchedule {
-        state: "ID".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["January".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "3 years before deed".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$200-$1,000".to_string(),
-        notes: "Tax deed to county after 3 years; then sold at auction".to_string(),
-    });
-    
-    m.insert("KS".to_string(), StateAuctionSchedule {
-        state: "KS".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Varies".to_string(),
-        typical_months: vec!["September".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "Court judgment".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$200-$1,000".to_string(),
-        notes: "Bid off to county; court petition for foreclosure".to_string(),
-    });
-    
-    m.insert("ME".to_string(), StateAuctionSchedule {
-        state: "ME".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Varies".to_string(),
-        typical_months: vec!["Varies".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "18 months".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: false,
-        primary_platform: "Town".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "Tax lien mortgage auto-forecloses after 18 months".to_string(),
-    });
-    
-    m.insert("MN".to_string(), StateAuctionSchedule {
-        state: "MN".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["May".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "None after sale".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "County".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "Tax-forfeited land auctions; cash or installment; no redemption".to_string(),
-    });
-    
-    m.insert("NC".to_string(), StateAuctionSchedule {
-        state: "NC".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Varies".to_string(),
-        typical_months: vec!["Varies".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "Upset bid period".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "Bid4Assets".to_string(),
-        deposit_range: "$500-$2,500".to_string(),
-        notes: "Judicial foreclosure or docketing certificate; upset bid period".to_string(),
-    });
-    
-    m.insert("ND".to_string(), StateAuctionSchedule {
-        state: "ND".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["October".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "Max 9%".to_string(),
-        redemption_period: "4 years".to_string(),
-        bidding_method: "Bid down interest rate".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$100-$500".to_string(),
-        notes: "Bid down from 9%; 4-year redemption from due date".to_string(),
-    });
-    
-    m.insert("NM".to_string(), StateAuctionSchedule {
-        state: "NM".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Varies".to_string(),
-        typical_months: vec!["Varies".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "120 days IRS only".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$200-$1,000".to_string(),
-        notes: "No owner redemption; Quitclaim Deed issued; IRS has 120 days".to_string(),
-    });
-    
-    m.insert("NV".to_string(), StateAuctionSchedule {
-        state: "NV".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["June".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "2 years before deed".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "County".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "Tax deed to Treasurer after 2 years; then auction; Las Vegas active".to_string(),
-    });
-    
-    m.insert("OR".to_string(), StateAuctionSchedule {
-        state: "OR".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["Varies".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "2 years".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "Foreclosure after 3 years; sold to county; 2-year redemption".to_string(),
-    });
-    
-    m.insert("TN".to_string(), StateAuctionSchedule {
-        state: "TN".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Varies".to_string(),
-        typical_months: vec!["Varies".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "1 year".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: false,
-        primary_platform: "County".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "2-year delinquent before Chancery Court suit; 1-year redemption".to_string(),
-    });
-    
-    m.insert("UT".to_string(), StateAuctionSchedule {
-        state: "UT".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["May".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "4 years".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "County".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "Preliminary sale Jan 16; final sale May 4 years later".to_string(),
-    });
-    
-    m.insert("VA".to_string(), StateAuctionSchedule {
-        state: "VA".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Varies".to_string(),
-        typical_months: vec!["Varies".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "Surplus rights only".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "Bid4Assets".to_string(),
-        deposit_range: "$1,000-$5,000".to_string(),
-        notes: "Judicial foreclosure; 3 years after due date; surplus to former owner".to_string(),
-    });
-    
-    m.insert("WA".to_string(), StateAuctionSchedule {
-        state: "WA".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["Varies".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "3 years before sale".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "Bid4Assets".to_string(),
-        deposit_range: "$500-$2,500".to_string(),
-        notes: "Certificate of delinquency after 3 years; foreclosure judgment".to_string(),
-    });
-    
-    m.insert("WI".to_string(), StateAuctionSchedule {
-        state: "WI".to_string(),
-        sale_type: "Tax Deed".to_string(),
-        frequency: "Annual".to_string(),
-        typical_months: vec!["September".to_string()],
-        typical_day: "Varies".to_string(),
-        interest_rate: "N/A".to_string(),
-        redemption_period: "2 years".to_string(),
-        bidding_method: "Highest bidder".to_string(),
-        online_available: true,
-        primary_platform: "County".to_string(),
-        deposit_range: "$500-$2,000".to_string(),
-        notes: "Tax deed after 2-year certificate; county cannot sell certificate".to_string(),
-    });
-    
+    add_schedule!("TX", "Monthly", ["Year-round"], "1st Tuesday of month", true, "County / RealAuction", "$2,000-$5,000",
+        "25% penalty if redeemed; largest deed state; no state income tax");
+    add_schedule!("CA", "Varies", ["March", "April", "September"], "Varies by county", true, "Bid4Assets", "$2,500-$5,000",
+        "Clear title; high values; 5-year pre-sale redemption; very competitive");
+    add_schedule!("MI", "Annual", ["July"], "3rd Tuesday", true, "GovEase / County", "$500-$2,000",
+        "No redemption after sale; min bid = taxes + estimated FMV; Detroit challenges");
+    add_schedule!("OH", "Varies", ["Year-round"], "Varies by county", true, "GovEase / County", "$500-$2,000",
+        "Sheriff's sale after judicial foreclosure; no redemption; large market");
+    add_schedule!("PA", "Monthly/Quarterly", ["Year-round"], "Varies by county", true, "Bid4Assets", "$500-$2,500",
+        "Upset sale then free & clear sale; no redemption after upset; Philadelphia active");
+    add_schedule!("NY", "Varies", ["Spring", "Fall"], "Varies", true, "Zeusauction / County", "$1,000-$5,000",
+        "2yr standard; 3-4yr residential/farm; judicial process; high values");
+    add_schedule!("AK", "Varies", ["Varies"], "Varies", false, "Borough/City", "$500-$2,000",
+        "Municipal foreclosure; deeded to borough/city if unredeemed; limited sales");
+    add_schedule!("AR", "Varies", ["Varies"], "Varies", false, "County", "$200-$1,000",
+        "Forfeited to state; limited warranty deed after 30 days; short redemption");
+    add_schedule!("DE", "Annual", ["Varies"], "Varies", true, "Bid4Assets", "$500-$2,000",
+        "Judicial foreclosure; 15% penalty on redemption; 60-day redemption");
+    add_schedule!("HI", "Varies", ["Varies"], "Varies", false, "County", "$1,000-$5,000",
+        "3-year lien before auction; 1-year redemption after sale; high values");
+    add_schedule!("ID", "Annual", ["January"], "Varies", false, "County", "$200-$1,000",
+        "Tax deed to county after 3 years; then sold at auction");
+    add_schedule!("KS", "Varies", ["September"], "Varies", false, "County", "$200-$1,000",
+        "Bid off to county; court petition for foreclosure");
+    add_schedule!("ME", "Varies", ["Varies"], "Varies", false, "Town", "$500-$2,000",
+        "Tax lien mortgage auto-forecloses after 18 months");
+    add_schedule!("MN", "Annual", ["May"], "Varies", true, "County", "$500-$2,000",
+        "Tax-forfeited land auctions; cash or installment; no redemption");
+    add_schedule!("NC", "Varies", ["Varies"], "Varies", true, "Bid4Assets", "$500-$2,500",
+        "Judicial foreclosure or docketing certificate; upset bid period");
+    add_schedule!("ND", "Annual", ["October"], "Varies", false, "County", "$100-$500",
+        "Bid down from 9%; 4-year redemption from due date");
+    add_schedule!("NM", "Varies", ["Varies"], "Varies", false, "County", "$200-$1,000",
+        "No owner redemption; Quitclaim Deed issued; IRS has 120 days");
+    add_schedule!("NV", "Annual", ["June"], "Varies", true, "County", "$500-$2,000",
+        "Tax deed to Treasurer after 2 years; then auction; Las Vegas active");
+    add_schedule!("OR", "Annual", ["Varies"], "Varies", false, "County", "$500-$2,000",
+        "Foreclosure after 3 years; sold to county; 2-year redemption");
+    add_schedule!("TN", "Varies", ["Varies"], "Varies", false, "County", "$500-$2,000",
+        "2-year delinquent before Chancery Court suit; 1-year redemption");
+    add_schedule!("UT", "Annual", ["May"], "Varies", true, "County", "$500-$2,000",
+        "Preliminary sale Jan 16; final sale May 4 years later");
+    add_schedule!("VT", "Annual", ["April", "May", "June", "July"], "Varies", false, "Town", "$200-$500",
+        "Redeemable deed; 12% interest if redeemed within 1 year; small rural market");
+    add_schedule!("VA", "Varies", ["Varies"], "Varies", true, "Bid4Assets", "$1,000-$5,000",
+        "Judicial foreclosure; 3 years after due date; surplus to former owner");
+    add_schedule!("WA", "Annual", ["Varies"], "Varies", true, "Bid4Assets", "$500-$2,500",
+        "Certificate of delinquency after 3 years; foreclosure judgment");
+    add_schedule!("WI", "Annual", ["September"], "Varies", true, "County", "$500-$2,000",
+        "Tax deed after 2-year certificate; county cannot sell certificate");
     m
 });
 
 // ============================================================================
 // UPCOMING AUCTIONS DATABASE - January-June 2026
+// Seed data only: loaded into the auction store on first run
 // ============================================================================
 
-pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
+pub fn seed_auctions() -> Vec<AuctionListing> {
     vec![
         // === JANUARY 2026 ===
         
@@ -918,6 +363,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Highest bidder".to_string(),
             min_bid: "Taxes owed".to_string(),
             payment_deadline: "24 hours".to_string(),
+            status_override: None,
         },
         AuctionListing {
             id: "PA-PHILA-2026-01".to_string(),
@@ -937,6 +383,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Highest bidder".to_string(),
             min_bid: "Upset amount".to_string(),
             payment_deadline: "30 days".to_string(),
+            status_override: None,
         },
         
         // New Jersey - Tax Liens
@@ -958,6 +405,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Bid down interest rate".to_string(),
             min_bid: "Taxes + fees".to_string(),
             payment_deadline: "Same day".to_string(),
+            status_override: None,
         },
         
         // === FEBRUARY 2026 ===
@@ -981,6 +429,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Highest bidder".to_string(),
             min_bid: "Judgment amount".to_string(),
             payment_deadline: "Same day".to_string(),
+            status_override: None,
         },
         AuctionListing {
             id: "TX-DALLAS-2026-02".to_string(),
@@ -1000,6 +449,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Highest bidder".to_string(),
             min_bid: "Min upset".to_string(),
             payment_deadline: "Same day".to_string(),
+            status_override: None,
         },
         AuctionListing {
             id: "TX-TARRANT-2026-02".to_string(),
@@ -1019,6 +469,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Highest bidder".to_string(),
             min_bid: "Judgment amount".to_string(),
             payment_deadline: "Same day".to_string(),
+            status_override: None,
         },
         
         // Arizona - Annual February Lien Sales
@@ -1040,6 +491,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Bid down interest rate".to_string(),
             min_bid: "Taxes + fees".to_string(),
             payment_deadline: "Varies".to_string(),
+            status_override: None,
         },
         AuctionListing {
             id: "AZ-PIMA-2026-02".to_string(),
@@ -1059,6 +511,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Bid down interest rate".to_string(),
             min_bid: "Taxes owed".to_string(),
             payment_deadline: "10 days".to_string(),
+            status_override: None,
         },
         
         // Georgia - 1st Tuesday
@@ -1080,6 +533,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Premium bidding".to_string(),
             min_bid: "Taxes + fees".to_string(),
             payment_deadline: "Same day".to_string(),
+            status_override: None,
         },
         AuctionListing {
             id: "GA-DEKALB-2026-02".to_string(),
@@ -1099,6 +553,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Premium bidding".to_string(),
             min_bid: "Taxes + fees".to_string(),
             payment_deadline: "Same day".to_string(),
+            status_override: None,
         },
         
         // === MARCH 2026 ===
@@ -1122,6 +577,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Highest bidder".to_string(),
             min_bid: "Opening bid".to_string(),
             payment_deadline: "24 hours".to_string(),
+            status_override: None,
         },
         AuctionListing {
             id: "FL-PALM-2026-03".to_string(),
@@ -1141,6 +597,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Highest bidder".to_string(),
             min_bid: "Opening bid".to_string(),
             payment_deadline: "24 hours".to_string(),
+            status_override: None,
         },
         
         // Maryland - Spring Sales
@@ -1162,6 +619,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Premium bidding".to_string(),
             min_bid: "Taxes + fees".to_string(),
             payment_deadline: "10 days".to_string(),
+            status_override: None,
         },
         
         // === MAY-JUNE 2026 ===
@@ -1185,6 +643,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Bid down interest rate".to_string(),
             min_bid: "Taxes + fees".to_string(),
             payment_deadline: "Varies".to_string(),
+            status_override: None,
         },
         AuctionListing {
             id: "FL-HILLSBOROUGH-2026-05".to_string(),
@@ -1204,6 +663,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Bid down interest rate".to_string(),
             min_bid: "Taxes + fees".to_string(),
             payment_deadline: "Varies".to_string(),
+            status_override: None,
         },
         
         // Iowa - Annual Sale (HIGHEST RATE)
@@ -1225,6 +685,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Bid down ownership percentage".to_string(),
             min_bid: "Taxes owed".to_string(),
             payment_deadline: "Same day".to_string(),
+            status_override: None,
         },
         AuctionListing {
             id: "IA-LINN-2026-06".to_string(),
@@ -1244,6 +705,7 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Bid down ownership percentage".to_string(),
             min_bid: "Taxes owed".to_string(),
             payment_deadline: "Same day".to_string(),
+            status_override: None,
         },
         
         // Illinois - Fall Sales
@@ -1265,30 +727,184 @@ pub fn get_upcoming_auctions() -> Vec<AuctionListing> {
             bidding_method: "Bid down interest rate".to_string(),
             min_bid: "Taxes + fees".to_string(),
             payment_deadline: "Varies".to_string(),
+            status_override: None,
         },
     ]
 }
 
-// Get auctions by state
-pub fn get_state_auctions(state: &str) -> Vec<AuctionListing> {
-    let state_upper = state.to_uppercase();
-    get_upcoming_auctions()
-        .into_iter()
-        .filter(|a| a.state == state_upper)
-        .collect()
+// Static platform list used to seed the auction store
+pub fn seed_platforms() -> Vec<AuctionPlatform> {
+    AUCTION_PLATFORMS.clone()
 }
 
-// Get all auction platforms
-pub fn get_platforms() -> Vec<AuctionPlatform> {
-    AUCTION_PLATFORMS.clone()
+// Static state schedules used to seed the auction store
+pub fn seed_schedules() -> Vec<StateAuctionSchedule> {
+    let mut schedules: Vec<StateAuctionSchedule> = STATE_SCHEDULES.values().cloned().collect();
+    schedules.sort_by(|a, b| a.state.cmp(&b.state));
+    schedules
 }
 
-// Get state auction schedule/rules
-pub fn get_state_schedule(state: &str) -> Option<StateAuctionSchedule> {
-    STATE_SCHEDULES.get(&state.to_uppercase()).cloned()
+// ============================================================================
+// LISTING VALIDATION
+// ============================================================================
+
+// County-run sales are listed with platform "County" rather than a vendor
+pub const COUNTY_PLATFORM: &str = "County";
+
+pub const AUCTION_TYPES: [&str; 3] = ["Online", "In-Person", "Hybrid"];
+
+pub fn is_known_state(state: &str) -> bool {
+    STATE_SCHEDULES.contains_key(&state.to_uppercase())
+}
+
+// Canonical form for user-supplied listings (admin API and bulk import)
+pub fn normalize_listing(listing: &mut AuctionListing) {
+    listing.id = listing.id.trim().to_string();
+    listing.state = listing.state.trim().to_uppercase();
+    listing.county = listing.county.trim().to_string();
+    listing.platform = listing.platform.trim().to_string();
+}
+
+fn parse_iso_date(field: &str, value: &str, errors: &mut Vec<String>) -> Option<chrono::NaiveDate> {
+    match chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        Ok(date) => Some(date),
+        Err(_) => {
+            errors.push(format!("{} must be an ISO date (YYYY-MM-DD), got '{}'", field, value));
+            None
+        }
+    }
+}
+
+// Check a listing before it is written to the store; returns every problem found
+pub fn validate_listing(listing: &AuctionListing, known_platforms: &[String]) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    if listing.id.trim().is_empty() {
+        errors.push("id is required".to_string());
+    }
+    if listing.county.trim().is_empty() {
+        errors.push("county is required".to_string());
+    }
+    if listing.sale_type.trim().is_empty() {
+        errors.push("sale_type is required".to_string());
+    }
+    if !is_known_state(&listing.state) {
+        errors.push(format!("state '{}' is not a known jurisdiction", listing.state));
+    }
+    if listing.platform != COUNTY_PLATFORM && !known_platforms.iter().any(|p| p == &listing.platform) {
+        errors.push(format!(
+            "platform '{}' is not a known auction platform (expected '{}' or one of: {})",
+            listing.platform,
+            COUNTY_PLATFORM,
+            known_platforms.join(", ")
+        ));
+    }
+    if !AUCTION_TYPES.contains(&listing.auction_type.as_str()) {
+        errors.push(format!(
+            "auction_type must be one of {}, got '{}'",
+            AUCTION_TYPES.join("/"),
+            listing.auction_type
+        ));
+    }
+    if listing.property_count < 0 {
+        errors.push("property_count cannot be negative".to_string());
+    }
+    if let Some(status) = listing.status_override {
+        if !status.is_manual() {
+            errors.push(format!(
+                "status_override can only be Cancelled or Postponed, got '{}'",
+                status.label()
+            ));
+        }
+    }
+    if listing.deposit_required < 0.0 || !listing.deposit_required.is_finite() {
+        errors.push("deposit_required must be a non-negative amount".to_string());
+    }
+
+    let sale_date = parse_iso_date("sale_date", &listing.sale_date, &mut errors);
+    let deadline = parse_iso_date("registration_deadline", &listing.registration_deadline, &mut errors);
+    if let (Some(sale), Some(deadline)) = (sale_date, deadline) {
+        if deadline >= sale {
+            errors.push(format!(
+                "registration_deadline ({}) must be before sale_date ({})",
+                listing.registration_deadline, listing.sale_date
+            ));
+        }
+    }
+
+    errors
 }
 
-// Get all state schedules
-pub fn get_all_schedules() -> Vec<StateAuctionSchedule> {
-    STATE_SCHEDULES.values().cloned().collect()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing() -> AuctionListing {
+        seed_auctions().remove(0)
+    }
+
+    fn platforms() -> Vec<String> {
+        seed_platforms().into_iter().map(|p| p.name).collect()
+    }
+
+    #[test]
+    fn seed_listings_are_valid() {
+        for listing in seed_auctions() {
+            assert_eq!(validate_listing(&listing, &platforms()), Vec::<String>::new(), "{}", listing.id);
+        }
+    }
+
+    #[test]
+    fn normalizes_user_input() {
+        let mut l = AuctionListing { id: " X-1 ".to_string(), state: " fl ".to_string(), county: " Lee ".to_string(), platform: " County ".to_string(), ..listing() };
+        normalize_listing(&mut l);
+        assert_eq!((l.id.as_str(), l.state.as_str(), l.county.as_str(), l.platform.as_str()), ("X-1", "FL", "Lee", "County"));
+    }
+
+    #[test]
+    fn reports_every_problem() {
+        let bad = AuctionListing {
+            id: " ".to_string(),
+            county: String::new(),
+            sale_type: String::new(),
+            state: "ZZ".to_string(),
+            platform: "eBay".to_string(),
+            auction_type: "Mail".to_string(),
+            property_count: -1,
+            deposit_required: f64::NAN,
+            status_override: Some(AuctionStatus::Completed),
+            sale_date: "2026-01-14".to_string(),
+            registration_deadline: "2026-01-14".to_string(),
+            ..listing()
+        };
+        let errors = validate_listing(&bad, &platforms());
+        assert_eq!(errors.len(), 10, "{:?}", errors);
+        assert!(errors.contains(&"registration_deadline (2026-01-14) must be before sale_date (2026-01-14)".to_string()));
+        assert!(errors.contains(&"status_override can only be Cancelled or Postponed, got 'Completed'".to_string()));
+
+        let dates = AuctionListing { sale_date: "Jan 14".to_string(), registration_deadline: "2026-13-01".to_string(), ..listing() };
+        assert_eq!(validate_listing(&dates, &platforms()), [
+            "sale_date must be an ISO date (YYYY-MM-DD), got 'Jan 14'",
+            "registration_deadline must be an ISO date (YYYY-MM-DD), got '2026-13-01'",
+        ]);
+    }
+
+    #[test]
+    fn status_follows_the_calendar() {
+        // PA-MONROE-2026-01: registration closes 2026-01-07, sale on 2026-01-14
+        let monroe = listing();
+        let on = |day: &str| listing_status(&monroe, chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d").unwrap());
+        assert_eq!(on("2026-01-01"), AuctionStatus::RegistrationOpen);
+        assert_eq!(on("2026-01-07"), AuctionStatus::RegistrationOpen);
+        assert_eq!(on("2026-01-08"), AuctionStatus::RegistrationClosed);
+        assert_eq!(on("2026-01-14"), AuctionStatus::LiveToday);
+        assert_eq!(on("2026-01-15"), AuctionStatus::Completed);
+
+        let postponed = AuctionListing { status_override: Some(AuctionStatus::Postponed), ..listing() };
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+        assert_eq!(listing_status(&postponed, today), AuctionStatus::Postponed);
+        assert_eq!(AuctionStatus::parse("live-today"), Some(AuctionStatus::LiveToday));
+        assert_eq!(AuctionStatus::parse("Registration Open"), Some(AuctionStatus::RegistrationOpen));
+        assert_eq!(AuctionStatus::parse("open"), None);
+    }
 }
@@ -0,0 +1,301 @@
+// Scoring Backtest - Grade counties as of past months and compare with what ZHVI did next
+// Only ZHVI-derived factors exist historically, so each as-of score uses the home value and
+// trailing 12-month growth known at that date; other factors drop out rather than leak the future
+
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::scoring;
+use crate::zhvi_history::ZhviHistory;
+
+// Offline fixture in Zillow's county CSV layout (synthetic values); pass a downloaded ZHVI file for real results
+pub const DEFAULT_FIXTURE: &str = "fixtures/zhvi_county_sample.csv";
+
+// Forward windows, in months
+pub const HORIZONS: [usize; 3] = [12, 24, 36];
+
+#[derive(Debug, Serialize)]
+pub struct TierReturns {
+    pub tier: u8,
+    pub name: String,
+    pub counties: usize,
+    pub mean_return_pct: f64,
+    // Share of the tier that beat the median county over the window
+    pub hit_rate_pct: f64,
+    // Share of the tier that gained value at all
+    pub positive_rate_pct: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HorizonResult {
+    pub months: usize,
+    pub counties: usize,
+    pub median_return_pct: f64,
+    // Spearman correlation of score with forward return; positive means higher scores did better
+    pub rank_correlation: Option<f64>,
+    pub tiers: Vec<TierReturns>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AsOfResult {
+    pub as_of: String,
+    pub counties_scored: usize,
+    pub horizons: Vec<HorizonResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BacktestReport {
+    pub model_version: String,
+    pub factors_scored: Vec<String>,
+    pub periods: Vec<AsOfResult>,
+    // Per-horizon results pooled across every as-of date
+    pub pooled: Vec<HorizonResult>,
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let mid = sorted.len() / 2;
+    if sorted.is_empty() {
+        0.0
+    } else if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+// 1-based ranks, ties sharing their average rank
+fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+    let mut result = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start;
+        while end + 1 < order.len() && values[order[end + 1]] == values[order[start]] {
+            end += 1;
+        }
+        let average = (start + end) as f64 / 2.0 + 1.0;
+        for &i in &order[start..=end] {
+            result[i] = average;
+        }
+        start = end + 1;
+    }
+    result
+}
+
+pub fn spearman(x: &[f64], y: &[f64]) -> Option<f64> {
+    if x.len() != y.len() || x.len() < 3 {
+        return None;
+    }
+    let (rx, ry) = (ranks(x), ranks(y));
+    let n = rx.len() as f64;
+    let (mx, my) = (rx.iter().sum::<f64>() / n, ry.iter().sum::<f64>() / n);
+    let cov: f64 = rx.iter().zip(&ry).map(|(a, b)| (a - mx) * (b - my)).sum();
+    let vx: f64 = rx.iter().map(|a| (a - mx).powi(2)).sum();
+    let vy: f64 = ry.iter().map(|b| (b - my).powi(2)).sum();
+    if vx == 0.0 || vy == 0.0 {
+        return None;
+    }
+    Some(cov / (vx * vy).sqrt())
+}
+
+// (score, tier, forward return %) for one county at one horizon
+type Outcome = (f64, u8, f64);
+
+fn summarize(months: usize, outcomes: &[Outcome]) -> HorizonResult {
+    let returns: Vec<f64> = outcomes.iter().map(|o| o.2).collect();
+    let scores: Vec<f64> = outcomes.iter().map(|o| o.0).collect();
+    let median_return = median(&returns);
+
+    let mut by_tier: HashMap<u8, Vec<f64>> = HashMap::new();
+    for &(_, tier, ret) in outcomes {
+        by_tier.entry(tier).or_default().push(ret);
+    }
+    let mut tiers: Vec<TierReturns> = scoring::model()
+        .tiers
+        .iter()
+        .filter_map(|band| {
+            let returns = by_tier.get(&band.tier)?;
+            let n = returns.len() as f64;
+            Some(TierReturns {
+                tier: band.tier,
+                name: band.name.clone(),
+                counties: returns.len(),
+                mean_return_pct: round2(returns.iter().sum::<f64>() / n),
+                hit_rate_pct: round2(returns.iter().filter(|r| **r > median_return).count() as f64 / n * 100.0),
+                positive_rate_pct: round2(returns.iter().filter(|r| **r > 0.0).count() as f64 / n * 100.0),
+            })
+        })
+        .collect();
+    tiers.sort_by_key(|t| t.tier);
+
+    HorizonResult {
+        months,
+        counties: outcomes.len(),
+        median_return_pct: round2(median_return),
+        rank_correlation: spearman(&scores, &returns).map(|r| (r * 1000.0).round() / 1000.0),
+        tiers,
+    }
+}
+
+// Every December with a year of trailing history and at least the shortest window ahead
+pub fn default_as_of(history: &ZhviHistory) -> Vec<NaiveDate> {
+    let shortest = HORIZONS[0];
+    history
+        .dates
+        .iter()
+        .enumerate()
+        .filter(|(i, d)| d.month() == 12 && *i >= 12 && i + shortest < history.dates.len())
+        .map(|(_, d)| *d)
+        .collect()
+}
+
+pub fn run(history: &ZhviHistory, as_of: &[NaiveDate]) -> Result<BacktestReport, String> {
+    if as_of.is_empty() {
+        return Err("No as-of dates with a year of history before them and 12 months after".to_string());
+    }
+    let mut periods = Vec::new();
+    let mut pooled: HashMap<usize, Vec<Outcome>> = HashMap::new();
+
+    for &date in as_of {
+        let month = history
+            .month_index(date)
+            .ok_or_else(|| format!("{} is before the first ZHVI month", date))?;
+        let graded: Vec<(usize, f64, u8)> = history
+            .counties
+            .iter()
+            .enumerate()
+            .filter_map(|(i, county)| {
+                let values = HashMap::from([
+                    (scoring::MEDIAN_HOME_VALUE, county.value_at(month)?),
+                    (scoring::GROWTH_YOY, county.change_pct(month, 12)?),
+                ]);
+                let score = scoring::score(&values);
+                Some((i, score.score, score.tier.tier))
+            })
+            .collect();
+
+        let horizons = HORIZONS
+            .iter()
+            .filter(|&&h| month + h < history.dates.len())
+            .map(|&h| {
+                let outcomes: Vec<Outcome> = graded
+                    .iter()
+                    .filter_map(|&(i, score, tier)| Some((score, tier, history.counties[i].change_pct(month + h, h)?)))
+                    .collect();
+                pooled.entry(h).or_default().extend(&outcomes);
+                summarize(h, &outcomes)
+            })
+            .collect();
+
+        periods.push(AsOfResult {
+            as_of: history.dates[month].to_string(),
+            counties_scored: graded.len(),
+            horizons,
+        });
+    }
+
+    let mut pooled: Vec<HorizonResult> = pooled.iter().map(|(&h, outcomes)| summarize(h, outcomes)).collect();
+    pooled.sort_by_key(|r| r.months);
+
+    Ok(BacktestReport {
+        model_version: scoring::model().version.clone(),
+        factors_scored: vec![scoring::MEDIAN_HOME_VALUE.to_string(), scoring::GROWTH_YOY.to_string()],
+        periods,
+        pooled,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zhvi_history;
+
+    const FIXTURE: &str = include_str!("../fixtures/zhvi_county_sample.csv");
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn median_of_odd_even_and_empty() {
+        assert_eq!(median(&[3.0, 1.0, 2.0]), 2.0);
+        assert_eq!(median(&[4.0, 1.0, 3.0, 2.0]), 2.5);
+        assert_eq!(median(&[-1.5]), -1.5);
+        assert_eq!(median(&[]), 0.0);
+    }
+
+    #[test]
+    fn ties_share_their_average_rank() {
+        assert_eq!(ranks(&[10.0, 30.0, 20.0]), [1.0, 3.0, 2.0]);
+        assert_eq!(ranks(&[5.0, 1.0, 5.0, 5.0, 0.0]), [4.0, 2.0, 4.0, 4.0, 1.0]);
+        assert!(ranks(&[]).is_empty());
+    }
+
+    #[test]
+    fn spearman_correlation() {
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(spearman(&x, &[10.0, 20.0, 25.0, 100.0, 1000.0]), Some(1.0));
+        assert_eq!(spearman(&x, &[5.0, 4.0, 3.0, 2.0, 1.0]), Some(-1.0));
+        // d = [0, 0, -1, 1, 0]: 1 - 6 * 2 / (5 * 24)
+        let r = spearman(&x, &[1.0, 2.0, 4.0, 3.0, 5.0]).unwrap();
+        assert!((r - 0.9).abs() < 1e-12, "{}", r);
+
+        assert_eq!(spearman(&[1.0, 2.0], &[2.0, 1.0]), None, "too few points");
+        assert_eq!(spearman(&x, &[1.0, 2.0, 3.0]), None, "length mismatch");
+        assert_eq!(spearman(&x, &[7.0; 5]), None, "no variance");
+    }
+
+    #[test]
+    fn runs_on_the_fixture() {
+        let history = zhvi_history::parse(FIXTURE).unwrap();
+        let as_of = default_as_of(&history);
+        let years: Vec<i32> = as_of.iter().map(|d| d.year()).collect();
+        assert_eq!(years, (2015..=2023).collect::<Vec<_>>());
+        assert!(as_of.iter().all(|d| d.month() == 12));
+
+        let report = run(&history, &as_of).unwrap();
+        assert_eq!(report.periods.len(), 9);
+        assert_eq!(report.factors_scored, [scoring::MEDIAN_HOME_VALUE, scoring::GROWTH_YOY]);
+
+        // Only windows that end inside the data are measured
+        let last = report.periods.last().unwrap();
+        assert_eq!(last.as_of, "2023-12-31");
+        assert_eq!(last.horizons.iter().map(|h| h.months).collect::<Vec<_>>(), [12]);
+        assert_eq!(report.periods[0].horizons.iter().map(|h| h.months).collect::<Vec<_>>(), HORIZONS);
+
+        // Two fixture counties start in March 2015, too late for trailing growth in December 2015
+        let scored: Vec<usize> = report.periods.iter().map(|p| p.counties_scored).collect();
+        assert_eq!(scored, [35, 37, 37, 37, 37, 37, 37, 37, 37]);
+        let pooled: Vec<(usize, usize)> = report.pooled.iter().map(|h| (h.months, h.counties)).collect();
+        assert_eq!(pooled, [(12, 9 * 37 - 2), (24, 8 * 37 - 2), (36, 7 * 37 - 2)]);
+        for horizon in report.periods.iter().flat_map(|p| &p.horizons).chain(&report.pooled) {
+            assert_eq!(horizon.tiers.iter().map(|t| t.counties).sum::<usize>(), horizon.counties);
+            assert!(horizon.tiers.windows(2).all(|w| w[0].tier < w[1].tier));
+            let r = horizon.rank_correlation.expect("scores and returns vary");
+            assert!((-1.0..=1.0).contains(&r));
+            for tier in &horizon.tiers {
+                assert!((0.0..=100.0).contains(&tier.hit_rate_pct) && (0.0..=100.0).contains(&tier.positive_rate_pct));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_unusable_dates() {
+        let history = zhvi_history::parse(FIXTURE).unwrap();
+        assert!(run(&history, &[]).unwrap_err().starts_with("No as-of dates"));
+        assert_eq!(run(&history, &[date("2010-12-31")]).unwrap_err(), "2010-12-31 is before the first ZHVI month");
+
+        // Too late for any window: scored, but nothing to measure
+        let report = run(&history, &[date("2024-12-31")]).unwrap();
+        assert!(report.periods[0].horizons.is_empty());
+        assert!(report.pooled.is_empty());
+    }
+}
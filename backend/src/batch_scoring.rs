@@ -0,0 +1,415 @@
+// Batch Scoring - Score and rank many counties in one request
+// Rows arrive as a JSON array or a CSV keyed by FIPS; blanks are filled from Census where possible
+
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+
+use crate::census::CountyCensusData;
+use crate::payload_format::PayloadFormat;
+use crate::scoring;
+
+pub const MAX_ROWS: usize = 10_000;
+
+// One county to score: every factor is optional so partial sheets still grade
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct BatchRow {
+    #[serde(deserialize_with = "fips_cell")]
+    pub fips: Option<String>,
+    pub name: Option<String>,
+    #[serde(deserialize_with = "number_cell")]
+    pub population: Option<f64>,
+    #[serde(deserialize_with = "number_cell")]
+    pub median_income: Option<f64>,
+    #[serde(deserialize_with = "number_cell")]
+    pub median_home_value: Option<f64>,
+    #[serde(deserialize_with = "number_cell")]
+    pub growth_yoy: Option<f64>,
+    #[serde(deserialize_with = "number_cell")]
+    pub days_on_market: Option<f64>,
+    #[serde(deserialize_with = "number_cell")]
+    pub transaction_volume: Option<f64>,
+    #[serde(deserialize_with = "number_cell")]
+    pub employment_rate: Option<f64>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberCell {
+    Number(f64),
+    Text(String),
+}
+
+// JSON number, or spreadsheet text like "$62,000" / "4.5%"; blank = missing
+fn number_cell<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    match Option::<NumberCell>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(NumberCell::Number(n)) => Ok(Some(n)),
+        Some(NumberCell::Text(text)) => {
+            let cleaned: String = text.chars().filter(|c| !matches!(c, '$' | ',' | '%' | ' ')).collect();
+            if cleaned.is_empty() {
+                return Ok(None);
+            }
+            cleaned.parse().map(Some).map_err(|_| D::Error::custom(format!("'{}' is not a number", text)))
+        }
+    }
+}
+
+// FIPS may be typed as a number in JSON (6037) as well as text
+fn fips_cell<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    Ok(match Option::<NumberCell>::deserialize(deserializer)? {
+        None => None,
+        Some(NumberCell::Number(n)) if n.fract() == 0.0 && n >= 0.0 => Some(format!("{}", n as u64)),
+        Some(NumberCell::Number(n)) => Some(n.to_string()),
+        Some(NumberCell::Text(text)) => Some(text),
+    })
+}
+
+impl BatchRow {
+    fn factor_values(&self) -> scoring::FactorValues {
+        [
+            (scoring::POPULATION, self.population),
+            (scoring::MEDIAN_INCOME, self.median_income),
+            (scoring::MEDIAN_HOME_VALUE, self.median_home_value),
+            (scoring::GROWTH_YOY, self.growth_yoy),
+            (scoring::DAYS_ON_MARKET, self.days_on_market),
+            (scoring::TRANSACTION_VOLUME, self.transaction_volume),
+            (scoring::EMPLOYMENT_RATE, self.employment_rate),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|v| (name, v)))
+        .collect()
+    }
+
+    // True when a Census lookup could supply a value this row lacks
+    pub fn needs_census(&self) -> bool {
+        self.fips.is_some()
+            && (self.population.is_none() || self.median_income.is_none() || self.median_home_value.is_none())
+    }
+
+    // Fill blank Census-backed factors; returns the factor names that were filled
+    fn fill_from_census(&mut self, county: &CountyCensusData) -> Vec<String> {
+        let mut filled = Vec::new();
+        let mut fill = |slot: &mut Option<f64>, name: &str, value: i64| {
+            if slot.is_none() && value > 0 {
+                *slot = Some(value as f64);
+                filled.push(name.to_string());
+            }
+        };
+        fill(&mut self.population, scoring::POPULATION, county.population);
+        fill(&mut self.median_income, scoring::MEDIAN_INCOME, county.median_income);
+        fill(&mut self.median_home_value, scoring::MEDIAN_HOME_VALUE, county.median_home_value);
+        if self.name.is_none() {
+            self.name = Some(format!("{}, {}", county.name, county.state));
+        }
+        filled
+    }
+}
+
+// Spreadsheets drop leading zeros, so "6037" is Los Angeles (06037)
+fn normalize_fips(raw: &str) -> Result<String, String> {
+    let fips = raw.trim();
+    if fips.is_empty() || fips.len() > 5 || !fips.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("'{}' is not a 5-digit county FIPS code", raw));
+    }
+    Ok(format!("{:0>5}", fips))
+}
+
+// Decode the payload into per-row results; a row that can't be read is an error for that row only
+pub fn parse_rows(format: PayloadFormat, body: &str) -> Result<Vec<Result<BatchRow, String>>, String> {
+    let rows: Vec<Result<BatchRow, String>> = match format {
+        PayloadFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_reader(body.as_bytes());
+            let headers = reader.headers().map_err(|e| format!("Invalid CSV header: {}", e))?;
+            let headers = csv::StringRecord::from(
+                headers.iter().map(|h| h.to_lowercase()).collect::<Vec<_>>(),
+            );
+            if !headers.iter().any(|h| h == "fips") {
+                return Err("CSV header must include a 'fips' column".to_string());
+            }
+            reader
+                .records()
+                .map(|record| {
+                    record
+                        .and_then(|r| r.deserialize::<BatchRow>(Some(&headers)))
+                        .map_err(|e| format!("Unreadable row: {}", e))
+                })
+                .collect()
+        }
+        PayloadFormat::Json => {
+            let values: Vec<serde_json::Value> = serde_json::from_str(body)
+                .map_err(|e| format!("Expected a JSON array of county inputs: {}", e))?;
+            values
+                .into_iter()
+                .map(|v| serde_json::from_value::<BatchRow>(v).map_err(|e| format!("Unreadable row: {}", e)))
+                .collect()
+        }
+    };
+    if rows.len() > MAX_ROWS {
+        return Err(format!("At most {} rows can be scored per request", MAX_ROWS));
+    }
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let mut row = row?;
+            row.fips = row.fips.as_deref().filter(|f| !f.trim().is_empty()).map(normalize_fips).transpose()?;
+            Ok(row)
+        })
+        .collect())
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchResult {
+    // 1-based position in the input
+    pub row: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fips: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub score: Option<f64>,
+    pub tier: Option<u8>,
+    pub tier_name: Option<String>,
+    // 1 = highest score; tied scores share a rank
+    pub rank: Option<usize>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub factors_missing: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub factors_from_census: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchResponse {
+    pub model_version: String,
+    pub total_rows: usize,
+    pub scored: usize,
+    pub invalid: usize,
+    pub results: Vec<BatchResult>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+// Score every readable row, then rank by score; results stay in input order
+pub fn score_rows(
+    rows: Vec<Result<BatchRow, String>>,
+    census: Option<&HashMap<String, CountyCensusData>>,
+    warnings: Vec<String>,
+) -> BatchResponse {
+    let mut results: Vec<BatchResult> = rows
+        .into_iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut result = BatchResult {
+                row: i + 1,
+                fips: None,
+                name: None,
+                score: None,
+                tier: None,
+                tier_name: None,
+                rank: None,
+                factors_missing: Vec::new(),
+                factors_from_census: Vec::new(),
+                error: None,
+            };
+            let mut row = match row {
+                Ok(row) => row,
+                Err(e) => {
+                    result.error = Some(e);
+                    return result;
+                }
+            };
+            if let (Some(fips), Some(census)) = (&row.fips, census) {
+                if let Some(county) = census.get(fips) {
+                    result.factors_from_census = row.fill_from_census(county);
+                }
+            }
+
+            let values = row.factor_values();
+            if values.is_empty() {
+                result.fips = row.fips;
+                result.error = Some("no factor values to score".to_string());
+                return result;
+            }
+            let graded = scoring::score(&values);
+            result.fips = row.fips;
+            result.name = row.name;
+            result.score = Some((graded.score * 100.0).round() / 100.0);
+            result.tier = Some(graded.tier.tier);
+            result.tier_name = Some(graded.tier.name.clone());
+            result.factors_missing = graded.factors_missing;
+            result
+        })
+        .collect();
+
+    let mut order: Vec<usize> = (0..results.len()).filter(|&i| results[i].score.is_some()).collect();
+    order.sort_by(|&a, &b| results[b].score.unwrap_or(0.0).total_cmp(&results[a].score.unwrap_or(0.0)));
+    let mut previous: Option<(f64, usize)> = None;
+    for (position, &i) in order.iter().enumerate() {
+        let score = results[i].score.unwrap_or(0.0);
+        let rank = match previous {
+            Some((prev_score, prev_rank)) if prev_score == score => prev_rank,
+            _ => position + 1,
+        };
+        results[i].rank = Some(rank);
+        previous = Some((score, rank));
+    }
+
+    let scored = order.len();
+    BatchResponse {
+        model_version: scoring::model().version.clone(),
+        total_rows: results.len(),
+        scored,
+        invalid: results.len() - scored,
+        results,
+        warnings,
+    }
+}
+
+// Flat CSV of the results, one line per input row
+pub fn to_csv(response: &BatchResponse) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let write_err = |e: csv::Error| format!("Failed to write CSV: {}", e);
+    writer
+        .write_record([
+            "row", "fips", "name", "score", "tier", "tier_name", "rank", "factors_missing", "error", "model_version",
+        ])
+        .map_err(write_err)?;
+    let text = |value: Option<String>| value.unwrap_or_default();
+    for r in &response.results {
+        writer
+            .write_record([
+                r.row.to_string(),
+                text(r.fips.clone()),
+                text(r.name.clone()),
+                text(r.score.map(|s| s.to_string())),
+                text(r.tier.map(|t| t.to_string())),
+                text(r.tier_name.clone()),
+                text(r.rank.map(|n| n.to_string())),
+                r.factors_missing.join(";"),
+                text(r.error.clone()),
+                response.model_version.clone(),
+            ])
+            .map_err(write_err)?;
+    }
+    let bytes = writer.into_inner().map_err(|e| format!("Failed to write CSV: {}", e))?;
+    String::from_utf8(bytes).map_err(|e| format!("Failed to write CSV: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rows(format: PayloadFormat, body: &str) -> Vec<Result<BatchRow, String>> {
+        parse_rows(format, body).unwrap()
+    }
+
+    fn county(fips: &str, population: i64) -> CountyCensusData {
+        serde_json::from_value(json!({
+            "name": "Los Angeles County", "state": "CA", "fips": fips, "population": population,
+            "median_income": 0, "median_home_value": 820000, "total_housing_units": 0, "vacant_units": 0,
+            "tier": 1, "score": 0.0,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn pads_fips_codes() {
+        let csv = rows(PayloadFormat::Csv, "FIPS,Population\n6037,\"10,000,000\"\n 01001 ,\n,5\n123456,\n6O37,\n");
+        let fips: Vec<Result<Option<String>, String>> = csv.into_iter().map(|r| r.map(|r| r.fips)).collect();
+        assert_eq!(fips[0], Ok(Some("06037".to_string())));
+        assert_eq!(fips[1], Ok(Some("01001".to_string())));
+        assert_eq!(fips[2], Ok(None));
+        assert_eq!(fips[3], Err("'123456' is not a 5-digit county FIPS code".to_string()));
+        assert_eq!(fips[4], Err("'6O37' is not a 5-digit county FIPS code".to_string()));
+
+        let body = json!([{"fips": 6037}, {"fips": "6037"}, {"fips": 60.37}]).to_string();
+        let fips: Vec<Result<Option<String>, String>> = rows(PayloadFormat::Json, &body).into_iter().map(|r| r.map(|r| r.fips)).collect();
+        assert_eq!(fips[0], Ok(Some("06037".to_string())));
+        assert_eq!(fips[1], Ok(Some("06037".to_string())));
+        assert!(fips[2].is_err());
+    }
+
+    #[test]
+    fn reads_spreadsheet_numbers() {
+        let body = "fips,median_income,growth_yoy,days_on_market\n06037,\"$62,000\",4.5%,\n06059,lots,1,2\n";
+        let parsed = rows(PayloadFormat::Csv, body);
+        let first = parsed[0].as_ref().unwrap();
+        assert_eq!((first.median_income, first.growth_yoy, first.days_on_market), (Some(62000.0), Some(4.5), None));
+        assert!(parsed[1].as_ref().unwrap_err().contains("'lots' is not a number"));
+    }
+
+    #[test]
+    fn rejects_bad_payloads() {
+        assert_eq!(parse_rows(PayloadFormat::Csv, "name,population\nX,1\n").err().unwrap(), "CSV header must include a 'fips' column");
+        assert!(parse_rows(PayloadFormat::Json, "{}").err().unwrap().starts_with("Expected a JSON array"));
+
+        let at_limit = format!("fips\n{}", "06037\n".repeat(MAX_ROWS));
+        assert_eq!(parse_rows(PayloadFormat::Csv, &at_limit).unwrap().len(), MAX_ROWS);
+        let over = format!("fips\n{}", "06037\n".repeat(MAX_ROWS + 1));
+        assert_eq!(parse_rows(PayloadFormat::Csv, &over).err().unwrap(), format!("At most {} rows can be scored per request", MAX_ROWS));
+        let json_over = serde_json::Value::Array(vec![json!({}); MAX_ROWS + 1]).to_string();
+        assert!(parse_rows(PayloadFormat::Json, &json_over).is_err());
+    }
+
+    #[test]
+    fn ties_share_a_rank() {
+        let body = json!([
+            {"fips": "00001", "population": 100000, "growth_yoy": 2},
+            {"fips": "00002", "population": 900000, "growth_yoy": 9},
+            {"fips": "00003", "population": 100000, "growth_yoy": 2},
+            {"fips": "00004"},
+            {"fips": "00005", "population": 50000, "growth_yoy": -1},
+        ])
+        .to_string();
+        let response = score_rows(rows(PayloadFormat::Json, &body), None, vec![]);
+        let ranks: Vec<Option<usize>> = response.results.iter().map(|r| r.rank).collect();
+        assert_eq!(ranks, [Some(2), Some(1), Some(2), None, Some(4)]);
+        assert_eq!(response.results[0].score, response.results[2].score);
+        assert_eq!(response.results[3].error.as_deref(), Some("no factor values to score"));
+        assert_eq!((response.total_rows, response.scored, response.invalid), (5, 4, 1));
+        assert_eq!(response.results.iter().map(|r| r.row).collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn fills_blanks_from_census() {
+        let census: HashMap<String, CountyCensusData> = [("06037".to_string(), county("06037", 9_800_000))].into();
+        let body = json!([{"fips": "6037", "median_home_value": 100000}, {"fips": "99999"}]).to_string();
+        let parsed = rows(PayloadFormat::Json, &body);
+        assert!(parsed.iter().all(|r| r.as_ref().unwrap().needs_census()));
+
+        let response = score_rows(parsed, Some(&census), vec!["note".to_string()]);
+        let la = &response.results[0];
+        // Census zeros are suppressed values and stay blank; typed values are never overwritten
+        assert_eq!(la.factors_from_census, [scoring::POPULATION]);
+        assert_eq!(la.name.as_deref(), Some("Los Angeles County, CA"));
+        assert!(la.factors_missing.contains(&scoring::MEDIAN_INCOME.to_string()));
+        assert!(response.results[1].error.is_some());
+        assert_eq!(response.warnings, ["note"]);
+    }
+
+    #[test]
+    fn writes_one_csv_line_per_row() {
+        let body = "fips,name,population,growth_yoy\n06037,\"Los Angeles, CA\",9800000,3\n,Blank,,\n";
+        let response = score_rows(rows(PayloadFormat::Csv, body), None, vec![]);
+        let csv = to_csv(&response).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "row,fips,name,score,tier,tier_name,rank,factors_missing,error,model_version");
+
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+        let records: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+        let la = &response.results[0];
+        assert_eq!(&records[0][1], "06037");
+        assert_eq!(&records[0][2], "Los Angeles, CA");
+        assert_eq!(records[0][3].parse::<f64>().unwrap(), la.score.unwrap());
+        assert_eq!(&records[0][6], "1");
+        assert_eq!(&records[0][7], la.factors_missing.join(";"));
+        assert_eq!(&records[0][9], response.model_version);
+        assert_eq!((&records[1][3], &records[1][6], &records[1][8]), ("", "", "no factor values to score"));
+    }
+}
@@ -1,15 +1,104 @@
-// Census API Client - Fetch county data for all US counties
+// Census API Client - Fetch county data for all US counties, and tract / ZCTA drill-downs
 // Documentation: https://api.census.gov/data/2022/acs/acs5.html
+// Any ACS 5-year vintage from 2019 to 2023 can be requested, with a core or extended variable set.
 
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
 use once_cell::sync::Lazy;
 
-// Census API endpoint
-const CENSUS_API_BASE: &str = "https://api.census.gov/data/2022/acs/acs5";
+use crate::data_cache;
+use crate::scoring;
+
+// Census API endpoint; the vintage goes between the two
+const CENSUS_API_BASE: &str = "https://api.census.gov/data";
+
+// ACS 5-year vintages every variable below is published for
+pub const VINTAGES: std::ops::RangeInclusive<u16> = 2019..=2023;
+pub const DEFAULT_VINTAGE: u16 = 2022;
+
+// Census API variables:
+// B01003_001E = Total population
+// B19013_001E = Median household income
+// B25077_001E = Median home value
+// B25001_001E = Total housing units
+// B25002_003E = Vacant housing units
+const CORE_VARIABLES: [&str; 5] = ["B01003_001E", "B19013_001E", "B25077_001E", "B25001_001E", "B25002_003E"];
+
+// Extended set:
+// B25003_001E / B25003_002E = Occupied housing units / owner occupied
+// B25064_001E = Median gross rent
+// B17001_001E / B17001_002E = Population with poverty status determined / below poverty level
+// B25035_001E = Median year structure built
+// B25081_001E / B25081_002E = Owner-occupied units / with a mortgage
+const EXTENDED_VARIABLES: [&str; 8] = [
+    "B25003_001E", "B25003_002E", "B25064_001E", "B17001_001E",
+    "B17001_002E", "B25035_001E", "B25081_001E", "B25081_002E",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum VariableSet {
+    #[default]
+    Core,
+    Extended,
+}
+
+impl VariableSet {
+    pub fn parse(name: &str) -> Option<VariableSet> {
+        match name.trim().to_lowercase().as_str() {
+            "core" => Some(VariableSet::Core),
+            "extended" => Some(VariableSet::Extended),
+            _ => None,
+        }
+    }
+
+    fn codes(&self) -> Vec<&'static str> {
+        match self {
+            VariableSet::Core => CORE_VARIABLES.to_vec(),
+            VariableSet::Extended => CORE_VARIABLES.iter().chain(EXTENDED_VARIABLES.iter()).copied().collect(),
+        }
+    }
+}
+
+// Which ACS vintage and variables to fetch
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AcsRequest {
+    pub year: u16,
+    pub variables: VariableSet,
+}
+
+impl Default for AcsRequest {
+    fn default() -> Self {
+        AcsRequest { year: DEFAULT_VINTAGE, variables: VariableSet::Core }
+    }
+}
+
+impl AcsRequest {
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if !VINTAGES.contains(&self.year) {
+            errors.push(format!(
+                "year must be an ACS 5-year vintage from {} to {}, got {}",
+                VINTAGES.start(), VINTAGES.end(), self.year
+            ));
+        }
+        errors
+    }
+
+    // Data cache key; the default request keeps the key used before vintages were selectable
+    fn cache_params(&self, geography: &str) -> String {
+        match self.variables {
+            VariableSet::Core => format!("acs5-{}-{}", self.year, geography),
+            VariableSet::Extended => format!("acs5-{}-{}-extended", self.year, geography),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        format!("US Census Bureau ACS {}", self.year)
+    }
+}
 
 // State FIPS mapping
 static STATE_FIPS: Lazy<HashMap<&str, &str>> = Lazy::new(|| {
@@ -35,6 +124,11 @@ static FIPS_TO_STATE: Lazy<HashMap<&str, &str>> = Lazy::new(|| {
     STATE_FIPS.iter().map(|(k, v)| (*v, *k)).collect()
 });
 
+// State abbreviation for a 5-digit county FIPS code
+pub fn state_for_fips(fips: &str) -> Option<&'static str> {
+    FIPS_TO_STATE.get(fips.get(..2)?).copied()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CountyCensusData {
     pub name: String,
@@ -45,149 +139,370 @@ pub struct CountyCensusData {
     pub median_home_value: i64,
     pub total_housing_units: i64,
     pub vacant_units: i64,
-    pub tier: i32,
+    pub tier: u8,
+    pub score: f32,
+    // Filled in when the extended variable set is requested
+    #[serde(flatten, default)]
+    pub extended: ExtendedStats,
+    // Change from an earlier vintage, filled in on request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change: Option<CensusChange>,
+    // Per-factor score explanation, filled in on request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breakdown: Option<scoring::Breakdown>,
 }
 
-// Cache for census data (avoid hitting API on every request)
-pub struct CensusCache {
-    data: RwLock<Option<Vec<CountyCensusData>>>,
-    last_fetch: RwLock<Option<std::time::Instant>>,
+// Extended-set values; None where the set wasn't requested or Census suppressed the estimate
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExtendedStats {
+    // Share of occupied units that are owner occupied
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_occupied_pct: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub median_gross_rent: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poverty_rate: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub median_year_built: Option<i64>,
+    // Share of owner-occupied units carrying a mortgage
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mortgaged_pct: Option<f64>,
 }
 
-impl CensusCache {
-    pub fn new() -> Self {
-        CensusCache {
-            data: RwLock::new(None),
-            last_fetch: RwLock::new(None),
-        }
+// Year-over-year deltas: _pct fields are percent changes, _pts fields are percentage-point changes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CensusChange {
+    pub compare_year: u16,
+    pub population: i64,
+    pub population_pct: Option<f64>,
+    pub median_income_pct: Option<f64>,
+    pub median_home_value_pct: Option<f64>,
+    pub vacancy_rate_pts: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_occupied_pts: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub median_gross_rent_pct: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poverty_rate_pts: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mortgaged_pts: Option<f64>,
+}
+
+impl CountyCensusData {
+    // Scoring-engine inputs this county has Census values for
+    pub fn factor_values(&self) -> scoring::FactorValues {
+        HashMap::from([
+            (scoring::POPULATION, self.population as f64),
+            (scoring::MEDIAN_INCOME, self.median_income as f64),
+            (scoring::MEDIAN_HOME_VALUE, self.median_home_value as f64),
+        ])
+    }
+
+    pub fn explain(&mut self) {
+        self.breakdown = Some(scoring::score(&self.factor_values()).breakdown);
+    }
+
+    fn vacancy_rate(&self) -> Option<f64> {
+        percent(self.vacant_units as f64, self.total_housing_units as f64)
     }
 }
 
-static CENSUS_CACHE: Lazy<Arc<CensusCache>> = Lazy::new(|| Arc::new(CensusCache::new()));
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
 
-// Tier calculation based on population, income, and home values
-fn calculate_tier(population: i64, income: i64, home_value: i64) -> i32 {
-    let mut score = 0.0;
-    
-    // Population score (25%)
-    score += (population as f64 / 500_000.0).min(1.0) * 25.0;
-    
-    // Income score (25%)
-    score += (income as f64 / 80_000.0).min(1.0) * 25.0;
-    
-    // Home value score (indicates growth - 50%)
-    // Assuming higher value = stronger market
-    score += (home_value as f64 / 400_000.0).min(1.0) * 50.0;
-    
-    match score as i32 {
-        80..=100 => 1,  // Prime
-        60..=79 => 2,   // Strong
-        40..=59 => 3,   // Opportunity
-        20..=39 => 4,   // Speculative
-        _ => 5,         // Avoid
+// part / whole as a percentage; None for an empty whole
+fn percent(part: f64, whole: f64) -> Option<f64> {
+    (whole > 0.0).then(|| round2(part / whole * 100.0))
+}
+
+fn pct_change(new: f64, old: f64) -> Option<f64> {
+    (old > 0.0).then(|| round2((new / old - 1.0) * 100.0))
+}
+
+fn pts_change(new: Option<f64>, old: Option<f64>) -> Option<f64> {
+    Some(round2(new? - old?))
+}
+
+// Attach changes against `previous` (another vintage), matched by FIPS; counties missing there get none
+pub fn attach_changes(counties: &mut [CountyCensusData], previous: &[CountyCensusData], compare_year: u16) {
+    let previous: HashMap<&str, &CountyCensusData> = previous.iter().map(|c| (c.fips.as_str(), c)).collect();
+    for county in counties.iter_mut() {
+        let Some(old) = previous.get(county.fips.as_str()) else { continue };
+        let (new_ext, old_ext) = (&county.extended, &old.extended);
+        county.change = Some(CensusChange {
+            compare_year,
+            population: county.population - old.population,
+            population_pct: pct_change(county.population as f64, old.population as f64),
+            median_income_pct: pct_change(county.median_income as f64, old.median_income as f64),
+            median_home_value_pct: pct_change(county.median_home_value as f64, old.median_home_value as f64),
+            vacancy_rate_pts: pts_change(county.vacancy_rate(), old.vacancy_rate()),
+            owner_occupied_pts: pts_change(new_ext.owner_occupied_pct, old_ext.owner_occupied_pct),
+            median_gross_rent_pct: match (new_ext.median_gross_rent, old_ext.median_gross_rent) {
+                (Some(new), Some(old)) => pct_change(new as f64, old as f64),
+                _ => None,
+            },
+            poverty_rate_pts: pts_change(new_ext.poverty_rate, old_ext.poverty_rate),
+            mortgaged_pts: pts_change(new_ext.mortgaged_pct, old_ext.mortgaged_pct),
+        });
     }
 }
 
-// Fetch all counties from Census API
+// Counties change once a year; refetch daily, and serve a copy up to a week old while refreshing
+const CENSUS_POLICY: data_cache::Policy = data_cache::Policy {
+    ttl: Duration::from_secs(24 * 3600),
+    stale_while_revalidate: Duration::from_secs(7 * 24 * 3600),
+};
+
+// Fetch all counties for the default vintage (disk-cached; the last good copy is served if Census is down)
 pub async fn fetch_all_counties(api_key: Option<&str>) -> Result<Vec<CountyCensusData>, String> {
-    // Check cache first (valid for 24 hours)
-    {
-        let last_fetch = CENSUS_CACHE.last_fetch.read().await;
-        let data = CENSUS_CACHE.data.read().await;
-        if let (Some(time), Some(cached_data)) = (&*last_fetch, &*data) {
-            if time.elapsed().as_secs() < 86400 {
-                return Ok(cached_data.clone());
-            }
+    fetch_counties(AcsRequest::default(), api_key).await
+}
+
+// Fetch all counties for any vintage and variable set
+pub async fn fetch_counties(request: AcsRequest, api_key: Option<&str>) -> Result<Vec<CountyCensusData>, String> {
+    let key = api_key.map(str::to_string);
+    let cached = data_cache::shared()
+        .get_or_fetch("census", &request.cache_params("county"), CENSUS_POLICY, move |_| async move {
+            download_counties(request, key.as_deref()).await.map(Arc::new)
+        })
+        .await?;
+    
+    // Graded on read so a scoring config change applies to cached counties too
+    Ok(cached
+        .value
+        .iter()
+        .map(|county| {
+            let mut county = county.clone();
+            let graded = scoring::score(&county.factor_values());
+            county.tier = graded.tier.tier;
+            county.score = graded.score as f32;
+            county
+        })
+        .collect())
+}
+
+// Redownload every county for the default vintage now (scheduled refreshes); returns the county count
+pub async fn refresh_counties(api_key: Option<&str>) -> Result<usize, String> {
+    let request = AcsRequest::default();
+    let cached = data_cache::shared()
+        .refresh("census", &request.cache_params("county"), |_| async {
+            download_counties(request, api_key).await.map(Arc::new)
+        })
+        .await?;
+    Ok(cached.value.len())
+}
+
+// One row of a Census table, looked up by column name
+struct AcsRow<'a> {
+    headers: &'a [String],
+    values: &'a [String],
+}
+
+impl AcsRow<'_> {
+    fn get(&self, name: &str) -> Option<&str> {
+        let i = self.headers.iter().position(|h| h == name)?;
+        self.values.get(i).map(String::as_str)
+    }
+
+    fn int(&self, name: &str) -> i64 {
+        self.get(name).and_then(|v| v.parse().ok()).unwrap_or(0)
+    }
+
+    // Census marks suppressed or unavailable estimates with large negative codes
+    fn estimate(&self, name: &str) -> Option<f64> {
+        self.get(name)?.parse::<f64>().ok().filter(|v| *v >= 0.0)
+    }
+
+    fn extended(&self) -> ExtendedStats {
+        let share = |part: &str, whole: &str| percent(self.estimate(part)?, self.estimate(whole)?);
+        ExtendedStats {
+            owner_occupied_pct: share("B25003_002E", "B25003_001E"),
+            median_gross_rent: self.estimate("B25064_001E").map(|v| v as i64),
+            poverty_rate: share("B17001_002E", "B17001_001E"),
+            // 0 means no estimate (e.g. no housing units)
+            median_year_built: self.estimate("B25035_001E").map(|v| v as i64).filter(|v| *v > 0),
+            mortgaged_pct: share("B25081_002E", "B25081_001E"),
         }
     }
-    
-    let client = Client::new();
-    
-    // Census API variables:
-    // B01003_001E = Total population
-    // B19013_001E = Median household income
-    // B25077_001E = Median home value
-    // B25001_001E = Total housing units
-    // B25002_003E = Vacant housing units
-    
+}
+
+// Rows of an ACS 5-year table; `geography` is the for=/in= query (e.g. "for=county:*&in=state:*")
+async fn fetch_table(year: u16, variables: &[&str], geography: &str, api_key: Option<&str>) -> Result<Vec<Vec<String>>, String> {
     let key_param = api_key.map(|k| format!("&key={}", k)).unwrap_or_default();
     let url = format!(
-        "{}?get=NAME,B01003_001E,B19013_001E,B25077_001E,B25001_001E,B25002_003E&for=county:*&in=state:*{}",
+        "{}/{}/acs/acs5?get=NAME,{}&{}{}",
         CENSUS_API_BASE,
+        year,
+        variables.join(","),
+        geography,
         key_param
     );
     
-    let response = client.get(&url)
+    // Errors drop the URL so the API key never reaches a log
+    let response = Client::new().get(&url)
         .send()
         .await
-        .map_err(|e| format!("Census API request failed: {}", e))?;
+        .map_err(|e| format!("Census API request failed: {}", e.without_url()))?;
     
+    // Census answers 204 when nothing matches the geography
+    if response.status() == reqwest::StatusCode::NO_CONTENT {
+        return Ok(Vec::new());
+    }
     if !response.status().is_success() {
         return Err(format!("Census API returned status: {}", response.status()));
     }
     
-    let data: Vec<Vec<String>> = response.json()
+    response.json()
         .await
-        .map_err(|e| format!("Failed to parse Census response: {}", e))?;
+        .map_err(|e| format!("Failed to parse Census response: {}", e.without_url()))
+}
+
+async fn download_counties(request: AcsRequest, api_key: Option<&str>) -> Result<Vec<CountyCensusData>, String> {
+    let data = fetch_table(request.year, &request.variables.codes(), "for=county:*&in=state:*", api_key).await?;
+    let Some((headers, rows)) = data.split_first() else {
+        return Ok(Vec::new());
+    };
+    let extended = request.variables == VariableSet::Extended;
     
-    // Skip header row
-    let counties: Vec<CountyCensusData> = data.iter()
-        .skip(1)
-        .filter_map(|row| {
-            if row.len() < 8 { return None; }
+    let counties: Vec<CountyCensusData> = rows.iter()
+        .filter_map(|values| {
+            let row = AcsRow { headers, values };
             
-            let name_parts: Vec<&str> = row[0].split(',').collect();
-            let county_name = name_parts.get(0)
-                .unwrap_or(&"Unknown")
+            let county_name = row.get("NAME")?
+                .split(',')
+                .next()
+                .unwrap_or("Unknown")
                 .replace(" County", "")
                 .replace(" Parish", "")   // Louisiana
                 .replace(" Borough", "")  // Alaska
                 .trim()
                 .to_string();
             
-            let state_fips = row.get(6)?;
-            let county_fips = row.get(7)?;
-            let state_abbr = FIPS_TO_STATE.get(state_fips.as_str())?;
+            let state_fips = row.get("state")?;
+            let county_fips = row.get("county")?;
+            let state_abbr = FIPS_TO_STATE.get(state_fips)?;
             
-            let population = row[1].parse().unwrap_or(0);
-            let income = row[2].parse().unwrap_or(0);
-            let home_value = row[3].parse().unwrap_or(0);
-            let housing_units = row[4].parse().unwrap_or(0);
-            let vacant = row[5].parse().unwrap_or(0);
+            let population = row.int("B01003_001E");
+            let income = row.int("B19013_001E");
             
             // Skip if missing critical data
             if population == 0 || income < 0 { return None; }
             
-            let tier = calculate_tier(population, income, home_value);
-            
             Some(CountyCensusData {
                 name: county_name,
                 state: state_abbr.to_string(),
                 fips: format!("{}{}", state_fips, county_fips),
                 population,
                 median_income: income,
-                median_home_value: home_value,
-                total_housing_units: housing_units,
-                vacant_units: vacant,
-                tier,
+                median_home_value: row.int("B25077_001E"),
+                total_housing_units: row.int("B25001_001E"),
+                vacant_units: row.int("B25002_003E"),
+                tier: 5,
+                score: 0.0,
+                extended: if extended { row.extended() } else { ExtendedStats::default() },
+                change: None,
+                breakdown: None,
             })
         })
         .collect();
     
-    // Update cache
-    {
-        let mut cache_data = CENSUS_CACHE.data.write().await;
-        let mut cache_time = CENSUS_CACHE.last_fetch.write().await;
-        *cache_data = Some(counties.clone());
-        *cache_time = Some(std::time::Instant::now());
-    }
-    
     Ok(counties)
 }
 
+// ============================================================================
+// TRACT & ZCTA DRILL-DOWN
+// Neighborhood-level values from the same ACS tables. Small geographies often have
+// suppressed medians, so those are optional here; there is no tier or score.
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AreaCensusData {
+    // 11-digit tract GEOID, or the 5-digit ZCTA
+    pub geoid: String,
+    pub name: String,
+    pub population: i64,
+    pub median_income: Option<i64>,
+    pub median_home_value: Option<i64>,
+    pub total_housing_units: i64,
+    pub vacant_units: i64,
+    #[serde(flatten, default)]
+    pub extended: ExtendedStats,
+}
+
+impl AcsRow<'_> {
+    fn area(&self, geoid: String, extended: bool) -> Option<AreaCensusData> {
+        Some(AreaCensusData {
+            geoid,
+            name: self.get("NAME")?.to_string(),
+            population: self.int("B01003_001E"),
+            median_income: self.estimate("B19013_001E").map(|v| v as i64),
+            median_home_value: self.estimate("B25077_001E").map(|v| v as i64),
+            total_housing_units: self.int("B25001_001E"),
+            vacant_units: self.int("B25002_003E"),
+            extended: if extended { self.extended() } else { ExtendedStats::default() },
+        })
+    }
+}
+
+// Cached rows for one geography; `geoid` builds each row's id from its geography columns
+async fn fetch_areas(
+    request: AcsRequest,
+    geography_key: String,
+    geography: String,
+    geoid: fn(&AcsRow) -> Option<String>,
+    api_key: Option<&str>,
+) -> Result<Vec<AreaCensusData>, String> {
+    let key = api_key.map(str::to_string);
+    let cached = data_cache::shared()
+        .get_or_fetch("census", &request.cache_params(&geography_key), CENSUS_POLICY, move |_| async move {
+            let data = fetch_table(request.year, &request.variables.codes(), &geography, key.as_deref()).await?;
+            let Some((headers, rows)) = data.split_first() else {
+                return Ok(Arc::new(Vec::new()));
+            };
+            let extended = request.variables == VariableSet::Extended;
+            let areas: Vec<AreaCensusData> = rows
+                .iter()
+                .filter_map(|values| {
+                    let row = AcsRow { headers, values };
+                    row.area(geoid(&row)?, extended)
+                })
+                .collect();
+            Ok(Arc::new(areas))
+        })
+        .await?;
+    Ok(cached.value.as_ref().clone())
+}
+
+// Every census tract in a county (5-digit county FIPS)
+pub async fn fetch_tracts(county_fips: &str, request: AcsRequest, api_key: Option<&str>) -> Result<Vec<AreaCensusData>, String> {
+    let (state, county) = county_fips.split_at(2);
+    fetch_areas(
+        request,
+        format!("tract-{}", county_fips),
+        format!("for=tract:*&in=state:{}&in=county:{}", state, county),
+        |row| Some(format!("{}{}{}", row.get("state")?, row.get("county")?, row.get("tract")?)),
+        api_key,
+    )
+    .await
+}
+
+// One ZIP Code Tabulation Area; None when Census has no ZCTA for the ZIP (e.g. PO-box-only ZIPs)
+pub async fn fetch_zcta(zip: &str, request: AcsRequest, api_key: Option<&str>) -> Result<Option<AreaCensusData>, String> {
+    let areas = fetch_areas(
+        request,
+        format!("zcta-{}", zip),
+        format!("for=zip%20code%20tabulation%20area:{}", zip),
+        |row| row.get("zip code tabulation area").map(str::to_string),
+        api_key,
+    )
+    .await?;
+    Ok(areas.into_iter().next())
+}
+
 // Fetch counties for a specific state
-pub async fn fetch_state_counties(state_abbr: &str, api_key: Option<&str>) -> Result<Vec<CountyCensusData>, String> {
-    let all_counties = fetch_all_counties(api_key).await?;
+pub async fn fetch_state_counties(state_abbr: &str, request: AcsRequest, api_key: Option<&str>) -> Result<Vec<CountyCensusData>, String> {
+    let all_counties = fetch_counties(request, api_key).await?;
     let state_upper = state_abbr.to_uppercase();
     
     Ok(all_counties.into_iter()
@@ -195,25 +510,105 @@ pub async fn fetch_state_counties(state_abbr: &str, api_key: Option<&str>) -> Re
         .collect())
 }
 
-// Get county statistics summary
-pub fn county_stats(counties: &[CountyCensusData]) -> HashMap<String, serde_json::Value> {
-    let mut stats = HashMap::new();
-    
-    stats.insert("total_counties".to_string(), serde_json::json!(counties.len()));
-    
-    let by_tier: HashMap<i32, usize> = counties.iter()
-        .fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.tier).or_insert(0) += 1;
-            acc
-        });
-    stats.insert("by_tier".to_string(), serde_json::json!(by_tier));
-    
-    let by_state: HashMap<String, usize> = counties.iter()
-        .fold(HashMap::new(), |mut acc, c| {
-            *acc.entry(c.state.clone()).or_insert(0) += 1;
-            acc
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn county(fips: &str, population: i64, median_income: i64, vacant_units: i64, extended: serde_json::Value) -> CountyCensusData {
+        let mut value = json!({
+            "name": "Test", "state": "CA", "fips": fips, "population": population, "median_income": median_income,
+            "median_home_value": 500000, "total_housing_units": 1000, "vacant_units": vacant_units, "tier": 3, "score": 50.0,
         });
-    stats.insert("by_state".to_string(), serde_json::json!(by_state));
-    
-    stats
+        value.as_object_mut().unwrap().extend(extended.as_object().unwrap().clone());
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn validates_vintage_and_variable_set() {
+        assert!(AcsRequest::default().validate().is_empty());
+        for year in [*VINTAGES.start(), *VINTAGES.end()] {
+            assert!(AcsRequest { year, ..Default::default() }.validate().is_empty());
+        }
+        assert_eq!(
+            AcsRequest { year: 2018, ..Default::default() }.validate(),
+            ["year must be an ACS 5-year vintage from 2019 to 2023, got 2018"]
+        );
+        assert_eq!(AcsRequest { year: 2024, ..Default::default() }.validate().len(), 1);
+
+        assert_eq!(VariableSet::parse(" Extended "), Some(VariableSet::Extended));
+        assert_eq!(VariableSet::parse("core"), Some(VariableSet::Core));
+        assert_eq!(VariableSet::parse("full"), None);
+        assert_eq!(VariableSet::Core.codes().len(), 5);
+        assert_eq!(VariableSet::Extended.codes().len(), 13);
+    }
+
+    #[test]
+    fn cache_keys_separate_vintages_and_sets() {
+        // The default key predates selectable vintages; changing it would orphan cached copies
+        assert_eq!(AcsRequest::default().cache_params("county"), "acs5-2022-county");
+        let extended = AcsRequest { year: 2021, variables: VariableSet::Extended };
+        assert_eq!(extended.cache_params("county"), "acs5-2021-county-extended");
+        assert_eq!(extended.label(), "US Census Bureau ACS 2021");
+    }
+
+    #[test]
+    fn suppressed_estimates_are_none() {
+        let headers: Vec<String> = EXTENDED_VARIABLES.iter().map(|s| s.to_string()).collect();
+        let values: Vec<String> = ["1000", "600", "-666666666", "0", "50", "0", "600", "-999999999"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let stats = AcsRow { headers: &headers, values: &values }.extended();
+        assert_eq!(stats.owner_occupied_pct, Some(60.0));
+        assert_eq!(stats.median_gross_rent, None);
+        assert_eq!(stats.poverty_rate, None, "empty poverty universe");
+        assert_eq!(stats.median_year_built, None);
+        assert_eq!(stats.mortgaged_pct, None);
+    }
+
+    #[test]
+    fn attaches_changes_by_fips() {
+        let previous = vec![
+            county("06059", 1, 1, 0, json!({})),
+            county("06037", 100_000, 50_000, 100, json!({
+                "owner_occupied_pct": 60.0, "median_gross_rent": 1000, "poverty_rate": 12.5, "mortgaged_pct": 70.0,
+            })),
+            county("06001", 5, 0, 0, json!({})),
+            county("99999", 1, 1, 0, json!({})),
+        ];
+        let mut current = vec![
+            county("06037", 110_000, 51_000, 120, json!({
+                "owner_occupied_pct": 55.5, "median_gross_rent": 1100, "mortgaged_pct": 71.25,
+            })),
+            county("06001", 6, 40_000, 0, json!({})),
+            county("06075", 10, 10, 0, json!({})),
+        ];
+        attach_changes(&mut current, &previous, 2021);
+
+        let la = current[0].change.as_ref().unwrap();
+        assert_eq!(la.compare_year, 2021);
+        assert_eq!(la.population, 10_000);
+        // _pct: relative change
+        assert_eq!(la.population_pct, Some(10.0));
+        assert_eq!(la.median_income_pct, Some(2.0));
+        assert_eq!(la.median_home_value_pct, Some(0.0));
+        assert_eq!(la.median_gross_rent_pct, Some(10.0));
+        // _pts: difference between two percentages (vacancy 10% -> 12%)
+        assert_eq!(la.vacancy_rate_pts, Some(2.0));
+        assert_eq!(la.owner_occupied_pts, Some(-4.5));
+        assert_eq!(la.mortgaged_pts, Some(1.25));
+        // Suppressed in the new vintage
+        assert_eq!(la.poverty_rate_pts, None);
+
+        // A zero base has no percent change; core-only vintages have no extended deltas
+        let alameda = current[1].change.as_ref().unwrap();
+        assert_eq!(alameda.population_pct, Some(20.0));
+        assert_eq!(alameda.median_income_pct, None);
+        assert_eq!(alameda.owner_occupied_pts, None);
+        assert_eq!(alameda.median_gross_rent_pct, None);
+
+        // Not in the earlier vintage
+        assert!(current[2].change.is_none());
+    }
 }
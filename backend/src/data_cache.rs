@@ -0,0 +1,421 @@
+// Data Cache - Disk-backed last-known-good cache shared by every upstream fetcher
+// Entries are keyed by source + params and written as JSON under CACHE_DIR/<source>/, so a
+// restart picks up where the last process left off. Within `ttl` a value is served as-is;
+// within `stale_while_revalidate` after that it is served immediately while one background
+// task refreshes it; past both the caller waits for a refetch, and concurrent callers share
+// that one fetch. When the upstream fails, the last good value is served (marked stale) no
+// matter how old it is.
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// After a failed fetch, serve the stale value for this long before asking the upstream again
+const RETRY_AFTER_ERROR: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Policy {
+    pub ttl: Duration,
+    pub stale_while_revalidate: Duration,
+}
+
+pub struct Cached<T> {
+    pub value: Arc<T>,
+    pub fetched_at: DateTime<Utc>,
+    // Older than the policy's ttl, or served because the upstream failed
+    pub stale: bool,
+}
+
+impl<T> Clone for Cached<T> {
+    fn clone(&self) -> Self {
+        Cached { value: self.value.clone(), fetched_at: self.fetched_at, stale: self.stale }
+    }
+}
+
+impl<T> Cached<T> {
+    fn age(&self) -> Duration {
+        (Utc::now() - self.fetched_at).to_std().unwrap_or_default()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct DiskEntry<T> {
+    source: String,
+    params: String,
+    fetched_at: DateTime<Utc>,
+    value: T,
+}
+
+struct Entry {
+    value: Arc<dyn Any + Send + Sync>,
+    fetched_at: DateTime<Utc>,
+    refreshing: bool,
+    failed_at: Option<Instant>,
+}
+
+// One foreground fetch per key; everyone who misses while it runs waits on the same cell
+type Flight<T> = tokio::sync::OnceCell<Result<Cached<T>, String>>;
+
+pub struct Store {
+    dir: PathBuf,
+    entries: Mutex<HashMap<String, Entry>>,
+    flights: Mutex<HashMap<String, Arc<dyn Any + Send + Sync>>>,
+}
+
+static SHARED: Lazy<Store> = Lazy::new(|| {
+    Store::new(PathBuf::from(std::env::var("CACHE_DIR").unwrap_or_else(|_| "cache".to_string())))
+});
+
+// The store backed by CACHE_DIR (default "cache")
+pub fn shared() -> &'static Store {
+    &SHARED
+}
+
+fn cache_key(source: &str, params: &str) -> String {
+    format!("{}/{}", source, params)
+}
+
+// Params become a file name: anything outside [A-Za-z0-9._-] is replaced
+fn file_name(params: &str) -> String {
+    let safe: String = params
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') { c } else { '_' })
+        .collect();
+    format!("{}.json", if safe.is_empty() { "default" } else { &safe })
+}
+
+impl Store {
+    pub fn new(dir: PathBuf) -> Store {
+        Store { dir, entries: Mutex::new(HashMap::new()), flights: Mutex::new(HashMap::new()) }
+    }
+
+    fn path(&self, source: &str, params: &str) -> PathBuf {
+        self.dir.join(source).join(file_name(params))
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<String, Entry>> {
+        // A panic mid-update leaves the map usable; the worst case is one stale entry
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn in_memory<T: Send + Sync + 'static>(&self, key: &str) -> Option<Cached<T>> {
+        let entries = self.entries();
+        let entry = entries.get(key)?;
+        let value = entry.value.clone().downcast::<T>().ok()?;
+        Some(Cached { value, fetched_at: entry.fetched_at, stale: false })
+    }
+
+    // Last stored value of any age: memory first, then the disk copy from an earlier run
+    pub async fn peek<T>(&self, source: &str, params: &str) -> Option<Cached<T>>
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+    {
+        let key = cache_key(source, params);
+        if let Some(cached) = self.in_memory::<T>(&key) {
+            return Some(cached);
+        }
+        let bytes = tokio::fs::read(self.path(source, params)).await.ok()?;
+        let disk: DiskEntry<T> = match serde_json::from_slice(&bytes) {
+            Ok(disk) => disk,
+            Err(e) => {
+                eprintln!("Cache {}: ignoring unreadable disk copy: {}", key, e);
+                return None;
+            }
+        };
+        let value = Arc::new(disk.value);
+        self.entries().entry(key).or_insert_with(|| Entry {
+            value: value.clone(),
+            fetched_at: disk.fetched_at,
+            refreshing: false,
+            failed_at: None,
+        });
+        Some(Cached { value, fetched_at: disk.fetched_at, stale: false })
+    }
+
+    // Record a fresh value in memory and on disk
+    pub async fn put<T>(&self, source: &str, params: &str, value: Arc<T>) -> Cached<T>
+    where
+        T: Serialize + Send + Sync + 'static,
+    {
+        let fetched_at = Utc::now();
+        self.entries().insert(
+            cache_key(source, params),
+            Entry { value: value.clone(), fetched_at, refreshing: false, failed_at: None },
+        );
+        if let Err(e) = self.write_disk(source, params, value.as_ref(), fetched_at).await {
+            eprintln!("Cache {}: {}", cache_key(source, params), e);
+        }
+        Cached { value, fetched_at, stale: false }
+    }
+
+    async fn write_disk<T: Serialize>(&self, source: &str, params: &str, value: &T, fetched_at: DateTime<Utc>) -> Result<(), String> {
+        let path = self.path(source, params);
+        let disk = DiskEntry { source: source.to_string(), params: params.to_string(), fetched_at, value };
+        let json = serde_json::to_vec(&disk).map_err(|e| format!("Failed to serialize: {}", e))?;
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+        // Write then rename so a crash never leaves a half-written copy
+        let tmp = path.with_extension("json.tmp");
+        tokio::fs::write(&tmp, json).await.map_err(|e| format!("Failed to write {}: {}", tmp.display(), e))?;
+        tokio::fs::rename(&tmp, &path)
+            .await
+            .map_err(|e| format!("Failed to move {} into place: {}", tmp.display(), e))
+    }
+
+    fn failed_recently(&self, key: &str) -> bool {
+        self.entries()
+            .get(key)
+            .and_then(|e| e.failed_at)
+            .is_some_and(|at| at.elapsed() < RETRY_AFTER_ERROR)
+    }
+
+    fn mark_failed(&self, key: &str) {
+        if let Some(entry) = self.entries().get_mut(key) {
+            entry.refreshing = false;
+            entry.failed_at = Some(Instant::now());
+        }
+    }
+
+    // The fetch in progress for a key, or a new one to run
+    fn flight<T: Send + Sync + 'static>(&self, key: &str) -> Arc<Flight<T>> {
+        let mut flights = self.flights.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(flight) = flights.get(key).and_then(|f| f.clone().downcast::<Flight<T>>().ok()) {
+            return flight;
+        }
+        let flight = Arc::new(Flight::<T>::new());
+        flights.insert(key.to_string(), flight.clone());
+        flight
+    }
+
+    // Forget a finished fetch so the next miss starts a new one
+    fn land<T: Send + Sync + 'static>(&self, key: &str, flight: &Arc<Flight<T>>) {
+        let mut flights = self.flights.lock().unwrap_or_else(|e| e.into_inner());
+        if flights.get(key).is_some_and(|f| f.clone().downcast::<Flight<T>>().is_ok_and(|f| Arc::ptr_eq(&f, flight))) {
+            flights.remove(key);
+        }
+    }
+
+    // Claim the background refresh for a key; false if one is running or just failed
+    fn begin_refresh(&self, key: &str) -> bool {
+        let mut entries = self.entries();
+        match entries.get_mut(key) {
+            Some(entry) if !entry.refreshing && entry.failed_at.is_none_or(|at| at.elapsed() >= RETRY_AFTER_ERROR) => {
+                entry.refreshing = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // Cached value under `policy`, calling `fetch` (with the previous value, for conditional requests) when needed
+    pub async fn get_or_fetch<T, F, Fut>(&'static self, source: &'static str, params: &str, policy: Policy, fetch: F) -> Result<Cached<T>, String>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+        F: FnOnce(Option<Arc<T>>) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Arc<T>, String>> + Send + 'static,
+    {
+        let key = cache_key(source, params);
+        let current = self.peek::<T>(source, params).await;
+
+        if let Some(cached) = &current {
+            let age = cached.age();
+            if age < policy.ttl {
+                return Ok(cached.clone());
+            }
+            let stale = Cached { stale: true, ..cached.clone() };
+            if age < policy.ttl + policy.stale_while_revalidate {
+                if self.begin_refresh(&key) {
+                    let previous = cached.value.clone();
+                    let params = params.to_string();
+                    tokio::spawn(async move {
+                        match fetch(Some(previous)).await {
+                            Ok(value) => {
+                                self.put(source, &params, value).await;
+                            }
+                            Err(e) => {
+                                eprintln!("Cache {}: background refresh failed: {}", cache_key(source, &params), e);
+                                self.mark_failed(&cache_key(source, &params));
+                            }
+                        }
+                    });
+                }
+                return Ok(stale);
+            }
+            if self.failed_recently(&key) {
+                return Ok(stale);
+            }
+        }
+
+        let flight = self.flight::<T>(&key);
+        let result = flight.get_or_init(|| self.fetch_now(source, params, policy, fetch)).await.clone();
+        self.land(&key, &flight);
+        result
+    }
+
+    // Foreground fetch, run once per flight; falls back to the last good value on failure
+    async fn fetch_now<T, F, Fut>(&self, source: &str, params: &str, policy: Policy, fetch: F) -> Result<Cached<T>, String>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+        F: FnOnce(Option<Arc<T>>) -> Fut,
+        Fut: Future<Output = Result<Arc<T>, String>>,
+    {
+        let key = cache_key(source, params);
+        // An earlier flight may have landed between this caller's miss and now
+        let current = self.peek::<T>(source, params).await;
+        if let Some(cached) = current.as_ref().filter(|c| c.age() < policy.ttl) {
+            return Ok(cached.clone());
+        }
+
+        match fetch(current.as_ref().map(|c| c.value.clone())).await {
+            Ok(value) => Ok(self.put(source, params, value).await),
+            Err(e) => match current {
+                Some(cached) => {
+                    eprintln!("Cache {}: {}; serving copy from {}", key, e, cached.fetched_at.to_rfc3339());
+                    self.mark_failed(&key);
+                    Ok(Cached { stale: true, ..cached })
+                }
+                None => Err(e),
+            },
+        }
+    }
+
+    // Fetch now regardless of age (scheduled refreshes); a failure leaves the cached value in place
+    pub async fn refresh<T, F, Fut>(&self, source: &str, params: &str, fetch: F) -> Result<Cached<T>, String>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+        F: FnOnce(Option<Arc<T>>) -> Fut,
+        Fut: Future<Output = Result<Arc<T>, String>>,
+    {
+        let previous = self.peek::<T>(source, params).await.map(|c| c.value);
+        match fetch(previous).await {
+            Ok(value) => Ok(self.put(source, params, value).await),
+            Err(e) => {
+                self.mark_failed(&cache_key(source, params));
+                Err(e)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const FRESH: Policy = Policy { ttl: Duration::from_secs(3600), stale_while_revalidate: Duration::ZERO };
+    const SWR: Policy = Policy { ttl: Duration::ZERO, stale_while_revalidate: Duration::from_secs(3600) };
+    const EXPIRED: Policy = Policy { ttl: Duration::ZERO, stale_while_revalidate: Duration::ZERO };
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("data-cache-test-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn test_store(name: &str) -> &'static Store {
+        Box::leak(Box::new(Store::new(test_dir(name))))
+    }
+
+    // A fetch that counts its calls and answers with the call number
+    fn counting(calls: &'static AtomicUsize) -> impl FnOnce(Option<Arc<usize>>) -> std::future::Ready<Result<Arc<usize>, String>> {
+        move |_| std::future::ready(Ok(Arc::new(calls.fetch_add(1, Ordering::SeqCst) + 1)))
+    }
+
+    fn failing(_: Option<Arc<usize>>) -> std::future::Ready<Result<Arc<usize>, String>> {
+        std::future::ready(Err("upstream down".to_string()))
+    }
+
+    fn leak_counter() -> &'static AtomicUsize {
+        Box::leak(Box::new(AtomicUsize::new(0)))
+    }
+
+    #[tokio::test]
+    async fn serves_within_ttl_without_refetching() {
+        let store = test_store("ttl");
+        let calls = leak_counter();
+        let first = store.get_or_fetch("test", "k", FRESH, counting(calls)).await.unwrap();
+        let second = store.get_or_fetch("test", "k", FRESH, counting(calls)).await.unwrap();
+        assert_eq!((*first.value, *second.value), (1, 1));
+        assert!(!second.stale);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn stale_while_revalidate_serves_old_value_and_refreshes_in_background() {
+        let store = test_store("swr");
+        let calls = leak_counter();
+        store.get_or_fetch("test", "k", SWR, counting(calls)).await.unwrap();
+
+        let served = store.get_or_fetch("test", "k", SWR, counting(calls)).await.unwrap();
+        assert_eq!(*served.value, 1);
+        assert!(served.stale);
+
+        // The background refresh lands shortly after
+        let mut refreshed = 0;
+        for _ in 0..100 {
+            refreshed = *store.peek::<usize>("test", "k").await.unwrap().value;
+            if refreshed == 2 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(refreshed, 2);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn serves_last_good_value_when_the_upstream_fails() {
+        let store = test_store("last-good");
+        let calls = leak_counter();
+        store.get_or_fetch("test", "k", EXPIRED, counting(calls)).await.unwrap();
+
+        let served = store.get_or_fetch("test", "k", EXPIRED, failing).await.unwrap();
+        assert_eq!(*served.value, 1);
+        assert!(served.stale);
+
+        // Without any good copy the error comes through
+        let err = store.get_or_fetch("test", "missing", EXPIRED, failing).await.err();
+        assert_eq!(err.as_deref(), Some("upstream down"));
+    }
+
+    #[tokio::test]
+    async fn restarts_from_the_disk_copy() {
+        let dir = test_dir("restart");
+        let calls = leak_counter();
+        let before: &'static Store = Box::leak(Box::new(Store::new(dir.clone())));
+        before.get_or_fetch("test", "a/b c", FRESH, counting(calls)).await.unwrap();
+
+        let after: &'static Store = Box::leak(Box::new(Store::new(dir)));
+        let served = after.get_or_fetch("test", "a/b c", FRESH, counting(calls)).await.unwrap();
+        assert_eq!(*served.value, 1);
+        assert!(!served.stale);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn concurrent_misses_share_one_fetch() {
+        let store = test_store("single-flight");
+        let calls = leak_counter();
+        let tasks: Vec<_> = (0..10)
+            .map(|_| {
+                tokio::spawn(store.get_or_fetch("test", "k", FRESH, move |_| async move {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    Ok(Arc::new(calls.fetch_add(1, Ordering::SeqCst) + 1))
+                }))
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(*task.await.unwrap().unwrap().value, 1);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
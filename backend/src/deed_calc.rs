@@ -0,0 +1,428 @@
+// Deed Analysis - Underwrite a tax deed purchase as a flip or a rental hold
+// ARV comes from the county's ZHVI and financing from the live 30-year mortgage rate
+
+use serde::{Deserialize, Serialize};
+
+// Defaults for the optional underwriting assumptions
+const DEFAULT_CLOSING_COST_PCT: f64 = 3.0;
+const DEFAULT_SELLING_COST_PCT: f64 = 8.0;
+const DEFAULT_LOAN_TO_COST_PCT: f64 = 70.0;
+const DEFAULT_FLIP_MONTHS: u32 = 6;
+const DEFAULT_HOLD_YEARS: u32 = 5;
+// Taxes, insurance and utilities while the property is held, as % of ARV per year
+const DEFAULT_HOLDING_COST_PCT: f64 = 1.5;
+// Monthly rent as % of ARV when no rent is supplied
+const DEFAULT_RENT_TO_VALUE_PCT: f64 = 0.75;
+const DEFAULT_VACANCY_PCT: f64 = 8.0;
+// Operating expenses (management, maintenance, taxes, insurance) as % of collected rent
+const DEFAULT_OPERATING_EXPENSE_PCT: f64 = 35.0;
+pub const DEFAULT_APPRECIATION_PCT: f64 = 3.0;
+const LOAN_TERM_MONTHS: u32 = 360;
+// Upper bounds on the scenario lengths; the cash-flow series have one entry per month
+pub const MAX_FLIP_MONTHS: u32 = 60;
+pub const MAX_HOLD_YEARS: u32 = 40;
+
+#[derive(Debug, Deserialize)]
+pub struct DeedAnalysisInput {
+    pub state: String,
+    // County name, or a 5-digit FIPS code
+    #[serde(default)]
+    pub county: Option<String>,
+    #[serde(default)]
+    pub fips: Option<String>,
+    pub bid_amount: f64,
+    #[serde(default)]
+    pub rehab_cost: f64,
+    // Overrides the ZHVI-based after-repair value
+    #[serde(default)]
+    pub arv: Option<f64>,
+    // Overrides the live 30-year mortgage rate
+    #[serde(default)]
+    pub mortgage_rate_pct: Option<f64>,
+    #[serde(default)]
+    pub loan_to_cost_pct: Option<f64>,
+    #[serde(default)]
+    pub closing_cost_pct: Option<f64>,
+    #[serde(default)]
+    pub selling_cost_pct: Option<f64>,
+    #[serde(default)]
+    pub monthly_holding_cost: Option<f64>,
+    #[serde(default)]
+    pub flip_months: Option<u32>,
+    #[serde(default)]
+    pub monthly_rent: Option<f64>,
+    #[serde(default)]
+    pub vacancy_pct: Option<f64>,
+    #[serde(default)]
+    pub operating_expense_pct: Option<f64>,
+    #[serde(default)]
+    pub hold_years: Option<u32>,
+    // Overrides the county's ZHVI year-over-year change
+    #[serde(default)]
+    pub appreciation_pct: Option<f64>,
+}
+
+impl DeedAnalysisInput {
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.county.as_deref().is_none_or(|c| c.trim().is_empty())
+            && self.fips.as_deref().is_none_or(|f| f.trim().is_empty())
+            && self.arv.is_none()
+        {
+            errors.push("county or fips is required unless arv is supplied".to_string());
+        }
+        if !self.bid_amount.is_finite() || self.bid_amount <= 0.0 {
+            errors.push("bid_amount must be greater than 0".to_string());
+        }
+        let non_negative = [
+            ("rehab_cost", Some(self.rehab_cost)),
+            ("arv", self.arv),
+            ("mortgage_rate_pct", self.mortgage_rate_pct),
+            ("monthly_holding_cost", self.monthly_holding_cost),
+            ("monthly_rent", self.monthly_rent),
+            ("closing_cost_pct", self.closing_cost_pct),
+            ("selling_cost_pct", self.selling_cost_pct),
+        ];
+        for (field, value) in non_negative {
+            if value.is_some_and(|v| !v.is_finite() || v < 0.0) {
+                errors.push(format!("{} must not be negative", field));
+            }
+        }
+        let percentages = [
+            ("loan_to_cost_pct", self.loan_to_cost_pct),
+            ("vacancy_pct", self.vacancy_pct),
+            ("operating_expense_pct", self.operating_expense_pct),
+        ];
+        for (field, value) in percentages {
+            if value.is_some_and(|v| !(0.0..=100.0).contains(&v)) {
+                errors.push(format!("{} must be between 0 and 100", field));
+            }
+        }
+        if self.flip_months.is_some_and(|m| !(1..=MAX_FLIP_MONTHS).contains(&m)) {
+            errors.push(format!("flip_months must be between 1 and {}", MAX_FLIP_MONTHS));
+        }
+        if self.hold_years.is_some_and(|y| !(1..=MAX_HOLD_YEARS).contains(&y)) {
+            errors.push(format!("hold_years must be between 1 and {}", MAX_HOLD_YEARS));
+        }
+        errors
+    }
+}
+
+// Market inputs resolved by the caller (ZHVI lookup, FRED rates)
+#[derive(Debug, Serialize, Clone)]
+pub struct MarketInputs {
+    pub arv: f64,
+    pub arv_source: String,
+    pub appreciation_pct: f64,
+    pub mortgage_rate_pct: f64,
+    pub rate_source: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Financing {
+    pub total_cost: f64,
+    pub loan_amount: f64,
+    pub cash_invested: f64,
+    pub mortgage_rate_pct: f64,
+    pub monthly_payment: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FlipScenario {
+    pub months: u32,
+    pub holding_costs: f64,
+    pub debt_service: f64,
+    pub sale_price: f64,
+    pub selling_costs: f64,
+    pub loan_payoff: f64,
+    pub profit: f64,
+    pub cash_on_cash_pct: f64,
+    pub irr_pct: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RentalScenario {
+    pub hold_years: u32,
+    pub monthly_rent: f64,
+    pub net_operating_income: f64,
+    pub annual_debt_service: f64,
+    // NOI / debt service; None when the purchase is all cash
+    pub dscr: Option<f64>,
+    pub annual_cash_flow: f64,
+    pub cash_on_cash_pct: f64,
+    pub exit_value: f64,
+    pub loan_payoff: f64,
+    pub profit: f64,
+    pub irr_pct: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DeedAnalysis {
+    pub state: String,
+    pub county: Option<String>,
+    pub bid_amount: f64,
+    pub rehab_cost: f64,
+    pub market: MarketInputs,
+    pub financing: Financing,
+    pub flip: FlipScenario,
+    pub rental: RentalScenario,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+fn pct_of(value: f64, pct: f64) -> f64 {
+    value * pct / 100.0
+}
+
+// Level payment on a fully amortizing loan
+fn monthly_payment(principal: f64, annual_rate_pct: f64, months: u32) -> f64 {
+    if principal <= 0.0 {
+        return 0.0;
+    }
+    let r = annual_rate_pct / 100.0 / 12.0;
+    if r == 0.0 {
+        return principal / months as f64;
+    }
+    principal * r / (1.0 - (1.0 + r).powi(-(months as i32)))
+}
+
+// Principal still owed after `paid` payments
+fn remaining_balance(principal: f64, annual_rate_pct: f64, payment: f64, paid: u32) -> f64 {
+    let r = annual_rate_pct / 100.0 / 12.0;
+    if r == 0.0 {
+        return (principal - payment * paid as f64).max(0.0);
+    }
+    let growth = (1.0 + r).powi(paid as i32);
+    (principal * growth - payment * (growth - 1.0) / r).max(0.0)
+}
+
+fn npv(rate: f64, cash_flows: &[f64]) -> f64 {
+    cash_flows
+        .iter()
+        .enumerate()
+        .map(|(t, cf)| cf / (1.0 + rate).powi(t as i32))
+        .sum()
+}
+
+// Per-period internal rate of return by bisection; None if the flows never change sign
+pub fn irr(cash_flows: &[f64]) -> Option<f64> {
+    let (mut low, mut high) = (-0.9999, 10.0);
+    // Long horizons overflow near -100%; pull the lower bound in until the NPV is finite
+    while !npv(low, cash_flows).is_finite() && low < -0.01 {
+        low /= 2.0;
+    }
+    let (npv_low, npv_high) = (npv(low, cash_flows), npv(high, cash_flows));
+    if !npv_low.is_finite() || npv_low.signum() == npv_high.signum() {
+        return None;
+    }
+    for _ in 0..200 {
+        let mid = (low + high) / 2.0;
+        let value = npv(mid, cash_flows);
+        if value.abs() < 1e-7 {
+            return Some(mid);
+        }
+        if value.signum() == npv_low.signum() {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Some((low + high) / 2.0)
+}
+
+// Monthly IRR expressed as an annual percentage
+fn annualized_irr_pct(monthly_flows: &[f64]) -> Option<f64> {
+    irr(monthly_flows).map(|r| round2(((1.0 + r).powi(12) - 1.0) * 100.0))
+}
+
+pub fn analyze(input: &DeedAnalysisInput, market: MarketInputs) -> DeedAnalysis {
+    let mut warnings = Vec::new();
+    let arv = market.arv;
+    let rate = market.mortgage_rate_pct;
+
+    // Acquisition and financing shared by both scenarios
+    let closing_costs = pct_of(input.bid_amount, input.closing_cost_pct.unwrap_or(DEFAULT_CLOSING_COST_PCT));
+    let total_cost = input.bid_amount + input.rehab_cost + closing_costs;
+    let loan_amount = pct_of(
+        input.bid_amount + input.rehab_cost,
+        input.loan_to_cost_pct.unwrap_or(DEFAULT_LOAN_TO_COST_PCT),
+    );
+    let cash_invested = total_cost - loan_amount;
+    let payment = monthly_payment(loan_amount, rate, LOAN_TERM_MONTHS);
+    if total_cost > arv {
+        warnings.push(format!("All-in cost ${:.0} exceeds the after-repair value ${:.0}", total_cost, arv));
+    }
+
+    // Flip: carry for N months, then sell at ARV and pay off the loan
+    let months = input.flip_months.unwrap_or(DEFAULT_FLIP_MONTHS);
+    let monthly_holding = input
+        .monthly_holding_cost
+        .unwrap_or(pct_of(arv, DEFAULT_HOLDING_COST_PCT) / 12.0);
+    let selling_costs = pct_of(arv, input.selling_cost_pct.unwrap_or(DEFAULT_SELLING_COST_PCT));
+    let flip_payoff = remaining_balance(loan_amount, rate, payment, months);
+    let monthly_carry = monthly_holding + payment;
+    let flip_proceeds = arv - selling_costs - flip_payoff;
+    let flip_profit = flip_proceeds - cash_invested - monthly_carry * months as f64;
+
+    let mut flip_flows = vec![-cash_invested];
+    flip_flows.extend((1..months).map(|_| -monthly_carry));
+    flip_flows.push(flip_proceeds - monthly_carry);
+
+    // Rental: stabilize at the supplied (or rent-to-value) rent and sell after the hold period
+    let hold_years = input.hold_years.unwrap_or(DEFAULT_HOLD_YEARS);
+    let monthly_rent = input.monthly_rent.unwrap_or(pct_of(arv, DEFAULT_RENT_TO_VALUE_PCT));
+    let collected = monthly_rent * 12.0 * (1.0 - input.vacancy_pct.unwrap_or(DEFAULT_VACANCY_PCT) / 100.0);
+    let noi = collected - pct_of(collected, input.operating_expense_pct.unwrap_or(DEFAULT_OPERATING_EXPENSE_PCT));
+    let annual_debt_service = payment * 12.0;
+    let annual_cash_flow = noi - annual_debt_service;
+    let dscr = (annual_debt_service > 0.0).then(|| round2(noi / annual_debt_service));
+    if dscr.is_some_and(|d| d < 1.0) {
+        warnings.push("Rental DSCR is below 1.0; rent does not cover debt service".to_string());
+    }
+
+    let hold_months = hold_years * 12;
+    let exit_value = arv * (1.0 + market.appreciation_pct / 100.0).powi(hold_years as i32);
+    let rental_payoff = remaining_balance(loan_amount, rate, payment, hold_months);
+    let exit_proceeds = exit_value
+        - pct_of(exit_value, input.selling_cost_pct.unwrap_or(DEFAULT_SELLING_COST_PCT))
+        - rental_payoff;
+    let rental_profit = annual_cash_flow * hold_years as f64 + exit_proceeds - cash_invested;
+
+    let monthly_cash_flow = annual_cash_flow / 12.0;
+    let mut rental_flows = vec![-cash_invested];
+    rental_flows.extend((1..hold_months).map(|_| monthly_cash_flow));
+    rental_flows.push(monthly_cash_flow + exit_proceeds);
+
+    let cash_on_cash = |amount: f64| if cash_invested > 0.0 { round2(amount / cash_invested * 100.0) } else { 0.0 };
+
+    DeedAnalysis {
+        state: input.state.trim().to_uppercase(),
+        county: input.county.clone(),
+        bid_amount: round2(input.bid_amount),
+        rehab_cost: round2(input.rehab_cost),
+        financing: Financing {
+            total_cost: round2(total_cost),
+            loan_amount: round2(loan_amount),
+            cash_invested: round2(cash_invested),
+            mortgage_rate_pct: rate,
+            monthly_payment: round2(payment),
+        },
+        flip: FlipScenario {
+            months,
+            holding_costs: round2(monthly_holding * months as f64),
+            debt_service: round2(payment * months as f64),
+            sale_price: round2(arv),
+            selling_costs: round2(selling_costs),
+            loan_payoff: round2(flip_payoff),
+            profit: round2(flip_profit),
+            cash_on_cash_pct: cash_on_cash(flip_profit),
+            irr_pct: annualized_irr_pct(&flip_flows),
+        },
+        rental: RentalScenario {
+            hold_years,
+            monthly_rent: round2(monthly_rent),
+            net_operating_income: round2(noi),
+            annual_debt_service: round2(annual_debt_service),
+            dscr,
+            annual_cash_flow: round2(annual_cash_flow),
+            cash_on_cash_pct: cash_on_cash(annual_cash_flow),
+            exit_value: round2(exit_value),
+            loan_payoff: round2(rental_payoff),
+            profit: round2(rental_profit),
+            irr_pct: annualized_irr_pct(&rental_flows),
+        },
+        market,
+        warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(value: serde_json::Value) -> DeedAnalysisInput {
+        serde_json::from_value(value).expect("valid deed input")
+    }
+
+    fn market(arv: f64, rate: f64) -> MarketInputs {
+        MarketInputs {
+            arv,
+            arv_source: "test".to_string(),
+            appreciation_pct: 0.0,
+            mortgage_rate_pct: rate,
+            rate_source: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn irr_solves_simple_flows() {
+        let r = irr(&[-100.0, 110.0]).unwrap();
+        assert!((r - 0.10).abs() < 1e-6, "{}", r);
+        // Two equal annual inflows of 60 on 100 invested: ~13.07%
+        let r = irr(&[-100.0, 60.0, 60.0]).unwrap();
+        assert!((r - 0.130662).abs() < 1e-5, "{}", r);
+    }
+
+    #[test]
+    fn irr_is_none_without_a_sign_change() {
+        assert_eq!(irr(&[-100.0, -10.0]), None);
+        assert_eq!(irr(&[100.0, 10.0]), None);
+    }
+
+    #[test]
+    fn level_payment_matches_amortization_table() {
+        // $100k over 30 years at 6%
+        assert_eq!(round2(monthly_payment(100_000.0, 6.0, 360)), 599.55);
+        assert_eq!(monthly_payment(0.0, 6.0, 360), 0.0);
+        assert_eq!(monthly_payment(12_000.0, 0.0, 12), 1000.0);
+    }
+
+    #[test]
+    fn remaining_balance_amortizes_to_zero() {
+        let payment = monthly_payment(100_000.0, 6.0, 360);
+        assert_eq!(remaining_balance(100_000.0, 6.0, payment, 0), 100_000.0);
+        // After the first payment only the principal part comes off: 599.55 - 500 interest
+        assert_eq!(round2(remaining_balance(100_000.0, 6.0, payment, 1)), 99_900.45);
+        assert!(remaining_balance(100_000.0, 6.0, payment, 360) < 0.01);
+        assert_eq!(remaining_balance(12_000.0, 0.0, 1000.0, 5), 7000.0);
+    }
+
+    #[test]
+    fn dscr_is_noi_over_debt_service() {
+        let deal = input(serde_json::json!({
+            "state": "TX", "arv": 150000.0, "bid_amount": 100000.0,
+            "closing_cost_pct": 0.0, "loan_to_cost_pct": 70.0,
+            "monthly_rent": 1000.0, "vacancy_pct": 0.0, "operating_expense_pct": 0.0
+        }));
+        let analysis = analyze(&deal, market(150_000.0, 6.0));
+        // Loan $70k at 6%: 419.69/month, 5036.28/year against NOI 12000
+        assert_eq!(analysis.financing.monthly_payment, 419.69);
+        assert_eq!(analysis.rental.net_operating_income, 12_000.0);
+        assert_eq!(analysis.rental.dscr, Some(2.38));
+    }
+
+    #[test]
+    fn all_cash_purchase_has_no_dscr() {
+        let deal = input(serde_json::json!({
+            "state": "TX", "arv": 150000.0, "bid_amount": 100000.0, "loan_to_cost_pct": 0.0
+        }));
+        let analysis = analyze(&deal, market(150_000.0, 6.0));
+        assert_eq!(analysis.financing.loan_amount, 0.0);
+        assert_eq!(analysis.rental.dscr, None);
+    }
+
+    #[test]
+    fn rejects_oversized_scenario_lengths() {
+        let deal = input(serde_json::json!({
+            "state": "TX", "arv": 150000.0, "bid_amount": 100000.0,
+            "flip_months": 4_000_000_000u32, "hold_years": 1_000_000
+        }));
+        let errors = deal.validate();
+        assert!(errors.contains(&"flip_months must be between 1 and 60".to_string()), "{:?}", errors);
+        assert!(errors.contains(&"hold_years must be between 1 and 40".to_string()), "{:?}", errors);
+    }
+}
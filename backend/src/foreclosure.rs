@@ -43,25 +43,6 @@ pub struct ForeclosureSummary {
     pub updated: String,
 }
 
-// State name mappings
-static STATE_NAMES: Lazy<HashMap<&str, &str>> = Lazy::new(|| {
-    let mut m = HashMap::new();
-    m.insert("AL", "Alabama"); m.insert("AK", "Alaska"); m.insert("AZ", "Arizona"); m.insert("AR", "Arkansas");
-    m.insert("CA", "California"); m.insert("CO", "Colorado"); m.insert("CT", "Connecticut"); m.insert("DE", "Delaware");
-    m.insert("DC", "District of Columbia"); m.insert("FL", "Florida"); m.insert("GA", "Georgia"); m.insert("HI", "Hawaii");
-    m.insert("ID", "Idaho"); m.insert("IL", "Illinois"); m.insert("IN", "Indiana"); m.insert("IA", "Iowa");
-    m.insert("KS", "Kansas"); m.insert("KY", "Kentucky"); m.insert("LA", "Louisiana"); m.insert("ME", "Maine");
-    m.insert("MD", "Maryland"); m.insert("MA", "Massachusetts"); m.insert("MI", "Michigan"); m.insert("MN", "Minnesota");
-    m.insert("MS", "Mississippi"); m.insert("MO", "Missouri"); m.insert("MT", "Montana"); m.insert("NE", "Nebraska");
-    m.insert("NV", "Nevada"); m.insert("NH", "New Hampshire"); m.insert("NJ", "New Jersey"); m.insert("NM", "New Mexico");
-    m.insert("NY", "New York"); m.insert("NC", "North Carolina"); m.insert("ND", "North Dakota"); m.insert("OH", "Ohio");
-    m.insert("OK", "Oklahoma"); m.insert("OR", "Oregon"); m.insert("PA", "Pennsylvania"); m.insert("RI", "Rhode Island");
-    m.insert("SC", "South Carolina"); m.insert("SD", "South Dakota"); m.insert("TN", "Tennessee"); m.insert("TX", "Texas");
-    m.insert("UT", "Utah"); m.insert("VT", "Vermont"); m.insert("VA", "Virginia"); m.insert("WA", "Washington");
-    m.insert("WV", "West Virginia"); m.insert("WI", "Wisconsin"); m.insert("WY", "Wyoming");
-    m
-});
-
 // Cache for foreclosure data
 pub struct ForeclosureCache {
     data: RwLock<HashMap<String, Vec<ForeclosureProperty>>>,
@@ -7,12 +7,35 @@
 //! - CPIAUCSL - Consumer Price Index (Inflation)
 //! - UNRATE - Unemployment Rate
 //! - HOUST - Housing Starts
+//! - DGS10 - 10-Year Treasury Yield
+//! - UMCSENT - Consumer Sentiment (University of Michigan)
+//! - MSPUS, MSACSR, MEDDAYONMARUS - Median sale price, months of supply, days on market
 
-use serde::{Deserialize, Serialize};
+use once_cell::sync::Lazy;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::data_cache;
 
 // FRED API base URL
-const FRED_API_BASE: &str = "https://api.stlouisfed.org/fred/series/observations";
+const FRED_BASE_URL: &str = "https://api.stlouisfed.org/fred";
+
+// Per-request timeout, and retries for 429/5xx with exponential backoff from BACKOFF_BASE
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(8);
+const MAX_RETRIES: u32 = 3;
+const BACKOFF_BASE: Duration = Duration::from_millis(500);
+const BACKOFF_MAX: Duration = Duration::from_secs(8);
+
+// FRED returns at most this many observations per request
+const MAX_OBSERVATIONS: usize = 100_000;
+
+/// Frequencies FRED can aggregate a series to (d = daily ... a = annual)
+pub const FREQUENCIES: [&str; 7] = ["d", "w", "bw", "m", "q", "sa", "a"];
+
+/// FRED's data transforms: lin = levels, pch = % change, pc1 = % change from a year ago, log = natural log
+pub const UNITS: [&str; 9] = ["lin", "chg", "ch1", "pch", "pc1", "pca", "cch", "cca", "log"];
 
 // Series IDs for economic indicators
 pub const SERIES_MORTGAGE_30YR: &str = "MORTGAGE30US";
@@ -22,6 +45,20 @@ pub const SERIES_CPI: &str = "CPIAUCSL";
 pub const SERIES_UNEMPLOYMENT: &str = "UNRATE";
 pub const SERIES_HOUSING_STARTS: &str = "HOUST";
 pub const SERIES_TREASURY_10YR: &str = "DGS10";
+pub const SERIES_CONSUMER_SENTIMENT: &str = "UMCSENT";
+pub const SERIES_MEDIAN_SALE_PRICE: &str = "MSPUS";
+pub const SERIES_MONTHS_SUPPLY: &str = "MSACSR";
+pub const SERIES_DAYS_ON_MARKET: &str = "MEDDAYONMARUS";
+
+// Values served when FRED is unreachable or no API key is set
+pub const FALLBACK_MORTGAGE_30YR: f64 = 6.72;
+pub const FALLBACK_MORTGAGE_30YR_CHANGE: f64 = 0.12;
+pub const FALLBACK_MORTGAGE_15YR: f64 = 5.92;
+pub const FALLBACK_FED_FUNDS: f64 = 4.33;
+pub const FALLBACK_CPI_YOY: f64 = 2.9;
+pub const FALLBACK_UNEMPLOYMENT: f64 = 4.1;
+pub const FALLBACK_HOUSING_STARTS: f64 = 1.499;
+pub const FALLBACK_TREASURY_10YR: f64 = 4.68;
 
 #[derive(Debug, Deserialize)]
 struct FredResponse {
@@ -34,142 +71,296 @@ struct FredObservation {
     value: String,
 }
 
-#[derive(Debug, Serialize, Clone)]
-pub struct FredDataPoint {
-    pub series_id: String,
-    pub name: String,
+/// Where a reading came from
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Provenance {
+    /// Fetched from FRED for this response
+    Live,
+    /// FRED failed; the last value fetched successfully
+    Cached,
+    /// No key or no successful fetch yet; a built-in constant
+    Fallback,
+}
+
+/// Latest value of a series and its change from the previous observation
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Reading {
     pub value: f64,
-    pub date: String,
-    pub unit: String,
+    pub change: f64,
+    /// Observation date; None when the value is a fallback
+    pub date: Option<String>,
+    pub provenance: Provenance,
+    /// Why the live fetch failed, when it did
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Reading {
+    pub fn fallback(value: f64, change: f64) -> Reading {
+        Reading { value, change, date: None, provenance: Provenance::Fallback, error: None }
+    }
 }
 
 #[derive(Debug, Serialize, Clone)]
 pub struct LiveRatesData {
-    pub mortgage_30yr: f64,
-    pub mortgage_15yr: f64,
-    pub mortgage_30yr_change: f64,
-    pub fed_funds: f64,
-    pub cpi_yoy: f64,
-    pub unemployment: f64,
-    pub housing_starts: f64,
-    pub treasury_10yr: f64,
+    pub mortgage_30yr: Reading,
+    pub mortgage_15yr: Reading,
+    pub fed_funds: Reading,
+    /// CPI-U, percent change from a year ago
+    pub cpi_yoy: Reading,
+    pub unemployment: Reading,
+    /// Annual rate, millions of units
+    pub housing_starts: Reading,
+    pub treasury_10yr: Reading,
     pub updated: String,
     pub source: String,
 }
 
-/// Fetch latest observation for a FRED series
-async fn fetch_series(series_id: &str, api_key: Option<&str>) -> Result<(f64, String), String> {
-    let key = api_key.unwrap_or("DEMO_API_KEY"); // Use demo key if not provided
-    
-    let url = format!(
-        "{}?series_id={}&api_key={}&file_type=json&sort_order=desc&limit=1",
-        FRED_API_BASE, series_id, key
-    );
+/// A real FRED key; the demo placeholder is treated as no key
+fn usable_key(api_key: Option<&str>) -> Option<&str> {
+    api_key.filter(|k| !k.is_empty() && *k != "DEMO_API_KEY")
+}
 
-    match reqwest::get(&url).await {
-        Ok(response) => {
-            if let Ok(data) = response.json::<FredResponse>().await {
-                if let Some(obs) = data.observations.first() {
-                    let value: f64 = obs.value.parse().unwrap_or(0.0);
-                    return Ok((value, obs.date.clone()));
+/// HTTP access to FRED with timeouts, retries and a last-known-good reading per series
+pub struct FredClient {
+    http: reqwest::Client,
+    base_url: String,
+    max_retries: u32,
+    backoff: Duration,
+    /// Where last good readings persist across restarts
+    store: &'static data_cache::Store,
+}
+
+static CLIENT: Lazy<FredClient> = Lazy::new(|| {
+    FredClient::new(FRED_BASE_URL, REQUEST_TIMEOUT, MAX_RETRIES, BACKOFF_BASE, data_cache::shared())
+});
+
+/// Series history responses are cached for an hour and served up to a day old while refreshing
+const SERIES_HISTORY_POLICY: data_cache::Policy = data_cache::Policy {
+    ttl: Duration::from_secs(3600),
+    stale_while_revalidate: Duration::from_secs(24 * 3600),
+};
+
+/// The shared client every fetch in this module goes through
+pub fn client() -> &'static FredClient {
+    &CLIENT
+}
+
+impl FredClient {
+    pub fn new(base_url: &str, timeout: Duration, max_retries: u32, backoff: Duration, store: &'static data_cache::Store) -> FredClient {
+        FredClient {
+            http: reqwest::Client::builder().timeout(timeout).build().unwrap_or_default(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            max_retries,
+            backoff,
+            store,
+        }
+    }
+
+    /// GET a FRED endpoint, retrying 429 and 5xx; Ok(None) when FRED says the series does not exist
+    async fn get_json<T: DeserializeOwned>(&self, endpoint: &str, params: &[(&str, String)]) -> Result<Option<T>, String> {
+        let url = format!("{}/{}", self.base_url, endpoint);
+        let mut attempt = 0;
+        loop {
+            // without_url keeps the api_key out of error messages
+            let response = self
+                .http
+                .get(&url)
+                .query(params)
+                .send()
+                .await
+                .map_err(|e| if e.is_timeout() {
+                    format!("FRED timed out: {}", e.without_url())
+                } else {
+                    format!("Failed to reach FRED: {}", e.without_url())
+                })?;
+            let status = response.status();
+            if status.is_success() {
+                return response
+                    .json::<T>()
+                    .await
+                    .map(Some)
+                    .map_err(|e| format!("Unexpected FRED response: {}", e.without_url()));
+            }
+            if status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+                if attempt >= self.max_retries {
+                    return Err(format!("FRED returned HTTP {} after {} attempts", status.as_u16(), attempt + 1));
                 }
+                // Honor Retry-After (in seconds) when FRED sends one
+                let delay = response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.trim().parse::<u64>().ok())
+                    .map(Duration::from_secs)
+                    .unwrap_or(self.backoff * 2u32.pow(attempt));
+                tokio::time::sleep(delay.min(BACKOFF_MAX)).await;
+                attempt += 1;
+                continue;
+            }
+            let message = response
+                .json::<FredErrorResponse>()
+                .await
+                .map(|e| e.error_message)
+                .unwrap_or_else(|_| format!("HTTP {}", status));
+            if status == reqwest::StatusCode::NOT_FOUND || message.contains("does not exist") {
+                return Ok(None);
             }
-            Err(format!("No data for series {}", series_id))
+            return Err(format!("FRED error: {}", message));
         }
-        Err(e) => Err(format!("Failed to fetch {}: {}", series_id, e)),
     }
-}
 
-/// Fetch historical data for a series (for calculating change)
-async fn fetch_series_history(series_id: &str, api_key: Option<&str>, limit: usize) -> Result<Vec<(String, f64)>, String> {
-    let key = api_key.unwrap_or("DEMO_API_KEY");
-    
-    let url = format!(
-        "{}?series_id={}&api_key={}&file_type=json&sort_order=desc&limit={}",
-        FRED_API_BASE, series_id, key, limit
-    );
+    /// The most recent observations of a series, newest first, with FRED applying `units`
+    async fn history(&self, series_id: &str, units: &str, api_key: &str, limit: usize) -> Result<Vec<(String, f64)>, String> {
+        let params = [
+            ("series_id", series_id.to_string()),
+            ("api_key", api_key.to_string()),
+            ("file_type", "json".to_string()),
+            ("sort_order", "desc".to_string()),
+            ("limit", limit.to_string()),
+            ("units", units.to_string()),
+        ];
+        let response = self
+            .get_json::<FredResponse>("series/observations", &params)
+            .await?
+            .ok_or_else(|| format!("FRED has no series {}", series_id))?;
+        Ok(response
+            .observations
+            .iter()
+            .filter_map(|obs| obs.value.parse::<f64>().ok().map(|v| (obs.date.clone(), v)))
+            .collect())
+    }
+
+    /// Latest live reading of a series, scaled by `scale` (e.g. thousands to millions)
+    async fn reading(&self, series_id: &str, units: &str, scale: f64, api_key: &str) -> Result<Reading, String> {
+        // A few extra observations so a missing latest value (".") doesn't leave no change
+        let history = self.history(series_id, units, api_key, 4).await?;
+        let (date, current) = history.first().ok_or_else(|| format!("No data for series {}", series_id))?;
+        let change = history.get(1).map_or(0.0, |(_, previous)| (current - previous) * scale);
+        Ok(Reading {
+            value: current * scale,
+            change: (change * 1000.0).round() / 1000.0,
+            date: Some(date.clone()),
+            provenance: Provenance::Live,
+            error: None,
+        })
+    }
 
-    match reqwest::get(&url).await {
-        Ok(response) => {
-            if let Ok(data) = response.json::<FredResponse>().await {
-                let history: Vec<(String, f64)> = data.observations
-                    .iter()
-                    .filter_map(|obs| {
-                        obs.value.parse::<f64>().ok().map(|v| (obs.date.clone(), v))
-                    })
-                    .collect();
-                return Ok(history);
+    /// Live reading when FRED answers, else the last good one, else `fallback`
+    pub async fn reading_or(&self, series_id: &str, units: &str, scale: f64, api_key: Option<&str>, fallback: Reading) -> Reading {
+        let Some(key) = usable_key(api_key) else {
+            return fallback;
+        };
+        let cache_params = format!("{}.{}", series_id, units);
+        match self.reading(series_id, units, scale, key).await {
+            Ok(reading) => {
+                self.store.put("fred", &cache_params, Arc::new(reading.clone())).await;
+                reading
+            }
+            Err(e) => {
+                eprintln!("FRED {}: {}", series_id, e);
+                let cached = self.store.peek::<Reading>("fred", &cache_params).await;
+                let mut reading = match cached {
+                    Some(cached) => Reading { provenance: Provenance::Cached, ..(*cached.value).clone() },
+                    None => fallback,
+                };
+                reading.error = Some(e);
+                reading
             }
-            Err("Failed to parse response".to_string())
         }
-        Err(e) => Err(format!("Failed to fetch history: {}", e)),
     }
+
+    /// Every headline rate, fetched concurrently
+    pub async fn live_rates(&self, api_key: Option<&str>) -> LiveRatesData {
+        let (mortgage_30yr, mortgage_15yr, fed_funds, cpi_yoy, unemployment, housing_starts, treasury_10yr) = tokio::join!(
+            self.reading_or(SERIES_MORTGAGE_30YR, "lin", 1.0, api_key, Reading::fallback(FALLBACK_MORTGAGE_30YR, FALLBACK_MORTGAGE_30YR_CHANGE)),
+            self.reading_or(SERIES_MORTGAGE_15YR, "lin", 1.0, api_key, Reading::fallback(FALLBACK_MORTGAGE_15YR, 0.0)),
+            self.reading_or(SERIES_FED_FUNDS, "lin", 1.0, api_key, Reading::fallback(FALLBACK_FED_FUNDS, 0.0)),
+            self.reading_or(SERIES_CPI, "pc1", 1.0, api_key, Reading::fallback(FALLBACK_CPI_YOY, 0.0)),
+            self.reading_or(SERIES_UNEMPLOYMENT, "lin", 1.0, api_key, Reading::fallback(FALLBACK_UNEMPLOYMENT, 0.0)),
+            self.reading_or(SERIES_HOUSING_STARTS, "lin", 0.001, api_key, Reading::fallback(FALLBACK_HOUSING_STARTS, 0.0)),
+            self.reading_or(SERIES_TREASURY_10YR, "lin", 1.0, api_key, Reading::fallback(FALLBACK_TREASURY_10YR, 0.0)),
+        );
+
+        // Label the response by the weakest source any field fell back to
+        let provenances = [&mortgage_30yr, &mortgage_15yr, &fed_funds, &cpi_yoy, &unemployment, &housing_starts, &treasury_10yr]
+            .map(|r| r.provenance);
+        let source = if provenances.iter().all(|p| *p == Provenance::Live) {
+            "FRED API (Live)"
+        } else if provenances.iter().all(|p| *p == Provenance::Fallback) {
+            "Fallback Data"
+        } else if provenances.contains(&Provenance::Live) {
+            "FRED API (Partial)"
+        } else {
+            "FRED API (Cached)"
+        };
+
+        LiveRatesData {
+            updated: mortgage_30yr.date.clone().unwrap_or_else(|| chrono::Utc::now().to_rfc3339()),
+            source: source.to_string(),
+            mortgage_30yr,
+            mortgage_15yr,
+            fed_funds,
+            cpi_yoy,
+            unemployment,
+            housing_starts,
+            treasury_10yr,
+        }
+    }
+}
+
+/// Fetch latest observation for a FRED series
+pub async fn fetch_series(series_id: &str, api_key: Option<&str>) -> Result<(f64, String), String> {
+    let key = api_key.unwrap_or("DEMO_API_KEY"); // Use demo key if not provided
+    let history = client().history(series_id, "lin", key, 4).await?;
+    history
+        .into_iter()
+        .next()
+        .map(|(date, value)| (value, date))
+        .ok_or_else(|| format!("No data for series {}", series_id))
+}
+
+/// Latest reading of a series with provenance; see FredClient::reading_or
+pub async fn fetch_reading_or(series_id: &str, units: &str, api_key: Option<&str>, fallback: Reading) -> Reading {
+    client().reading_or(series_id, units, 1.0, api_key, fallback).await
 }
 
 /// Fetch all live economic data from FRED
 pub async fn fetch_live_rates(api_key: Option<&str>) -> LiveRatesData {
-    // Try to fetch live data, fall back to defaults
-    let mut mortgage_30yr = 6.72;
-    let mut mortgage_15yr = 5.92;
-    let mut mortgage_30yr_change = 0.12;
-    let mut fed_funds = 4.33;
-    let mut cpi_yoy = 2.9;
-    let mut unemployment = 4.1;
-    let mut housing_starts = 1.499;
-    let mut treasury_10yr = 4.68;
-    let mut updated = chrono::Utc::now().to_rfc3339();
-    let mut source = "Fallback Data".to_string();
-
-    // Only try live fetch if API key is available
-    if let Some(key) = api_key {
-        if !key.is_empty() && key != "DEMO_API_KEY" {
-            source = "FRED API (Live)".to_string();
-            
-            // Fetch 30-year mortgage with history for change calculation
-            if let Ok(history) = fetch_series_history(SERIES_MORTGAGE_30YR, Some(key), 2).await {
-                if let Some((date, current)) = history.first() {
-                    mortgage_30yr = *current;
-                    updated = date.clone();
-                    if let Some((_, previous)) = history.get(1) {
-                        mortgage_30yr_change = ((current - previous) * 100.0).round() / 100.0;
-                    }
-                }
-            }
-
-            // Fetch 15-year mortgage
-            if let Ok((value, _)) = fetch_series(SERIES_MORTGAGE_15YR, Some(key)).await {
-                mortgage_15yr = value;
-            }
+    client().live_rates(api_key).await
+}
 
-            // Fetch Fed Funds
-            if let Ok((value, _)) = fetch_series(SERIES_FED_FUNDS, Some(key)).await {
-                fed_funds = value;
-            }
+/// Unemployment rate and where it came from
+#[derive(Debug, Clone)]
+pub struct UnemploymentReading {
+    pub rate: f64,
+    /// "county", "state", "national" or "fallback"
+    pub level: &'static str,
+    pub series_id: Option<String>,
+    pub date: Option<String>,
+}
 
-            // Fetch Unemployment
-            if let Ok((value, _)) = fetch_series(SERIES_UNEMPLOYMENT, Some(key)).await {
-                unemployment = value;
-            }
+/// BLS LAUS county unemployment rate as published on FRED (monthly, not seasonally adjusted)
+pub fn county_unemployment_series(fips: &str) -> String {
+    format!("LAUCN{}0000000003", fips)
+}
 
-            // Fetch 10-Year Treasury
-            if let Ok((value, _)) = fetch_series(SERIES_TREASURY_10YR, Some(key)).await {
-                treasury_10yr = value;
+/// Latest unemployment rate for a county, falling back to its state and then the nation
+pub async fn fetch_county_unemployment(fips: &str, state_abbr: &str, api_key: Option<&str>) -> UnemploymentReading {
+    if let Some(key) = usable_key(api_key) {
+        let candidates = [
+            ("county", county_unemployment_series(fips)),
+            ("state", format!("{}UR", state_abbr)),
+            ("national", SERIES_UNEMPLOYMENT.to_string()),
+        ];
+        for (level, series_id) in candidates {
+            if let Ok((rate, date)) = fetch_series(&series_id, Some(key)).await {
+                return UnemploymentReading { rate, level, series_id: Some(series_id), date: Some(date) };
             }
         }
     }
-
-    LiveRatesData {
-        mortgage_30yr,
-        mortgage_15yr,
-        mortgage_30yr_change,
-        fed_funds,
-        cpi_yoy,
-        unemployment,
-        housing_starts,
-        treasury_10yr,
-        updated,
-        source,
-    }
+    UnemploymentReading { rate: FALLBACK_UNEMPLOYMENT, level: "fallback", series_id: None, date: None }
 }
 
 /// Get indicator metadata
@@ -182,5 +373,407 @@ pub fn get_indicator_metadata() -> HashMap<&'static str, (&'static str, &'static
     meta.insert(SERIES_UNEMPLOYMENT, ("Unemployment Rate", "%"));
     meta.insert(SERIES_HOUSING_STARTS, ("Housing Starts", "M units"));
     meta.insert(SERIES_TREASURY_10YR, ("10-Year Treasury", "%"));
+    meta.insert(SERIES_CONSUMER_SENTIMENT, ("Consumer Sentiment", "index"));
+    meta.insert(SERIES_MEDIAN_SALE_PRICE, ("Median Home Sale Price", "$"));
+    meta.insert(SERIES_MONTHS_SUPPLY, ("Months of Supply", "months"));
+    meta.insert(SERIES_DAYS_ON_MARKET, ("Median Days on Market", "days"));
     meta
 }
+
+/// FRED's description of a series, plus our display name and unit when it is one we track
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SeriesInfo {
+    pub id: String,
+    pub title: String,
+    pub frequency: String,
+    pub units: String,
+    pub seasonal_adjustment: String,
+    pub observation_start: String,
+    pub observation_end: String,
+    pub last_updated: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_unit: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FredSeriesResponse {
+    seriess: Vec<SeriesInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FredErrorResponse {
+    error_message: String,
+}
+
+/// Optional FRED observation parameters; None leaves FRED's default
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct SeriesQuery {
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub frequency: Option<String>,
+    pub units: Option<String>,
+}
+
+impl SeriesQuery {
+    /// Problems with the parameters, reported before anything is sent to FRED
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let date = |name: &str, value: &Option<String>, errors: &mut Vec<String>| {
+            let value = value.as_deref()?;
+            let parsed = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").ok();
+            if parsed.is_none() {
+                errors.push(format!("{} must be a YYYY-MM-DD date, got '{}'", name, value));
+            }
+            parsed
+        };
+        let start = date("start", &self.start, &mut errors);
+        let end = date("end", &self.end, &mut errors);
+        if let (Some(start), Some(end)) = (start, end) {
+            if start > end {
+                errors.push("start must not be after end".to_string());
+            }
+        }
+        if let Some(frequency) = self.frequency.as_deref().filter(|f| !FREQUENCIES.contains(f)) {
+            errors.push(format!("frequency must be one of {}, got '{}'", FREQUENCIES.join(", "), frequency));
+        }
+        if let Some(units) = self.units.as_deref().filter(|u| !UNITS.contains(u)) {
+            errors.push(format!("units must be one of {}, got '{}'", UNITS.join(", "), units));
+        }
+        errors
+    }
+}
+
+/// One observation; None where FRED reports "." (no value for that period)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SeriesObservation {
+    pub date: String,
+    pub value: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SeriesHistory {
+    pub info: SeriesInfo,
+    pub units: String,
+    pub frequency: Option<String>,
+    pub observation_count: usize,
+    pub observations: Vec<SeriesObservation>,
+    /// When FRED was last asked; stale when that copy is past its TTL (refreshing, or FRED is down)
+    #[serde(default)]
+    pub fetched_at: Option<String>,
+    #[serde(default)]
+    pub stale: bool,
+}
+
+/// FRED series IDs are short alphanumeric codes (MORTGAGE30US, LAUCN060370000000003)
+pub fn is_valid_series_id(series_id: &str) -> bool {
+    !series_id.is_empty() && series_id.len() <= 30 && series_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Series metadata from FRED's series endpoint, merged with get_indicator_metadata
+pub async fn fetch_series_info(series_id: &str, api_key: &str) -> Result<Option<SeriesInfo>, String> {
+    let params = [
+        ("series_id", series_id.to_string()),
+        ("api_key", api_key.to_string()),
+        ("file_type", "json".to_string()),
+    ];
+    let Some(response) = client().get_json::<FredSeriesResponse>("series", &params).await? else {
+        return Ok(None);
+    };
+    Ok(response.seriess.into_iter().next().map(|mut info| {
+        if let Some((name, unit)) = get_indicator_metadata().get(info.id.as_str()) {
+            info.display_name = Some(name.to_string());
+            info.display_unit = Some(unit.to_string());
+        }
+        info
+    }))
+}
+
+/// Complete observation history of a series, oldest first; cached per series and query
+pub async fn fetch_series_observations(
+    series_id: &str,
+    query: &SeriesQuery,
+    api_key: &str,
+) -> Result<Option<SeriesHistory>, String> {
+    let params = format!(
+        "{}.{}.{}.{}.{}",
+        series_id,
+        query.start.as_deref().unwrap_or(""),
+        query.end.as_deref().unwrap_or(""),
+        query.frequency.as_deref().unwrap_or(""),
+        query.units.as_deref().unwrap_or("lin"),
+    );
+    let (series_id, query, api_key) = (series_id.to_string(), query.clone(), api_key.to_string());
+    let cached = data_cache::shared()
+        .get_or_fetch("fred-series", &params, SERIES_HISTORY_POLICY, move |_| async move {
+            download_series_observations(&series_id, &query, &api_key).await.map(Arc::new)
+        })
+        .await?;
+    Ok(cached.value.as_ref().clone().map(|mut history| {
+        history.fetched_at = Some(cached.fetched_at.to_rfc3339());
+        history.stale = cached.stale;
+        history
+    }))
+}
+
+/// Complete observation history of a series, oldest first, with FRED applying any frequency or units transform
+async fn download_series_observations(
+    series_id: &str,
+    query: &SeriesQuery,
+    api_key: &str,
+) -> Result<Option<SeriesHistory>, String> {
+    let Some(info) = fetch_series_info(series_id, api_key).await? else {
+        return Ok(None);
+    };
+    let units = query.units.clone().unwrap_or_else(|| "lin".to_string());
+    let mut params = vec![
+        ("series_id", info.id.clone()),
+        ("api_key", api_key.to_string()),
+        ("file_type", "json".to_string()),
+        ("sort_order", "asc".to_string()),
+        ("limit", MAX_OBSERVATIONS.to_string()),
+        ("units", units.clone()),
+    ];
+    for (name, value) in [
+        ("observation_start", &query.start),
+        ("observation_end", &query.end),
+        ("frequency", &query.frequency),
+    ] {
+        if let Some(value) = value {
+            params.push((name, value.clone()));
+        }
+    }
+    let Some(response) = client().get_json::<FredResponse>("series/observations", &params).await? else {
+        return Ok(None);
+    };
+
+    let observations: Vec<SeriesObservation> = response
+        .observations
+        .into_iter()
+        .map(|obs| SeriesObservation { value: obs.value.parse().ok(), date: obs.date })
+        .collect();
+    Ok(Some(SeriesHistory {
+        info,
+        units,
+        frequency: query.frequency.clone(),
+        observation_count: observations.len(),
+        observations,
+        fetched_at: None,
+        stale: false,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{extract::Query, http::StatusCode, response::IntoResponse, routing::get, Json, Router};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    type Params = Query<HashMap<String, String>>;
+
+    // Serve a mock FRED on an ephemeral port and return its base URL
+    async fn serve(router: Router) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        format!("http://{}", addr)
+    }
+
+    // Each client gets its own cache directory so last-good readings don't leak between tests
+    fn test_client(base_url: &str) -> FredClient {
+        let dir = std::env::temp_dir().join(format!("fred-test-{}", base_url.rsplit(':').next().unwrap_or("0")));
+        let _ = std::fs::remove_dir_all(&dir);
+        let store = Box::leak(Box::new(data_cache::Store::new(dir)));
+        FredClient::new(base_url, Duration::from_millis(200), 3, Duration::from_millis(5), store)
+    }
+
+    // Two observations, newest first, chosen per series and units
+    fn observations(params: &HashMap<String, String>) -> Json<serde_json::Value> {
+        let (latest, previous) = match (params["series_id"].as_str(), params["units"].as_str()) {
+            (SERIES_CPI, "pc1") => ("3.1", "2.9"),
+            (SERIES_HOUSING_STARTS, _) => ("1350", "1400"),
+            _ => ("6.5", "6.4"),
+        };
+        Json(serde_json::json!({ "observations": [
+            { "date": "2025-01-02", "value": latest },
+            { "date": "2024-12-26", "value": previous },
+        ]}))
+    }
+
+    fn fallback() -> Reading {
+        Reading::fallback(FALLBACK_MORTGAGE_30YR, 0.0)
+    }
+
+    #[tokio::test]
+    async fn live_rates_fetch_concurrently_and_mark_every_field_live() {
+        let router = Router::new().route(
+            "/series/observations",
+            get(|Query(params): Params| async move {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                observations(&params)
+            }),
+        );
+        let client = test_client(&serve(router).await);
+
+        let started = Instant::now();
+        let rates = client.live_rates(Some("key")).await;
+        // Seven sequential calls would take at least 700ms
+        assert!(started.elapsed() < Duration::from_millis(500), "took {:?}", started.elapsed());
+
+        assert_eq!(rates.source, "FRED API (Live)");
+        assert_eq!(rates.updated, "2025-01-02");
+        assert_eq!(rates.cpi_yoy.value, 3.1);
+        assert!((rates.housing_starts.value - 1.35).abs() < 1e-9);
+        assert_eq!(rates.housing_starts.change, -0.05);
+        assert_eq!(rates.mortgage_30yr.change, 0.1);
+        for reading in [&rates.mortgage_30yr, &rates.mortgage_15yr, &rates.fed_funds, &rates.unemployment, &rates.treasury_10yr] {
+            assert_eq!(reading.provenance, Provenance::Live);
+            assert!(reading.error.is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_429_and_5xx_then_succeeds() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let router = Router::new().route(
+            "/series/observations",
+            get(move |Query(params): Params| {
+                let attempt = counter.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    match attempt {
+                        0 => StatusCode::TOO_MANY_REQUESTS.into_response(),
+                        1 => StatusCode::SERVICE_UNAVAILABLE.into_response(),
+                        _ => observations(&params).into_response(),
+                    }
+                }
+            }),
+        );
+        let client = test_client(&serve(router).await);
+
+        let reading = client.reading_or(SERIES_MORTGAGE_30YR, "lin", 1.0, Some("key"), fallback()).await;
+        assert_eq!(reading.provenance, Provenance::Live);
+        assert_eq!(reading.value, 6.5);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_retries_and_surfaces_the_error() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let router = Router::new().route(
+            "/series/observations",
+            get(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async { StatusCode::INTERNAL_SERVER_ERROR }
+            }),
+        );
+        let client = test_client(&serve(router).await);
+
+        let reading = client.reading_or(SERIES_MORTGAGE_30YR, "lin", 1.0, Some("key"), fallback()).await;
+        assert_eq!(reading.provenance, Provenance::Fallback);
+        assert_eq!(reading.value, FALLBACK_MORTGAGE_30YR);
+        let error = reading.error.unwrap();
+        assert!(error.contains("HTTP 500 after 4 attempts"), "{}", error);
+        assert_eq!(hits.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn slow_responses_time_out() {
+        let router = Router::new().route(
+            "/series/observations",
+            get(|Query(params): Params| async move {
+                tokio::time::sleep(Duration::from_secs(2)).await;
+                observations(&params)
+            }),
+        );
+        let client = test_client(&serve(router).await);
+
+        let started = Instant::now();
+        let reading = client.reading_or(SERIES_MORTGAGE_30YR, "lin", 1.0, Some("key"), fallback()).await;
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(reading.provenance, Provenance::Fallback);
+        assert!(reading.error.unwrap().contains("timed out"));
+    }
+
+    #[tokio::test]
+    async fn failed_fetch_serves_the_last_good_reading() {
+        let failing = Arc::new(AtomicBool::new(false));
+        let flag = failing.clone();
+        let router = Router::new().route(
+            "/series/observations",
+            get(move |Query(params): Params| {
+                let fail = flag.load(Ordering::SeqCst);
+                async move {
+                    if fail {
+                        StatusCode::BAD_GATEWAY.into_response()
+                    } else {
+                        observations(&params).into_response()
+                    }
+                }
+            }),
+        );
+        let client = test_client(&serve(router).await);
+
+        let live = client.live_rates(Some("key")).await;
+        assert_eq!(live.source, "FRED API (Live)");
+
+        failing.store(true, Ordering::SeqCst);
+        let rates = client.live_rates(Some("key")).await;
+        assert_eq!(rates.source, "FRED API (Cached)");
+        assert_eq!(rates.mortgage_30yr.provenance, Provenance::Cached);
+        assert_eq!(rates.mortgage_30yr.value, 6.5);
+        assert_eq!(rates.mortgage_30yr.date.as_deref(), Some("2025-01-02"));
+        assert!(rates.mortgage_30yr.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn without_a_key_nothing_is_requested() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let router = Router::new().route(
+            "/series/observations",
+            get(move |Query(params): Params| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async move { observations(&params) }
+            }),
+        );
+        let client = test_client(&serve(router).await);
+
+        let rates = client.live_rates(Some("DEMO_API_KEY")).await;
+        assert_eq!(rates.source, "Fallback Data");
+        assert_eq!(rates.mortgage_30yr.value, FALLBACK_MORTGAGE_30YR);
+        assert!(rates.mortgage_30yr.error.is_none());
+        assert_eq!(hits.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn unknown_series_is_not_retried() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let router = Router::new().route(
+            "/series",
+            get(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async {
+                    let body = serde_json::json!({
+                        "error_code": 400,
+                        "error_message": "Bad Request.  The series does not exist."
+                    });
+                    (StatusCode::BAD_REQUEST, Json(body))
+                }
+            }),
+        );
+        let client = test_client(&serve(router).await);
+
+        let params = [("series_id", "NOPE".to_string()), ("api_key", "key".to_string())];
+        let info = client.get_json::<FredSeriesResponse>("series", &params).await.unwrap();
+        assert!(info.is_none());
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,360 @@
+// Lien Return Calculator - Interest, penalties and yield on a redeemed tax lien
+// Works from the typed statutes so escalating penalties, bid-down rates, minimum
+// returns and non-refundable premiums follow each state's actual rules
+
+use serde::{Deserialize, Serialize};
+
+use crate::statutes::{self, BiddingMethod, RateStep, RedemptionRule, StateStatute, Term};
+
+#[derive(Debug, Deserialize)]
+pub struct LienReturnInput {
+    pub state: String,
+    // Lien amount paid to the county (taxes, interest and costs), excluding any premium
+    pub purchase_price: f64,
+    // Winning rate in bid-down states; defaults to the statutory ceiling
+    #[serde(default)]
+    pub bid_rate_pct: Option<f64>,
+    // Amount bid over the lien in premium states
+    #[serde(default)]
+    pub premium: Option<f64>,
+    // Months after the sale the owner redeems
+    pub redemption_month: u32,
+    // Value of the floating index (CO: federal discount rate); defaults to its reference value
+    #[serde(default)]
+    pub index_rate_pct: Option<f64>,
+    #[serde(default)]
+    pub homestead: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LienReturn {
+    pub state: String,
+    pub sale_type: String,
+    pub redemption_month: u32,
+    // Annual rate interest accrued at in the first step (bid, statutory or indexed)
+    pub rate_used_pct: f64,
+    pub purchase_price: f64,
+    pub premium: f64,
+    pub total_invested: f64,
+    pub interest_earned: f64,
+    pub penalties: f64,
+    // Extra paid to reach a statutory minimum return (FL 5%)
+    pub minimum_return_top_up: f64,
+    pub premium_refunded: bool,
+    pub premium_loss: f64,
+    pub total_returned: f64,
+    pub profit: f64,
+    pub simple_yield_pct: f64,
+    // Simple yield compounded to a 12-month rate
+    pub annualized_yield_pct: f64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    pub statute: &'static StateStatute,
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+// Annual rate a step accrues at, after bid-down and index adjustments
+fn step_rate(statute: &StateStatute, step: &RateStep, bid_rate: Option<f64>, index_rate: f64) -> f64 {
+    match (bid_rate, statute.rate_index) {
+        (Some(bid), _) if step.annual_pct > 0.0 => bid,
+        (_, Some(_)) => step.annual_pct + index_rate,
+        _ => step.annual_pct,
+    }
+}
+
+// Simple interest across every step the lien sat in, plus the penalty of the step it redeemed in
+fn accrued_return(statute: &StateStatute, face: f64, month: u32, bid_rate: Option<f64>, index_rate: f64) -> (f64, f64) {
+    let steps = statute.rate_steps;
+    let mut interest = 0.0;
+    let mut penalty_pct = 0.0;
+    for (i, step) in steps.iter().enumerate() {
+        if month < step.from_month {
+            break;
+        }
+        let end = steps.get(i + 1).map_or(month, |next| next.from_month.min(month));
+        let months_in_step = (end - step.from_month) as f64;
+        interest += face * step_rate(statute, step, bid_rate, index_rate) / 100.0 * months_in_step / 12.0;
+        penalty_pct = step.penalty_pct;
+    }
+    (interest, face * penalty_pct / 100.0)
+}
+
+// Redemption window for the property class, if the statute fixes one after the sale
+pub fn redemption_term(statute: &StateStatute, homestead: bool) -> Option<Term> {
+    match statute.redemption {
+        RedemptionRule::AfterSale { homestead: h, non_homestead: n } => Some(if homestead { h } else { n }),
+        _ => None,
+    }
+}
+
+// Validated purchase terms for one lien under its state's statute
+#[derive(Debug, Clone, Copy)]
+pub struct LienTerms {
+    pub statute: &'static StateStatute,
+    pub face: f64,
+    pub premium: f64,
+    // Winning rate in bid-down states
+    pub bid_rate: Option<f64>,
+    // Value of the statute's floating index, if it has one
+    pub index_rate: f64,
+}
+
+// What the owner pays back on redemption, by component
+#[derive(Debug, Clone, Copy)]
+pub struct Payout {
+    pub interest: f64,
+    pub penalties: f64,
+    pub top_up: f64,
+    pub premium_loss: f64,
+}
+
+impl LienTerms {
+    // Apply the statute's bidding rules; problems are appended to errors / warnings
+    pub fn resolve(
+        statute: &'static StateStatute,
+        face: f64,
+        bid_rate_pct: Option<f64>,
+        premium: Option<f64>,
+        index_rate_pct: Option<f64>,
+        errors: &mut Vec<String>,
+        warnings: &mut Vec<String>,
+    ) -> LienTerms {
+        if statute.rate_steps.is_empty() {
+            errors.push(format!("{} sales earn no statutory return on redemption", statute.state));
+        }
+        if premium.is_some_and(|p| p < 0.0) {
+            errors.push("premium must not be negative".to_string());
+        }
+
+        let bid_rate = match (statute.bidding, bid_rate_pct) {
+            (BiddingMethod::BidDownRate { floor_pct, ceiling_pct }, Some(bid)) => {
+                if bid < floor_pct || bid > ceiling_pct {
+                    errors.push(format!(
+                        "bid_rate_pct must be between {}% and {}% in {}",
+                        floor_pct, ceiling_pct, statute.state
+                    ));
+                }
+                Some(bid)
+            }
+            (BiddingMethod::BidDownRate { ceiling_pct, .. }, None) => Some(ceiling_pct),
+            (_, Some(_)) => {
+                warnings.push(format!("{} does not bid down the rate; bid_rate_pct ignored", statute.state));
+                None
+            }
+            (_, None) => None,
+        };
+        let premium = match statute.bidding {
+            BiddingMethod::Premium { .. } => premium.unwrap_or(0.0),
+            _ => {
+                if premium.is_some_and(|p| p > 0.0) {
+                    warnings.push(format!("{} does not use premium bidding; premium ignored", statute.state));
+                }
+                0.0
+            }
+        };
+        let index_rate = statute.rate_index.map_or(0.0, |index| index_rate_pct.unwrap_or(index.reference_pct()));
+
+        LienTerms { statute, face, premium, bid_rate, index_rate }
+    }
+
+    pub fn invested(&self) -> f64 {
+        self.face + self.premium
+    }
+
+    pub fn premium_refunded(&self) -> bool {
+        !matches!(self.statute.bidding, BiddingMethod::Premium { refunded: false })
+    }
+
+    pub fn payout(&self, month: u32) -> Payout {
+        let statute = self.statute;
+        let (interest, penalties) = accrued_return(statute, self.face, month, self.bid_rate, self.index_rate);
+
+        // FL: the minimum applies unless the certificate was bid all the way down to 0%
+        let top_up = match statute.min_return_pct {
+            Some(min) if self.bid_rate != Some(0.0) => (self.face * min / 100.0 - interest - penalties).max(0.0),
+            _ => 0.0,
+        };
+        let premium_loss = if self.premium_refunded() { 0.0 } else { self.premium };
+        Payout { interest, penalties, top_up, premium_loss }
+    }
+
+    // Everything handed back to the holder on redemption at `month`
+    pub fn total_returned(&self, month: u32) -> f64 {
+        let p = self.payout(month);
+        self.face + p.interest + p.penalties + p.top_up + (self.premium - p.premium_loss)
+    }
+}
+
+pub fn calculate(input: &LienReturnInput) -> Result<LienReturn, Vec<String>> {
+    let statute = statutes::get(input.state.trim())
+        .ok_or_else(|| vec![format!("state '{}' is not a known jurisdiction", input.state)])?;
+
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+    if !input.purchase_price.is_finite() || input.purchase_price <= 0.0 {
+        errors.push("purchase_price must be greater than 0".to_string());
+    }
+    if input.redemption_month == 0 {
+        errors.push("redemption_month must be at least 1".to_string());
+    }
+    let terms = LienTerms::resolve(
+        statute,
+        input.purchase_price,
+        input.bid_rate_pct,
+        input.premium,
+        input.index_rate_pct,
+        &mut errors,
+        &mut warnings,
+    );
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    if let Some(term) = redemption_term(statute, input.homestead) {
+        if input.redemption_month > term.max_months() {
+            warnings.push(format!(
+                "redemption_month {} is past the {} redemption period in {}; the owner could no longer redeem",
+                input.redemption_month,
+                term.label(),
+                statute.state
+            ));
+        }
+    }
+
+    let payout = terms.payout(input.redemption_month);
+    let total_invested = terms.invested();
+    let total_returned = terms.total_returned(input.redemption_month);
+    let profit = total_returned - total_invested;
+    let simple_yield = profit / total_invested;
+    let years = input.redemption_month as f64 / 12.0;
+    let annualized_yield = if simple_yield > -1.0 { (1.0 + simple_yield).powf(1.0 / years) - 1.0 } else { -1.0 };
+
+    let rate_used = statute
+        .rate_steps
+        .first()
+        .map_or(0.0, |step| step_rate(statute, step, terms.bid_rate, terms.index_rate));
+
+    Ok(LienReturn {
+        state: statute.state.to_string(),
+        sale_type: statute.sale_kind.label().to_string(),
+        redemption_month: input.redemption_month,
+        rate_used_pct: round2(rate_used),
+        purchase_price: round2(terms.face),
+        premium: round2(terms.premium),
+        total_invested: round2(total_invested),
+        interest_earned: round2(payout.interest),
+        penalties: round2(payout.penalties),
+        minimum_return_top_up: round2(payout.top_up),
+        premium_refunded: terms.premium_refunded(),
+        premium_loss: round2(payout.premium_loss),
+        total_returned: round2(total_returned),
+        profit: round2(profit),
+        simple_yield_pct: round2(simple_yield * 100.0),
+        annualized_yield_pct: round2(annualized_yield * 100.0),
+        warnings,
+        statute,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calc(value: serde_json::Value) -> Result<LienReturn, Vec<String>> {
+        calculate(&serde_json::from_value(value).expect("valid lien input"))
+    }
+
+    #[test]
+    fn georgia_penalty_steps_up_each_year() {
+        let at = |month: u32| {
+            calc(serde_json::json!({ "state": "GA", "purchase_price": 1000.0, "redemption_month": month })).unwrap()
+        };
+        let first_year = at(6);
+        assert_eq!(first_year.interest_earned, 0.0);
+        assert_eq!(first_year.penalties, 200.0);
+        assert_eq!(first_year.total_returned, 1200.0);
+        assert_eq!(first_year.simple_yield_pct, 20.0);
+        // 20% in half a year compounds to 44% a year
+        assert_eq!(first_year.annualized_yield_pct, 44.0);
+
+        assert_eq!(at(13).penalties, 300.0);
+        assert_eq!(at(30).penalties, 400.0);
+    }
+
+    #[test]
+    fn georgia_premium_is_refunded() {
+        let result = calc(serde_json::json!({
+            "state": "GA", "purchase_price": 1000.0, "premium": 500.0, "redemption_month": 6
+        }))
+        .unwrap();
+        assert!(result.premium_refunded);
+        assert_eq!(result.premium_loss, 0.0);
+        assert_eq!(result.total_invested, 1500.0);
+        assert_eq!(result.total_returned, 1700.0);
+        assert_eq!(result.profit, 200.0);
+    }
+
+    #[test]
+    fn florida_tops_up_to_the_five_percent_minimum() {
+        let result = calc(serde_json::json!({
+            "state": "FL", "purchase_price": 1000.0, "bid_rate_pct": 0.25, "redemption_month": 1
+        }))
+        .unwrap();
+        assert_eq!(result.rate_used_pct, 0.25);
+        assert_eq!(result.interest_earned, 0.21);
+        assert_eq!(result.minimum_return_top_up, 49.79);
+        assert_eq!(result.total_returned, 1050.0);
+    }
+
+    #[test]
+    fn florida_minimum_does_not_apply_above_it_or_at_zero_percent() {
+        let full_rate = calc(serde_json::json!({
+            "state": "FL", "purchase_price": 1000.0, "bid_rate_pct": 18.0, "redemption_month": 12
+        }))
+        .unwrap();
+        assert_eq!(full_rate.interest_earned, 180.0);
+        assert_eq!(full_rate.minimum_return_top_up, 0.0);
+
+        let zero = calc(serde_json::json!({
+            "state": "FL", "purchase_price": 1000.0, "bid_rate_pct": 0.0, "redemption_month": 3
+        }))
+        .unwrap();
+        assert_eq!(zero.minimum_return_top_up, 0.0);
+        assert_eq!(zero.total_returned, 1000.0);
+    }
+
+    #[test]
+    fn florida_rejects_bids_above_the_ceiling() {
+        let errors = calc(serde_json::json!({
+            "state": "FL", "purchase_price": 1000.0, "bid_rate_pct": 20.0, "redemption_month": 6
+        }))
+        .unwrap_err();
+        assert_eq!(errors, vec!["bid_rate_pct must be between 0% and 18% in FL".to_string()]);
+    }
+
+    #[test]
+    fn colorado_premium_is_lost_and_rate_floats_on_the_index() {
+        let result = calc(serde_json::json!({
+            "state": "CO", "purchase_price": 1000.0, "premium": 200.0, "redemption_month": 12
+        }))
+        .unwrap();
+        // 9 points over the 4% reference discount rate
+        assert_eq!(result.rate_used_pct, 13.0);
+        assert_eq!(result.interest_earned, 130.0);
+        assert!(!result.premium_refunded);
+        assert_eq!(result.premium_loss, 200.0);
+        assert_eq!(result.total_invested, 1200.0);
+        assert_eq!(result.total_returned, 1130.0);
+        assert_eq!(result.profit, -70.0);
+
+        let indexed = calc(serde_json::json!({
+            "state": "CO", "purchase_price": 1000.0, "index_rate_pct": 5.0, "redemption_month": 12
+        }))
+        .unwrap();
+        assert_eq!(indexed.rate_used_pct, 14.0);
+        assert_eq!(indexed.interest_earned, 140.0);
+    }
+}
@@ -0,0 +1,592 @@
+// Lien Portfolio Simulator - Monte Carlo over redemption timing
+// Each trial draws, per lien, whether and when the owner redeems; liens that never
+// redeem go to deed once the redemption window and foreclosure have run
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::auctions::StateAuctionSchedule;
+use crate::deed_calc;
+use crate::lien_calc::{self, LienTerms};
+
+pub const DEFAULT_TRIALS: u32 = 1000;
+pub const MAX_TRIALS: u32 = 20_000;
+pub const MAX_LIENS: usize = 500;
+// Upper bound on trials x liens per request
+const MAX_DRAWS: u64 = 2_000_000;
+// Most liens redeem; the rest go to deed
+const DEFAULT_REDEMPTION_PROBABILITY: f64 = 0.95;
+// Months from the end of the redemption window until the deed is in hand
+const DEFAULT_FORECLOSURE_MONTHS: u32 = 6;
+// Upper bound on redemption.*.max_month and foreclosure_months (50 years)
+pub const MAX_TIMING_MONTHS: u32 = 600;
+// Upper bound on the simulated horizon; every trial allocates one slot per month
+pub const MAX_HORIZON_MONTHS: u32 = 720;
+// Upper bound on trials x simulated months; each trial walks every month for its IRR
+const MAX_MONTH_DRAWS: u64 = 5_000_000;
+// Locked-capital percentiles come from the first trials only; the mean uses every trial
+const LOCKED_SAMPLE_TRIALS: usize = 1000;
+
+#[derive(Debug)]
+pub enum SimulationError {
+    // Per-field problems with the request
+    Invalid(Vec<String>),
+    // Some lien's deed month lies past MAX_HORIZON_MONTHS, or trials x horizon is too large
+    HorizonTooLong(String),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SimulationLien {
+    pub state: String,
+    #[serde(default)]
+    pub county: Option<String>,
+    pub face_amount: f64,
+    #[serde(default)]
+    pub bid_rate_pct: Option<f64>,
+    #[serde(default)]
+    pub premium: Option<f64>,
+    // Recovered if the lien goes to deed; defaults to the face amount
+    #[serde(default)]
+    pub property_value: Option<f64>,
+    #[serde(default)]
+    pub homestead: bool,
+}
+
+// Triangular distribution of the redemption month, given the lien redeems at all
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct RedemptionTiming {
+    pub redemption_probability: f64,
+    pub min_month: u32,
+    pub mode_month: u32,
+    pub max_month: u32,
+}
+
+// Per-state override; unset fields keep the schedule-derived default
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct RedemptionTimingInput {
+    pub redemption_probability: Option<f64>,
+    pub min_month: Option<u32>,
+    pub mode_month: Option<u32>,
+    pub max_month: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SimulationInput {
+    pub liens: Vec<SimulationLien>,
+    #[serde(default)]
+    pub trials: Option<u32>,
+    // Fixed seed for reproducible runs
+    #[serde(default)]
+    pub seed: Option<u64>,
+    // Keyed by state abbreviation
+    #[serde(default)]
+    pub redemption: HashMap<String, RedemptionTimingInput>,
+    #[serde(default)]
+    pub foreclosure_months: Option<u32>,
+    // Value of a floating index (CO: federal discount rate)
+    #[serde(default)]
+    pub index_rate_pct: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Distribution {
+    pub mean: f64,
+    pub p5: f64,
+    pub p25: f64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p95: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LockedCapital {
+    pub month: u32,
+    pub mean: f64,
+    pub p5: f64,
+    pub p95: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LienOutcome {
+    pub index: usize,
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub county: Option<String>,
+    pub invested: f64,
+    pub timing: RedemptionTiming,
+    pub deed_month: u32,
+    pub deed_probability: f64,
+    // Mean redemption month over the trials where it redeemed
+    pub mean_redemption_month: Option<f64>,
+    pub mean_profit: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SimulationResult {
+    pub trials: u32,
+    pub seed: u64,
+    pub total_invested: f64,
+    // Annualized portfolio IRR (%) across trials
+    pub irr_pct: Option<Distribution>,
+    pub probability_of_loss: f64,
+    pub profit: Distribution,
+    pub probability_any_deed: f64,
+    pub expected_deeds: f64,
+    pub capital_locked: Vec<LockedCapital>,
+    pub liens: Vec<LienOutcome>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+fn round4(value: f64) -> f64 {
+    (value * 10_000.0).round() / 10_000.0
+}
+
+// Default timing from the state's schedule: redemption can happen any month of the
+// statutory window, most often in its first quarter
+fn default_timing(schedule: &StateAuctionSchedule, homestead: bool) -> Option<RedemptionTiming> {
+    let term = lien_calc::redemption_term(schedule.statute?, homestead)?;
+    let max_month = term.max_months().max(1);
+    Some(RedemptionTiming {
+        redemption_probability: DEFAULT_REDEMPTION_PROBABILITY,
+        min_month: 1,
+        mode_month: (max_month / 4).max(1),
+        max_month,
+    })
+}
+
+fn apply_override(mut timing: RedemptionTiming, o: &RedemptionTimingInput) -> RedemptionTiming {
+    if let Some(p) = o.redemption_probability {
+        timing.redemption_probability = p;
+    }
+    if let Some(max) = o.max_month {
+        timing.max_month = max;
+        timing.mode_month = timing.mode_month.min(max);
+    }
+    if let Some(min) = o.min_month {
+        timing.min_month = min;
+        timing.mode_month = timing.mode_month.max(min);
+    }
+    if let Some(mode) = o.mode_month {
+        timing.mode_month = mode;
+    }
+    timing
+}
+
+fn validate_timing(state: &str, t: &RedemptionTiming) -> Vec<String> {
+    let mut errors = Vec::new();
+    if !(0.0..=1.0).contains(&t.redemption_probability) {
+        errors.push(format!("redemption.{}.redemption_probability must be between 0 and 1", state));
+    }
+    if t.max_month > MAX_TIMING_MONTHS {
+        errors.push(format!("redemption.{}.max_month must not exceed {}", state, MAX_TIMING_MONTHS));
+    }
+    if t.min_month == 0 || !(t.min_month <= t.mode_month && t.mode_month <= t.max_month) {
+        errors.push(format!(
+            "redemption.{} must satisfy 1 <= min_month <= mode_month <= max_month",
+            state
+        ));
+    }
+    errors
+}
+
+// Inverse-CDF draw from the triangular distribution, rounded up to a whole month
+fn sample_month(t: &RedemptionTiming, u: f64) -> u32 {
+    let a = t.min_month as f64 - 1.0;
+    let b = t.max_month as f64;
+    let c = (t.mode_month as f64 - 0.5).clamp(a, b);
+    if b <= a {
+        return t.max_month;
+    }
+    let x = if u < (c - a) / (b - a) {
+        a + (u * (b - a) * (c - a)).sqrt()
+    } else {
+        b - ((1.0 - u) * (b - a) * (b - c)).sqrt()
+    };
+    (x.ceil() as u32).clamp(t.min_month, t.max_month)
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (p / 100.0 * (sorted.len() - 1) as f64).round() as usize;
+    sorted[rank.min(sorted.len() - 1)]
+}
+
+fn distribution(mut values: Vec<f64>) -> Distribution {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let mean = if values.is_empty() { 0.0 } else { values.iter().sum::<f64>() / values.len() as f64 };
+    Distribution {
+        mean: round2(mean),
+        p5: round2(percentile(&values, 5.0)),
+        p25: round2(percentile(&values, 25.0)),
+        p50: round2(percentile(&values, 50.0)),
+        p75: round2(percentile(&values, 75.0)),
+        p95: round2(percentile(&values, 95.0)),
+    }
+}
+
+struct PreparedLien {
+    terms: LienTerms,
+    timing: RedemptionTiming,
+    deed_month: u32,
+    deed_value: f64,
+}
+
+// Resolve statute terms and timing for every lien, collecting all validation errors
+fn prepare(
+    input: &SimulationInput,
+    schedules: &HashMap<String, StateAuctionSchedule>,
+    warnings: &mut Vec<String>,
+) -> Result<Vec<PreparedLien>, Vec<String>> {
+    let mut errors = Vec::new();
+    if input.liens.is_empty() {
+        errors.push("liens must contain at least one lien".to_string());
+    }
+    if input.liens.len() > MAX_LIENS {
+        errors.push(format!("at most {} liens per simulation", MAX_LIENS));
+    }
+    for state in input.redemption.keys() {
+        if !input.liens.iter().any(|l| l.state.trim().eq_ignore_ascii_case(state)) {
+            warnings.push(format!("redemption override for {} matches no lien", state));
+        }
+    }
+    let foreclosure_months = input.foreclosure_months.unwrap_or(DEFAULT_FORECLOSURE_MONTHS);
+    if foreclosure_months > MAX_TIMING_MONTHS {
+        errors.push(format!("foreclosure_months must not exceed {}", MAX_TIMING_MONTHS));
+    }
+
+    let mut prepared = Vec::with_capacity(input.liens.len());
+    for (i, lien) in input.liens.iter().enumerate() {
+        let state = lien.state.trim().to_uppercase();
+        let Some(schedule) = schedules.get(&state) else {
+            errors.push(format!("liens[{}]: state '{}' is not a known jurisdiction", i, lien.state));
+            continue;
+        };
+        let Some(statute) = schedule.statute else {
+            errors.push(format!("liens[{}]: no statute on record for {}", i, state));
+            continue;
+        };
+        if !lien.face_amount.is_finite() || lien.face_amount <= 0.0 {
+            errors.push(format!("liens[{}]: face_amount must be greater than 0", i));
+        }
+        if lien.property_value.is_some_and(|v| !v.is_finite() || v < 0.0) {
+            errors.push(format!("liens[{}]: property_value must not be negative", i));
+        }
+
+        let mut lien_errors = Vec::new();
+        let mut lien_warnings = Vec::new();
+        let terms = LienTerms::resolve(
+            statute,
+            lien.face_amount,
+            lien.bid_rate_pct,
+            lien.premium,
+            input.index_rate_pct,
+            &mut lien_errors,
+            &mut lien_warnings,
+        );
+        errors.extend(lien_errors.into_iter().map(|e| format!("liens[{}]: {}", i, e)));
+        warnings.extend(lien_warnings.into_iter().map(|w| format!("liens[{}]: {}", i, w)));
+
+        let override_input = input.redemption.iter().find(|(k, _)| k.eq_ignore_ascii_case(&state)).map(|(_, v)| v);
+        let timing = match (default_timing(schedule, lien.homestead), override_input) {
+            (Some(timing), Some(o)) => apply_override(timing, o),
+            (Some(timing), None) => timing,
+            (None, Some(o)) if o.max_month.is_some() => apply_override(
+                RedemptionTiming {
+                    redemption_probability: DEFAULT_REDEMPTION_PROBABILITY,
+                    min_month: 1,
+                    mode_month: 1,
+                    max_month: 1,
+                },
+                o,
+            ),
+            (None, _) => {
+                errors.push(format!(
+                    "liens[{}]: {} has no fixed post-sale redemption period ({}); supply redemption.{}.max_month",
+                    i, state, schedule.redemption_period, state
+                ));
+                continue;
+            }
+        };
+        errors.extend(validate_timing(&state, &timing).into_iter().map(|e| format!("liens[{}]: {}", i, e)));
+
+        let Some(deed_month) = timing.max_month.checked_add(foreclosure_months) else {
+            errors.push(format!("liens[{}]: max_month plus foreclosure_months overflows", i));
+            continue;
+        };
+        prepared.push(PreparedLien {
+            terms,
+            timing,
+            deed_month,
+            deed_value: lien.property_value.unwrap_or(lien.face_amount),
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(prepared)
+    } else {
+        errors.dedup();
+        Err(errors)
+    }
+}
+
+pub fn simulate(
+    input: &SimulationInput,
+    schedules: &HashMap<String, StateAuctionSchedule>,
+) -> Result<SimulationResult, SimulationError> {
+    let trials = input.trials.unwrap_or(DEFAULT_TRIALS);
+    let mut warnings = Vec::new();
+    let mut errors = Vec::new();
+    if trials == 0 || trials > MAX_TRIALS {
+        errors.push(format!("trials must be between 1 and {}", MAX_TRIALS));
+    }
+    if trials as u64 * input.liens.len() as u64 > MAX_DRAWS {
+        errors.push(format!("trials x liens must not exceed {}", MAX_DRAWS));
+    }
+    let liens = match prepare(input, schedules, &mut warnings) {
+        Ok(liens) if errors.is_empty() => liens,
+        Ok(_) => return Err(SimulationError::Invalid(errors)),
+        Err(lien_errors) => {
+            errors.extend(lien_errors);
+            return Err(SimulationError::Invalid(errors));
+        }
+    };
+    if let Some((i, lien)) = liens.iter().enumerate().find(|(_, l)| l.deed_month > MAX_HORIZON_MONTHS) {
+        return Err(SimulationError::HorizonTooLong(format!(
+            "liens[{}] reaches deed in month {}; simulations are limited to {} months",
+            i, lien.deed_month, MAX_HORIZON_MONTHS
+        )));
+    }
+
+    let seed = input.seed.unwrap_or_else(rand::random);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let total_invested: f64 = liens.iter().map(|l| l.terms.invested()).sum();
+    let horizon = liens.iter().map(|l| l.deed_month).max().unwrap_or(0) as usize;
+    if trials as u64 * (horizon as u64 + 1) > MAX_MONTH_DRAWS {
+        return Err(SimulationError::HorizonTooLong(format!(
+            "{} trials over {} months exceeds {} trial-months; lower trials or shorten the horizon",
+            trials,
+            horizon + 1,
+            MAX_MONTH_DRAWS
+        )));
+    }
+
+    let mut irrs = Vec::with_capacity(trials as usize);
+    let mut profits = Vec::with_capacity(trials as usize);
+    let sample_size = (trials as usize).min(LOCKED_SAMPLE_TRIALS);
+    let mut locked_sum = vec![0.0; horizon + 1];
+    let mut locked_sample: Vec<Vec<f64>> = vec![Vec::with_capacity(sample_size); horizon + 1];
+    let mut trials_with_deed = 0u32;
+    let mut deeds = vec![0u32; liens.len()];
+    let mut redemption_months = vec![0u64; liens.len()];
+    let mut lien_profit = vec![0.0; liens.len()];
+
+    for trial in 0..trials as usize {
+        let mut flows = vec![0.0; horizon + 1];
+        // Capital released at each month (exits), to derive what is still locked
+        let mut released = vec![0.0; horizon + 1];
+        flows[0] = -total_invested;
+        let mut any_deed = false;
+
+        for (i, lien) in liens.iter().enumerate() {
+            let (month, returned) = if rng.gen::<f64>() < lien.timing.redemption_probability {
+                let month = sample_month(&lien.timing, rng.gen());
+                redemption_months[i] += month as u64;
+                (month, lien.terms.total_returned(month))
+            } else {
+                deeds[i] += 1;
+                any_deed = true;
+                (lien.deed_month, lien.deed_value)
+            };
+            flows[month as usize] += returned;
+            released[month as usize] += lien.terms.invested();
+            lien_profit[i] += returned - lien.terms.invested();
+        }
+        if any_deed {
+            trials_with_deed += 1;
+        }
+
+        let mut locked = total_invested;
+        for (month, out) in released.iter().enumerate() {
+            locked -= out;
+            locked_sum[month] += locked.max(0.0);
+            if trial < sample_size {
+                locked_sample[month].push(locked.max(0.0));
+            }
+        }
+        profits.push(flows.iter().sum::<f64>());
+        if let Some(r) = deed_calc::irr(&flows) {
+            irrs.push(((1.0 + r).powi(12) - 1.0) * 100.0);
+        }
+    }
+
+    let n = trials as f64;
+    if irrs.len() < trials as usize {
+        warnings.push(format!("IRR undefined in {} trials", trials as usize - irrs.len()));
+    }
+    let probability_of_loss = profits.iter().filter(|p| **p < 0.0).count() as f64 / n;
+
+    let capital_locked = locked_sample
+        .into_iter()
+        .zip(locked_sum)
+        .enumerate()
+        .map(|(month, (values, sum))| {
+            let d = distribution(values);
+            LockedCapital { month: month as u32, mean: round2(sum / n), p5: d.p5, p95: d.p95 }
+        })
+        .collect();
+
+    let outcomes = liens
+        .iter()
+        .enumerate()
+        .map(|(i, lien)| {
+            let redeemed = trials - deeds[i];
+            LienOutcome {
+                index: i,
+                state: lien.terms.statute.state.to_string(),
+                county: input.liens[i].county.clone(),
+                invested: round2(lien.terms.invested()),
+                timing: lien.timing,
+                deed_month: lien.deed_month,
+                deed_probability: round4(deeds[i] as f64 / n),
+                mean_redemption_month: (redeemed > 0).then(|| round2(redemption_months[i] as f64 / redeemed as f64)),
+                mean_profit: round2(lien_profit[i] / n),
+            }
+        })
+        .collect();
+
+    Ok(SimulationResult {
+        trials,
+        seed,
+        total_invested: round2(total_invested),
+        irr_pct: (!irrs.is_empty()).then(|| distribution(irrs)),
+        probability_of_loss: round4(probability_of_loss),
+        profit: distribution(profits),
+        probability_any_deed: round4(trials_with_deed as f64 / n),
+        expected_deeds: round2(deeds.iter().sum::<u32>() as f64 / n),
+        capital_locked,
+        liens: outcomes,
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auctions;
+
+    fn schedules() -> HashMap<String, StateAuctionSchedule> {
+        auctions::seed_schedules().into_iter().map(|s| (s.state.clone(), s)).collect()
+    }
+
+    fn input(value: serde_json::Value) -> SimulationInput {
+        serde_json::from_value(value).expect("valid simulation input")
+    }
+
+    fn invalid(result: Result<SimulationResult, SimulationError>) -> Vec<String> {
+        match result {
+            Err(SimulationError::Invalid(errors)) => errors,
+            other => panic!("expected validation errors, got {:?}", other.map(|r| r.trials)),
+        }
+    }
+
+    #[test]
+    fn seeded_run_is_reproducible() {
+        let body = serde_json::json!({
+            "liens": [
+                { "state": "FL", "face_amount": 1000.0, "bid_rate_pct": 12.0 },
+                { "state": "FL", "face_amount": 2500.0, "bid_rate_pct": 8.0, "property_value": 90000.0 }
+            ],
+            "trials": 500,
+            "seed": 42
+        });
+        let first = simulate(&input(body.clone()), &schedules()).expect("simulates");
+        let second = simulate(&input(body), &schedules()).expect("simulates");
+
+        assert_eq!(first.seed, 42);
+        assert_eq!(first.trials, 500);
+        assert_eq!(first.total_invested, 3500.0);
+        assert_eq!(
+            serde_json::to_string(&first).unwrap(),
+            serde_json::to_string(&second).unwrap()
+        );
+        // FL: 24-month redemption window plus the default 6 months of foreclosure
+        assert_eq!(first.liens[0].deed_month, 30);
+        assert_eq!(first.capital_locked.len(), 31);
+    }
+
+    #[test]
+    fn rejects_oversized_max_month() {
+        let body = serde_json::json!({
+            "liens": [{ "state": "FL", "face_amount": 1000.0, "bid_rate_pct": 12.0 }],
+            "redemption": { "FL": { "max_month": 4_000_000_000u32 } },
+            "seed": 1
+        });
+        let errors = invalid(simulate(&input(body), &schedules()));
+        assert!(errors.iter().any(|e| e.contains("max_month must not exceed 600")), "{:?}", errors);
+    }
+
+    #[test]
+    fn rejects_oversized_foreclosure_months() {
+        let body = serde_json::json!({
+            "liens": [{ "state": "FL", "face_amount": 1000.0, "bid_rate_pct": 12.0 }],
+            "foreclosure_months": u32::MAX,
+            "seed": 1
+        });
+        let errors = invalid(simulate(&input(body), &schedules()));
+        assert!(errors.iter().any(|e| e.contains("foreclosure_months must not exceed 600")), "{:?}", errors);
+    }
+
+    #[test]
+    fn rejects_horizon_past_the_cap() {
+        let body = serde_json::json!({
+            "liens": [{ "state": "FL", "face_amount": 1000.0, "bid_rate_pct": 12.0 }],
+            "redemption": { "FL": { "max_month": 600 } },
+            "foreclosure_months": 200,
+            "seed": 1
+        });
+        match simulate(&input(body), &schedules()) {
+            Err(SimulationError::HorizonTooLong(msg)) => assert!(msg.contains("month 800"), "{}", msg),
+            other => panic!("expected HorizonTooLong, got {:?}", other.map(|r| r.trials)),
+        }
+    }
+
+    #[test]
+    fn rejects_too_many_trial_months() {
+        // Deed month 720 is within the horizon cap, but 20,000 trials x 721 months is not
+        let body = serde_json::json!({
+            "liens": [{ "state": "FL", "face_amount": 1000.0, "bid_rate_pct": 12.0 }],
+            "redemption": { "FL": { "max_month": 120 } },
+            "foreclosure_months": 600,
+            "trials": MAX_TRIALS,
+            "seed": 1
+        });
+        match simulate(&input(body), &schedules()) {
+            Err(SimulationError::HorizonTooLong(msg)) => assert!(msg.contains("721 months"), "{}", msg),
+            other => panic!("expected HorizonTooLong, got {:?}", other.map(|r| r.trials)),
+        }
+    }
+
+    #[test]
+    fn locked_capital_mean_covers_every_trial() {
+        let body = serde_json::json!({
+            "liens": [{ "state": "FL", "face_amount": 1000.0, "bid_rate_pct": 12.0 }],
+            "trials": 3000,
+            "seed": 7
+        });
+        let result = simulate(&input(body), &schedules()).expect("simulates");
+        let start = &result.capital_locked[0];
+        assert_eq!((start.mean, start.p5, start.p95), (1000.0, 1000.0, 1000.0));
+        // Every lien has exited by the deed month
+        let end = result.capital_locked.last().unwrap();
+        assert_eq!((end.mean, end.p5, end.p95), (0.0, 0.0, 0.0));
+    }
+}
@@ -1,18 +1,49 @@
 use axum::{
-    extract::{Path, Query, State},
-    http::{header, Method},
+    extract::{Path, Query, Request, State},
+    http::{header, HeaderMap, Method, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
     routing::{get, post},
     Json, Router,
 };
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use tower_http::cors::{AllowOrigin, CorsLayer};
 
 mod census;
+mod data_cache;
 mod foreclosure;
 mod auctions;
+mod auction_calendar;
+mod auction_import;
+mod auction_store;
+mod backtest;
+mod batch_scoring;
+mod deed_calc;
 mod fred_api;
+mod lien_calc;
+mod lien_simulator;
+mod market_data;
+mod payload_format;
+mod redfin;
+mod scheduler;
+mod scoring;
+mod statutes;
+mod zhvi_history;
+
+use auction_store::AuctionRepository;
+use redfin::RedfRecord;
+
+// ============================================================================
+// APP STATE
+// ============================================================================
+
+#[derive(Clone)]
+struct AppState {
+    auctions: Arc<dyn AuctionRepository>,
+}
 
 // ============================================================================
 // DATA STRUCTURES
@@ -27,6 +58,7 @@ pub struct StateAuctionInfo {
     pub interest_rate: String,
     pub redemption_period: String,
     pub notes: String,
+    pub statute: &'static statutes::StateStatute,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -34,6 +66,11 @@ pub struct CountyData {
     pub name: String,
     pub state: String,
     pub tier: u8,
+    pub score: f32,
+    pub model_version: String,
+    pub profile: String,
+    // Profile factors with no value here; the score is rescaled over the rest
+    pub factors_missing: Vec<String>,
     pub pop: u32,
     pub income: u32,
     pub zhvi: u32,
@@ -50,6 +87,8 @@ pub struct AnalysisInput {
     pub days_on_market: u16,
     pub transaction_volume: u32,
     pub employment_rate: f32,
+    #[serde(default)]
+    pub median_home_value: Option<u32>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -59,6 +98,36 @@ pub struct AnalysisOutput {
     pub name: String,
     pub action: String,
     pub recommendation: String,
+    pub model_version: String,
+    pub profile: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub factors_missing: Vec<String>,
+    pub breakdown: scoring::Breakdown,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ErrorResponse {
+    pub error: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<String>,
+}
+
+type ApiError = (StatusCode, Json<ErrorResponse>);
+
+fn api_error(status: StatusCode, error: impl Into<String>) -> ApiError {
+    (status, Json(ErrorResponse { error: error.into(), details: vec![] }))
+}
+
+fn internal_error(e: String) -> ApiError {
+    eprintln!("Internal error: {}", e);
+    api_error(StatusCode::INTERNAL_SERVER_ERROR, e)
+}
+
+fn validation_error(details: Vec<String>) -> ApiError {
+    (
+        StatusCode::UNPROCESSABLE_ENTITY,
+        Json(ErrorResponse { error: "Validation failed".to_string(), details }),
+    )
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -86,21 +155,12 @@ pub struct ZhviResponse {
     pub data: Vec<ZhviRecord>,
 }
 
-#[derive(Debug, Serialize, Clone)]
-pub struct RedfRecord {
-    pub region: String,
-    pub region_type: String,
-    pub median_dom: f32,
-    pub sale_to_list: f32,
-    pub inventory: u32,
-    pub price_drop_pct: f32,
-    pub homes_sold: u32,
-}
-
 #[derive(Debug, Serialize, Clone)]
 pub struct RedfResponse {
     pub updated: String,
     pub source: String,
+    // Redfin was unreachable and the last downloaded tracker was used
+    pub stale: bool,
     pub record_count: usize,
     pub data: Vec<RedfRecord>,
 }
@@ -141,78 +201,23 @@ static STATE_NAMES: Lazy<HashMap<&str, &str>> = Lazy::new(|| {
     m
 });
 
+// Rendered from the typed statutes so the text always matches the rules
 static STATE_AUCTION_DATA: Lazy<HashMap<String, StateAuctionInfo>> = Lazy::new(|| {
-    let mut m = HashMap::new();
-    
-    macro_rules! add_state {
-        ($abbr:expr, $type:expr, $rate:expr, $redemption:expr, $notes:expr) => {
-            m.insert($abbr.to_string(), StateAuctionInfo {
-                abbr: $abbr.to_string(),
-                name: STATE_NAMES.get($abbr).unwrap_or(&$abbr).to_string(),
-                sale_type: $type.to_string(),
-                interest_rate: $rate.to_string(),
-                redemption_period: $redemption.to_string(),
-                notes: $notes.to_string(),
-            });
-        };
-    }
-    
-    // === LIEN STATES (26) ===
-    add_state!("AL", "Lien", "12%", "3 years", "Most sales May-June; 12% interest from date of sale");
-    add_state!("AZ", "Lien", "16%", "3 years", "Bid-down process; max 16% simple interest");
-    add_state!("CO", "Lien", "Fed rate + 9pts", "3 years", "Premium bidding; no premium reimbursement");
-    add_state!("CT", "Lien", "18%", "6 months", "Combined lien/deed format; larger towns only");
-    add_state!("DC", "Lien", "18%", "6 months - 1 year", "Premium bidding; no interest on overbid");
-    add_state!("FL", "Lien", "18%", "2 years", "Bid-down; guaranteed 5% minimum return");
-    add_state!("GA", "Lien", "20-40%", "1 year", "20% year 1; escalates to 30% after 2yrs, 40% after 3yrs");
-    add_state!("IL", "Lien", "18%", "2 years", "Bid-down from 18%; graduated penalty redemption");
-    add_state!("IN", "Lien", "Graduated", "1 year", "A/B/C Sales process; Commissioner's Sale for county-titled");
-    add_state!("IA", "Lien", "24%", "1 year 9 months", "Bid least undivided ownership interest; highest rate");
-    add_state!("KY", "Lien", "12%", "1 year", "12% interest from date of issuance");
-    add_state!("LA", "Lien", "Bid-down", "3 years", "2024-2025 reform: bid-down interest system; online now available");
-    add_state!("MD", "Lien", "18-24%", "6 months", "Statutory 6% but most counties charge 18-24%");
-    add_state!("MA", "Lien", "16%", "Collector's deed", "Smallest undivided part auction; 16% rate");
-    add_state!("MS", "Lien", "18%", "2 years", "Tax lien state");
-    add_state!("MO", "Lien", "10%", "2 years", "10% interest; 18% penalty each year delinquent");
-    add_state!("MT", "Lien", "10%", "2-3 years", "5/6 of 1% per month (10% per annum)");
-    add_state!("NE", "Lien", "14%", "3 years", "Undivided interest at 14% per annum");
-    add_state!("NH", "Lien", "18%", "2 years", "Auction for percentage of undivided interest");
-    add_state!("NJ", "Lien", "18%", "2 years", "Bid-down from 18%; active market");
-    add_state!("OK", "Lien", "8%", "2 years", "Multiple bidders decided by random drawing");
-    add_state!("RI", "Lien", "10% + 1%/mo", "1 year", "Collector's Deed; 10% first 6mo, 1%/mo after");
-    add_state!("SC", "Lien", "8% penalty", "1 year", "Highest and best bidder wins");
-    add_state!("SD", "Lien", "12% (max 10% bid)", "3-4 years", "Bid-down from 10%; 12% statutory");
-    add_state!("WV", "Lien", "12%", "1 year", "Highest bidder at public auction");
-    add_state!("WY", "Lien", "15% + 3% penalty", "4 years", "Longest redemption; 15% + 3% penalty + fees");
-    
-    // === DEED STATES (25) ===
-    add_state!("AK", "Deed", "N/A", "1 year", "Municipal foreclosure; deeded to borough/city if unredeemed");
-    add_state!("AR", "Deed", "N/A", "30 days", "Forfeited to state; limited warranty deed after 30 days");
-    add_state!("CA", "Deed", "N/A", "5 years (3 for some)", "Tax Collector's Deed; free of pre-existing encumbrances");
-    add_state!("DE", "Deed", "15%", "60 days", "Judicial foreclosure; 15% penalty on redemption");
-    add_state!("HI", "Deed", "N/A", "1 year", "3-year lien before auction; 1-year redemption after sale");
-    add_state!("ID", "Deed", "N/A", "3 years before deed", "Tax deed to county after 3 years; then sold at auction");
-    add_state!("KS", "Deed", "N/A", "Court judgment", "Bid off to county; court petition for foreclosure");
-    add_state!("ME", "Deed", "N/A", "18 months", "Tax lien mortgage auto-forecloses after 18 months");
-    add_state!("MI", "Deed", "N/A", "None after sale", "Forfeit lands; auction 3rd Tuesday July; min bid = taxes + FMV");
-    add_state!("MN", "Deed", "N/A", "None after sale", "Tax-forfeited land auctions; cash or installment");
-    add_state!("NV", "Deed", "N/A", "2 years before deed", "Tax deed to Treasurer after 2yr; then auction");
-    add_state!("NM", "Deed", "N/A", "120 days IRS only", "No owner redemption; Quitclaim Deed issued");
-    add_state!("NY", "Deed", "N/A", "2-4 years", "2yr standard; 3-4yr for residential/farm; judicial foreclosure");
-    add_state!("NC", "Deed", "N/A", "Upset bid period", "Judicial foreclosure or docketing certificate");
-    add_state!("ND", "Deed", "Max 9%", "4 years", "Bid-down from 9%; 4yr redemption from due date");
-    add_state!("OH", "Deed", "N/A", "None after sale", "Judicial foreclosure after 2yr delinquent; Sheriff's sale");
-    add_state!("OR", "Deed", "N/A", "2 years before deed", "Foreclosure after 3yr; sold to county; 2yr redemption");
-    add_state!("PA", "Deed", "N/A", "None after sale", "Upset Sale; min bid = taxes + interest + costs");
-    add_state!("TN", "Deed", "N/A", "1 year", "2yr delinquent before Chancery Court suit");
-    add_state!("TX", "Deed", "25% penalty", "6mo-2yr", "6mo non-Homestead; 2yr Homestead/Ag; 25% penalty");
-    add_state!("UT", "Deed", "N/A", "4 years", "Preliminary sale Jan 16; final sale May 4yr later");
-    add_state!("VT", "Deed", "N/A", "1 year", "Foreclosure after 2yr; Collector's Deed after 1yr redemption");
-    add_state!("VA", "Deed", "N/A", "Surplus rights only", "Judicial foreclosure; 3yr after due date; surplus to former owner");
-    add_state!("WA", "Deed", "N/A", "3 years before sale", "Certificate of delinquency after 3yr; foreclosure judgment");
-    add_state!("WI", "Deed", "N/A", "2 years", "Tax deed after 2yr certificate; county cannot sell certificate");
-    
-    m
+    statutes::all()
+        .iter()
+        .map(|s| {
+            let info = StateAuctionInfo {
+                abbr: s.state.to_string(),
+                name: STATE_NAMES.get(s.state).unwrap_or(&s.state).to_string(),
+                sale_type: s.sale_kind.short_label().to_string(),
+                interest_rate: s.rate_label(),
+                redemption_period: s.redemption.label(),
+                notes: s.notes.to_string(),
+                statute: s,
+            };
+            (s.state.to_string(), info)
+        })
+        .collect()
 });
 
 // ============================================================================
@@ -225,13 +230,18 @@ static COUNTY_DATABASE: Lazy<CountyDb> = Lazy::new(|| {
     let mut db: CountyDb = HashMap::new();
     
     macro_rules! add_county {
-        ($state:expr, $name:expr, $pop:expr, $income:expr, $zhvi:expr, $growth:expr, $dom:expr, $tier:expr, $notes:expr) => {
+        ($state:expr, $name:expr, $pop:expr, $income:expr, $zhvi:expr, $growth:expr, $dom:expr, $notes:expr) => {
+            let graded = scoring::score(&county_factors($pop, $income, $zhvi, $growth, $dom));
             db.entry($state.to_string())
                 .or_insert_with(Vec::new)
                 .push(CountyData {
                     name: $name.to_string(),
                     state: $state.to_string(),
-                    tier: $tier,
+                    tier: graded.tier.tier,
+                    score: graded.score as f32,
+                    model_version: graded.model_version.to_string(),
+                    profile: graded.profile.to_string(),
+                    factors_missing: graded.factors_missing,
                     pop: $pop,
                     income: $income,
                     zhvi: $zhvi,
@@ -243,215 +253,235 @@ static COUNTY_DATABASE: Lazy<CountyDb> = Lazy::new(|| {
     }
     
     // Alabama
-    add_county!("AL", "Shelby", 223024, 85678, 345000, 5.8, 32, 1, "Birmingham suburb");
-    add_county!("AL", "Madison", 387545, 68234, 285000, 5.5, 32, 1, "Huntsville tech");
-    add_county!("AL", "Baldwin", 231767, 62481, 320000, 5.8, 38, 1, "Gulf Coast");
-    add_county!("AL", "Jefferson", 674721, 52891, 185000, 3.2, 42, 2, "Birmingham");
-    add_county!("AL", "Mobile", 414809, 48234, 165000, 3.0, 48, 2, "Port city");
+    add_county!("AL", "Shelby", 223024, 85678, 345000, 5.8, 32, "Birmingham suburb");
+    add_county!("AL", "Madison", 387545, 68234, 285000, 5.5, 32, "Huntsville tech");
+    add_county!("AL", "Baldwin", 231767, 62481, 320000, 5.8, 38, "Gulf Coast");
+    add_county!("AL", "Jefferson", 674721, 52891, 185000, 3.2, 42, "Birmingham");
+    add_county!("AL", "Mobile", 414809, 48234, 165000, 3.0, 48, "Port city");
     
     // Alaska
-    add_county!("AK", "Anchorage", 291247, 84567, 365000, 2.8, 45, 1, "Urban center");
-    add_county!("AK", "Matanuska-Susitna", 108317, 75678, 325000, 4.5, 48, 2, "Mat-Su");
-    add_county!("AK", "Fairbanks", 97121, 72345, 275000, 2.5, 55, 2, "Interior");
+    add_county!("AK", "Anchorage", 291247, 84567, 365000, 2.8, 45, "Urban center");
+    add_county!("AK", "Matanuska-Susitna", 108317, 75678, 325000, 4.5, 48, "Mat-Su");
+    add_county!("AK", "Fairbanks", 97121, 72345, 275000, 2.5, 55, "Interior");
     
     // Arizona
-    add_county!("AZ", "Maricopa", 4420568, 68234, 420000, 5.5, 30, 1, "Phoenix");
-    add_county!("AZ", "Pima", 1043433, 55234, 320000, 4.2, 42, 1, "Tucson");
-    add_county!("AZ", "Pinal", 464474, 58234, 345000, 5.8, 38, 2, "Phoenix spillover");
+    add_county!("AZ", "Maricopa", 4420568, 68234, 420000, 5.5, 30, "Phoenix");
+    add_county!("AZ", "Pima", 1043433, 55234, 320000, 4.2, 42, "Tucson");
+    add_county!("AZ", "Pinal", 464474, 58234, 345000, 5.8, 38, "Phoenix spillover");
     
     // California
-    add_county!("CA", "Los Angeles", 9829544, 72000, 850000, 3.8, 35, 1, "LA metro");
-    add_county!("CA", "San Diego", 3286069, 82000, 880000, 4.5, 28, 1, "Biotech");
-    add_county!("CA", "Orange", 3167809, 100000, 1050000, 4.2, 30, 1, "OC");
-    add_county!("CA", "San Francisco", 815201, 140000, 1350000, 2.5, 30, 1, "SF");
+    add_county!("CA", "Los Angeles", 9829544, 72000, 850000, 3.8, 35, "LA metro");
+    add_county!("CA", "San Diego", 3286069, 82000, 880000, 4.5, 28, "Biotech");
+    add_county!("CA", "Orange", 3167809, 100000, 1050000, 4.2, 30, "OC");
+    add_county!("CA", "San Francisco", 815201, 140000, 1350000, 2.5, 30, "SF");
     
     // Colorado
-    add_county!("CO", "Denver", 715522, 78000, 580000, 4.8, 28, 1, "Denver");
-    add_county!("CO", "El Paso", 730395, 68000, 420000, 4.5, 32, 1, "CO Springs");
-    add_county!("CO", "Boulder", 330758, 88000, 680000, 3.8, 35, 1, "CU");
+    add_county!("CO", "Denver", 715522, 78000, 580000, 4.8, 28, "Denver");
+    add_county!("CO", "El Paso", 730395, 68000, 420000, 4.5, 32, "CO Springs");
+    add_county!("CO", "Boulder", 330758, 88000, 680000, 3.8, 35, "CU");
     
     // Florida
-    add_county!("FL", "Miami-Dade", 2701767, 58000, 520000, 5.8, 35, 1, "Miami");
-    add_county!("FL", "Broward", 1944375, 62000, 450000, 5.2, 32, 1, "Ft Lauderdale");
-    add_county!("FL", "Palm Beach", 1492191, 72000, 520000, 4.8, 38, 1, "Palm Beach");
-    add_county!("FL", "Hillsborough", 1459762, 62000, 380000, 5.5, 30, 1, "Tampa");
-    add_county!("FL", "Orange", 1393452, 58000, 385000, 5.2, 32, 1, "Orlando");
+    add_county!("FL", "Miami-Dade", 2701767, 58000, 520000, 5.8, 35, "Miami");
+    add_county!("FL", "Broward", 1944375, 62000, 450000, 5.2, 32, "Ft Lauderdale");
+    add_county!("FL", "Palm Beach", 1492191, 72000, 520000, 4.8, 38, "Palm Beach");
+    add_county!("FL", "Hillsborough", 1459762, 62000, 380000, 5.5, 30, "Tampa");
+    add_county!("FL", "Orange", 1393452, 58000, 385000, 5.2, 32, "Orlando");
     
     // Georgia
-    add_county!("GA", "Fulton", 1066710, 72000, 420000, 5.2, 28, 1, "Atlanta");
-    add_county!("GA", "Gwinnett", 936250, 72000, 380000, 4.8, 32, 1, "Atlanta NE");
-    add_county!("GA", "Cobb", 760141, 78000, 420000, 4.5, 30, 1, "Marietta");
+    add_county!("GA", "Fulton", 1066710, 72000, 420000, 5.2, 28, "Atlanta");
+    add_county!("GA", "Gwinnett", 936250, 72000, 380000, 4.8, 32, "Atlanta NE");
+    add_county!("GA", "Cobb", 760141, 78000, 420000, 4.5, 30, "Marietta");
     
     // Hawaii
-    add_county!("HI", "Honolulu", 974563, 92000, 950000, 3.2, 35, 1, "Oahu");
-    add_county!("HI", "Hawaii", 200983, 68000, 520000, 3.5, 48, 2, "Big Island");
-    add_county!("HI", "Maui", 164637, 78000, 980000, 3.0, 52, 2, "Maui");
+    add_county!("HI", "Honolulu", 974563, 92000, 950000, 3.2, 35, "Oahu");
+    add_county!("HI", "Hawaii", 200983, 68000, 520000, 3.5, 48, "Big Island");
+    add_county!("HI", "Maui", 164637, 78000, 980000, 3.0, 52, "Maui");
     
     // New York
-    add_county!("NY", "Kings", 2559903, 67000, 850000, 5.1, 25, 1, "Brooklyn");
-    add_county!("NY", "Queens", 2253858, 72500, 680000, 4.8, 30, 1, "Queens");
-    add_county!("NY", "New York", 1629153, 93651, 1150000, 4.2, 28, 1, "Manhattan");
-    add_county!("NY", "Nassau", 1356924, 120000, 620000, 5.5, 28, 1, "Long Island");
+    add_county!("NY", "Kings", 2559903, 67000, 850000, 5.1, 25, "Brooklyn");
+    add_county!("NY", "Queens", 2253858, 72500, 680000, 4.8, 30, "Queens");
+    add_county!("NY", "New York", 1629153, 93651, 1150000, 4.2, 28, "Manhattan");
+    add_county!("NY", "Nassau", 1356924, 120000, 620000, 5.5, 28, "Long Island");
     
     // Texas
-    add_county!("TX", "Harris", 4731145, 63000, 285000, 4.5, 32, 1, "Houston");
-    add_county!("TX", "Dallas", 2613539, 62000, 320000, 5.2, 28, 1, "Dallas");
-    add_county!("TX", "Tarrant", 2110640, 68000, 310000, 4.8, 30, 1, "Fort Worth");
-    add_county!("TX", "Travis", 1290188, 85000, 520000, 6.5, 25, 1, "Austin");
-    add_county!("TX", "Collin", 1064465, 110000, 480000, 5.8, 28, 1, "Plano");
+    add_county!("TX", "Harris", 4731145, 63000, 285000, 4.5, 32, "Houston");
+    add_county!("TX", "Dallas", 2613539, 62000, 320000, 5.2, 28, "Dallas");
+    add_county!("TX", "Tarrant", 2110640, 68000, 310000, 4.8, 30, "Fort Worth");
+    add_county!("TX", "Travis", 1290188, 85000, 520000, 6.5, 25, "Austin");
+    add_county!("TX", "Collin", 1064465, 110000, 480000, 5.8, 28, "Plano");
     
     // Idaho
-    add_county!("ID", "Ada", 494967, 72000, 520000, 5.5, 28, 1, "Boise");
-    add_county!("ID", "Canyon", 229849, 55000, 380000, 5.8, 35, 2, "Nampa");
+    add_county!("ID", "Ada", 494967, 72000, 520000, 5.5, 28, "Boise");
+    add_county!("ID", "Canyon", 229849, 55000, 380000, 5.8, 35, "Nampa");
     
     // Illinois
-    add_county!("IL", "Cook", 5173146, 65000, 310000, 3.2, 35, 1, "Chicago");
-    add_county!("IL", "DuPage", 932877, 95000, 380000, 2.8, 32, 1, "West suburbs");
+    add_county!("IL", "Cook", 5173146, 65000, 310000, 3.2, 35, "Chicago");
+    add_county!("IL", "DuPage", 932877, 95000, 380000, 2.8, 32, "West suburbs");
     
     // Indiana
-    add_county!("IN", "Hamilton", 338011, 105000, 385000, 4.5, 32, 1, "Carmel");
-    add_county!("IN", "Marion", 977203, 52000, 215000, 4.2, 35, 2, "Indianapolis");
+    add_county!("IN", "Hamilton", 338011, 105000, 385000, 4.5, 32, "Carmel");
+    add_county!("IN", "Marion", 977203, 52000, 215000, 4.2, 35, "Indianapolis");
     
     // Iowa
-    add_county!("IA", "Polk", 492401, 68000, 265000, 3.8, 35, 2, "Des Moines");
+    add_county!("IA", "Polk", 492401, 68000, 265000, 3.8, 35, "Des Moines");
     
     // Kansas
-    add_county!("KS", "Johnson", 609863, 92000, 350000, 3.8, 32, 1, "KC suburbs");
+    add_county!("KS", "Johnson", 609863, 92000, 350000, 3.8, 32, "KC suburbs");
     
     // Kentucky
-    add_county!("KY", "Jefferson", 782969, 55000, 225000, 3.5, 38, 2, "Louisville");
-    add_county!("KY", "Fayette", 323152, 58000, 265000, 3.8, 35, 2, "Lexington");
+    add_county!("KY", "Jefferson", 782969, 55000, 225000, 3.5, 38, "Louisville");
+    add_county!("KY", "Fayette", 323152, 58000, 265000, 3.8, 35, "Lexington");
     
     // Louisiana
-    add_county!("LA", "East Baton Rouge", 456781, 55000, 235000, 3.2, 42, 2, "Baton Rouge");
-    add_county!("LA", "Orleans", 383997, 45000, 285000, 3.5, 42, 2, "New Orleans");
+    add_county!("LA", "East Baton Rouge", 456781, 55000, 235000, 3.2, 42, "Baton Rouge");
+    add_county!("LA", "Orleans", 383997, 45000, 285000, 3.5, 42, "New Orleans");
     
     // Maine
-    add_county!("ME", "Cumberland", 303069, 78000, 450000, 3.5, 38, 2, "Portland");
+    add_county!("ME", "Cumberland", 303069, 78000, 450000, 3.5, 38, "Portland");
     
     // Maryland
-    add_county!("MD", "Montgomery", 1062061, 115000, 580000, 3.2, 32, 1, "DC suburbs");
-    add_county!("MD", "Prince George's", 967201, 82000, 380000, 3.8, 35, 1, "DC suburbs");
+    add_county!("MD", "Montgomery", 1062061, 115000, 580000, 3.2, 32, "DC suburbs");
+    add_county!("MD", "Prince George's", 967201, 82000, 380000, 3.8, 35, "DC suburbs");
     
     // Massachusetts
-    add_county!("MA", "Middlesex", 1632002, 105000, 680000, 3.2, 28, 1, "Cambridge");
-    add_county!("MA", "Suffolk", 803907, 78000, 680000, 3.0, 30, 1, "Boston");
+    add_county!("MA", "Middlesex", 1632002, 105000, 680000, 3.2, 28, "Cambridge");
+    add_county!("MA", "Suffolk", 803907, 78000, 680000, 3.0, 30, "Boston");
     
     // Michigan
-    add_county!("MI", "Oakland", 1274395, 78000, 320000, 3.8, 32, 1, "Detroit N");
-    add_county!("MI", "Wayne", 1773922, 48000, 145000, 4.5, 38, 2, "Detroit");
+    add_county!("MI", "Oakland", 1274395, 78000, 320000, 3.8, 32, "Detroit N");
+    add_county!("MI", "Wayne", 1773922, 48000, 145000, 4.5, 38, "Detroit");
     
     // Minnesota
-    add_county!("MN", "Hennepin", 1281565, 78000, 350000, 3.5, 28, 1, "Minneapolis");
-    add_county!("MN", "Ramsey", 552352, 65000, 295000, 3.2, 32, 1, "St. Paul");
+    add_county!("MN", "Hennepin", 1281565, 78000, 350000, 3.5, 28, "Minneapolis");
+    add_county!("MN", "Ramsey", 552352, 65000, 295000, 3.2, 32, "St. Paul");
     
     // Mississippi
-    add_county!("MS", "DeSoto", 184945, 68000, 265000, 4.2, 38, 2, "Memphis sub");
+    add_county!("MS", "DeSoto", 184945, 68000, 265000, 4.2, 38, "Memphis sub");
     
     // Missouri
-    add_county!("MO", "St. Louis County", 1004125, 72000, 265000, 2.8, 38, 2, "STL suburbs");
-    add_county!("MO", "Jackson", 717204, 55000, 215000, 3.2, 40, 2, "Kansas City");
+    add_county!("MO", "St. Louis County", 1004125, 72000, 265000, 2.8, 38, "STL suburbs");
+    add_county!("MO", "Jackson", 717204, 55000, 215000, 3.2, 40, "Kansas City");
     
     // Montana
-    add_county!("MT", "Yellowstone", 164731, 58000, 350000, 4.2, 42, 2, "Billings");
-    add_county!("MT", "Gallatin", 114434, 68000, 620000, 5.2, 38, 2, "Bozeman");
+    add_county!("MT", "Yellowstone", 164731, 58000, 350000, 4.2, 42, "Billings");
+    add_county!("MT", "Gallatin", 114434, 68000, 620000, 5.2, 38, "Bozeman");
     
     // Nebraska
-    add_county!("NE", "Douglas", 584526, 68000, 265000, 3.5, 35, 2, "Omaha");
+    add_county!("NE", "Douglas", 584526, 68000, 265000, 3.5, 35, "Omaha");
     
     // Nevada
-    add_county!("NV", "Clark", 2265461, 58000, 420000, 5.5, 32, 1, "Las Vegas");
-    add_county!("NV", "Washoe", 486492, 65000, 520000, 5.0, 35, 1, "Reno");
+    add_county!("NV", "Clark", 2265461, 58000, 420000, 5.5, 32, "Las Vegas");
+    add_county!("NV", "Washoe", 486492, 65000, 520000, 5.0, 35, "Reno");
     
     // New Hampshire
-    add_county!("NH", "Hillsborough", 422937, 82000, 420000, 3.8, 32, 1, "Manchester");
+    add_county!("NH", "Hillsborough", 422937, 82000, 420000, 3.8, 32, "Manchester");
     
     // New Jersey
-    add_county!("NJ", "Bergen", 955732, 105000, 580000, 3.2, 32, 1, "NYC suburbs");
-    add_county!("NJ", "Middlesex", 863162, 92000, 480000, 3.5, 32, 1, "Central NJ");
+    add_county!("NJ", "Bergen", 955732, 105000, 580000, 3.2, 32, "NYC suburbs");
+    add_county!("NJ", "Middlesex", 863162, 92000, 480000, 3.5, 32, "Central NJ");
     
     // New Mexico
-    add_county!("NM", "Bernalillo", 679121, 52000, 295000, 4.2, 42, 2, "Albuquerque");
+    add_county!("NM", "Bernalillo", 679121, 52000, 295000, 4.2, 42, "Albuquerque");
     
     // North Carolina
-    add_county!("NC", "Wake", 1129410, 82000, 420000, 5.2, 28, 1, "Raleigh");
-    add_county!("NC", "Mecklenburg", 1115482, 72000, 380000, 4.8, 30, 1, "Charlotte");
+    add_county!("NC", "Wake", 1129410, 82000, 420000, 5.2, 28, "Raleigh");
+    add_county!("NC", "Mecklenburg", 1115482, 72000, 380000, 4.8, 30, "Charlotte");
     
     // North Dakota
-    add_county!("ND", "Cass", 184525, 62000, 295000, 3.2, 38, 2, "Fargo");
+    add_county!("ND", "Cass", 184525, 62000, 295000, 3.2, 38, "Fargo");
     
     // Ohio
-    add_county!("OH", "Franklin", 1323807, 62000, 285000, 4.8, 28, 1, "Columbus");
-    add_county!("OH", "Cuyahoga", 1235072, 52000, 165000, 2.5, 42, 2, "Cleveland");
+    add_county!("OH", "Franklin", 1323807, 62000, 285000, 4.8, 28, "Columbus");
+    add_county!("OH", "Cuyahoga", 1235072, 52000, 165000, 2.5, 42, "Cleveland");
     
     // Oklahoma
-    add_county!("OK", "Oklahoma", 797434, 55000, 195000, 3.5, 38, 2, "OKC");
-    add_county!("OK", "Tulsa", 669279, 55000, 195000, 3.2, 40, 2, "Tulsa");
+    add_county!("OK", "Oklahoma", 797434, 55000, 195000, 3.5, 38, "OKC");
+    add_county!("OK", "Tulsa", 669279, 55000, 195000, 3.2, 40, "Tulsa");
     
     // Oregon
-    add_county!("OR", "Multnomah", 812855, 72000, 520000, 4.0, 32, 1, "Portland");
-    add_county!("OR", "Washington", 600372, 85000, 550000, 4.5, 30, 1, "Hillsboro");
+    add_county!("OR", "Multnomah", 812855, 72000, 520000, 4.0, 32, "Portland");
+    add_county!("OR", "Washington", 600372, 85000, 550000, 4.5, 30, "Hillsboro");
     
     // Pennsylvania
-    add_county!("PA", "Philadelphia", 1576251, 52000, 220000, 4.5, 35, 2, "Philadelphia");
-    add_county!("PA", "Allegheny", 1218380, 62000, 225000, 3.8, 38, 2, "Pittsburgh");
-    add_county!("PA", "Montgomery", 856553, 95000, 420000, 3.5, 32, 1, "Main Line");
+    add_county!("PA", "Philadelphia", 1576251, 52000, 220000, 4.5, 35, "Philadelphia");
+    add_county!("PA", "Allegheny", 1218380, 62000, 225000, 3.8, 38, "Pittsburgh");
+    add_county!("PA", "Montgomery", 856553, 95000, 420000, 3.5, 32, "Main Line");
     
     // Rhode Island
-    add_county!("RI", "Providence", 660741, 58000, 350000, 3.8, 38, 2, "Providence");
+    add_county!("RI", "Providence", 660741, 58000, 350000, 3.8, 38, "Providence");
     
     // South Carolina
-    add_county!("SC", "Charleston", 411406, 68000, 420000, 4.8, 35, 1, "Charleston");
-    add_county!("SC", "Greenville", 523542, 62000, 285000, 4.5, 35, 2, "Greenville");
+    add_county!("SC", "Charleston", 411406, 68000, 420000, 4.8, 35, "Charleston");
+    add_county!("SC", "Greenville", 523542, 62000, 285000, 4.5, 35, "Greenville");
     
     // South Dakota
-    add_county!("SD", "Minnehaha", 197214, 62000, 295000, 4.0, 35, 2, "Sioux Falls");
+    add_county!("SD", "Minnehaha", 197214, 62000, 295000, 4.0, 35, "Sioux Falls");
     
     // Tennessee
-    add_county!("TN", "Davidson", 715884, 62000, 380000, 5.2, 32, 1, "Nashville");
-    add_county!("TN", "Shelby", 937166, 52000, 225000, 3.8, 38, 2, "Memphis");
+    add_county!("TN", "Davidson", 715884, 62000, 380000, 5.2, 32, "Nashville");
+    add_county!("TN", "Shelby", 937166, 52000, 225000, 3.8, 38, "Memphis");
     
     // Utah
-    add_county!("UT", "Salt Lake", 1160437, 72000, 520000, 5.5, 28, 1, "Salt Lake City");
-    add_county!("UT", "Utah", 659399, 72000, 480000, 5.8, 30, 1, "Provo");
+    add_county!("UT", "Salt Lake", 1160437, 72000, 520000, 5.5, 28, "Salt Lake City");
+    add_county!("UT", "Utah", 659399, 72000, 480000, 5.8, 30, "Provo");
     
     // Vermont
-    add_county!("VT", "Chittenden", 168323, 78000, 450000, 3.5, 38, 2, "Burlington");
+    add_county!("VT", "Chittenden", 168323, 78000, 450000, 3.5, 38, "Burlington");
     
     // Virginia
-    add_county!("VA", "Fairfax", 1150309, 130000, 680000, 3.5, 28, 1, "Fairfax");
-    add_county!("VA", "Prince William", 482204, 105000, 480000, 4.5, 32, 1, "Woodbridge");
-    add_county!("VA", "Loudoun", 420959, 155000, 680000, 4.0, 30, 1, "Leesburg");
+    add_county!("VA", "Fairfax", 1150309, 130000, 680000, 3.5, 28, "Fairfax");
+    add_county!("VA", "Prince William", 482204, 105000, 480000, 4.5, 32, "Woodbridge");
+    add_county!("VA", "Loudoun", 420959, 155000, 680000, 4.0, 30, "Leesburg");
     
     // Washington
-    add_county!("WA", "King", 2269675, 105000, 780000, 4.5, 25, 1, "Seattle");
-    add_county!("WA", "Pierce", 921130, 72000, 480000, 5.2, 32, 1, "Tacoma");
-    add_county!("WA", "Snohomish", 827957, 88000, 620000, 5.0, 30, 1, "Everett");
+    add_county!("WA", "King", 2269675, 105000, 780000, 4.5, 25, "Seattle");
+    add_county!("WA", "Pierce", 921130, 72000, 480000, 5.2, 32, "Tacoma");
+    add_county!("WA", "Snohomish", 827957, 88000, 620000, 5.0, 30, "Everett");
     
     // West Virginia
-    add_county!("WV", "Berkeley", 119171, 62000, 265000, 4.0, 45, 2, "Martinsburg");
+    add_county!("WV", "Berkeley", 119171, 62000, 265000, 4.0, 45, "Martinsburg");
     
     // Wisconsin
-    add_county!("WI", "Milwaukee", 939489, 48000, 185000, 4.0, 38, 2, "Milwaukee");
-    add_county!("WI", "Dane", 561504, 72000, 380000, 4.5, 32, 1, "Madison");
+    add_county!("WI", "Milwaukee", 939489, 48000, 185000, 4.0, 38, "Milwaukee");
+    add_county!("WI", "Dane", 561504, 72000, 380000, 4.5, 32, "Madison");
     
     // Wyoming
-    add_county!("WY", "Laramie", 100512, 58000, 295000, 3.5, 48, 3, "Cheyenne");
-    add_county!("WY", "Teton", 23464, 92000, 1250000, 4.0, 55, 2, "Jackson");
+    add_county!("WY", "Laramie", 100512, 58000, 295000, 3.5, 48, "Cheyenne");
+    add_county!("WY", "Teton", 23464, 92000, 1250000, 4.0, 55, "Jackson");
     
     // Arkansas
-    add_county!("AR", "Benton", 284333, 72345, 295000, 5.8, 32, 1, "NW Arkansas");
-    add_county!("AR", "Washington", 245871, 55678, 285000, 5.2, 35, 1, "Fayetteville");
+    add_county!("AR", "Benton", 284333, 72345, 295000, 5.8, 32, "NW Arkansas");
+    add_county!("AR", "Washington", 245871, 55678, 285000, 5.2, 35, "Fayetteville");
     
     // Connecticut
-    add_county!("CT", "Fairfield", 943332, 105000, 580000, 3.2, 38, 1, "NYC suburbs");
+    add_county!("CT", "Fairfield", 943332, 105000, 580000, 3.2, 38, "NYC suburbs");
     
     // Delaware
-    add_county!("DE", "New Castle", 570719, 72000, 320000, 3.5, 38, 2, "Wilmington");
+    add_county!("DE", "New Castle", 570719, 72000, 320000, 3.5, 38, "Wilmington");
     
     db
 });
 
+// COUNTY_DATABASE re-graded under each scoring profile, built once per process
+static PROFILE_COUNTY_DATABASES: Lazy<HashMap<String, CountyDb>> = Lazy::new(|| {
+    scoring::model()
+        .profiles
+        .iter()
+        .map(|profile| {
+            let mut db = COUNTY_DATABASE.clone();
+            for county in db.values_mut().flatten() {
+                let values = county_factors(county.pop, county.income, county.zhvi, county.growth, county.dom);
+                let graded = scoring::score_with(profile, &values);
+                county.tier = graded.tier.tier;
+                county.score = graded.score as f32;
+                county.profile = profile.name.clone();
+                county.factors_missing = graded.factors_missing;
+            }
+            (profile.name.clone(), db)
+        })
+        .collect()
+});
+
 // ============================================================================
 // QUERY PARAMS
 // ============================================================================
@@ -459,6 +489,30 @@ static COUNTY_DATABASE: Lazy<CountyDb> = Lazy::new(|| {
 #[derive(Deserialize)]
 struct CountyParams {
     state: Option<String>,
+    profile: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ProfileParams {
+    // Scoring profile name ("lien-yield", "deed-flip", "buy-and-hold"); default weights when absent
+    profile: Option<String>,
+}
+
+fn scoring_profile(name: Option<&str>) -> Result<&'static scoring::Profile, ApiError> {
+    match name.map(str::trim).filter(|n| !n.is_empty()) {
+        Some(name) => scoring::model().profile(name).map_err(|e| api_error(StatusCode::BAD_REQUEST, e)),
+        None => Ok(scoring::default_profile()),
+    }
+}
+
+#[derive(Deserialize)]
+struct CensusParams {
+    // Include each county's per-factor score breakdown
+    explain: Option<bool>,
+    // ACS 5-year vintage; when given, changes from the previous vintage are included
+    year: Option<u16>,
+    // core (default) or extended
+    vars: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -482,6 +536,8 @@ async fn health() -> Json<HealthResponse> {
             "/api/state-info/:abbr".to_string(),
             "/api/counties".to_string(),
             "/api/analyze".to_string(),
+            "/api/analyze/batch".to_string(),
+            "/api/analyze/county/:fips".to_string(),
         ],
     })
 }
@@ -512,18 +568,20 @@ async fn get_state_info(Path(abbr): Path<String>) -> Json<Option<StateAuctionInf
     Json(STATE_AUCTION_DATA.get(&upper_abbr).cloned())
 }
 
-async fn get_counties(Query(params): Query<CountyParams>) -> Json<Vec<CountyData>> {
+async fn get_counties(Query(params): Query<CountyParams>) -> Result<Json<Vec<CountyData>>, ApiError> {
+    let profile = scoring_profile(params.profile.as_deref())?;
+    let database = &PROFILE_COUNTY_DATABASES[&profile.name];
     let mut results = Vec::new();
     
     if let Some(state) = params.state {
         // Try both abbreviation and full name
         let upper_state = state.to_uppercase();
-        if let Some(counties) = COUNTY_DATABASE.get(&upper_state) {
+        if let Some(counties) = database.get(&upper_state) {
             results.extend(counties.clone());
         }
     } else {
         // Return all counties
-        for counties in COUNTY_DATABASE.values() {
+        for counties in database.values() {
             results.extend(counties.clone());
         }
     }
@@ -537,219 +595,310 @@ async fn get_counties(Query(params): Query<CountyParams>) -> Json<Vec<CountyData
         }
     });
     
-    Json(results)
+    Ok(Json(results))
 }
 
-fn calculate_score(input: &AnalysisInput) -> AnalysisOutput {
-    let mut score: f32 = 0.0;
-    
-    // Population (15%) - Target 500k+
-    score += (input.population as f32 / 500_000.0).min(1.0) * 15.0;
-    
-    // Median Income (15%) - Target $80k+
-    score += (input.median_income as f32 / 80_000.0).min(1.0) * 15.0;
-    
-    // Growth YoY (20%) - Target 5%+
-    score += (input.growth_yoy / 5.0).max(0.0).min(1.0) * 20.0;
-    
-    // Days on Market (20%) - Target < 30 (Inverse)
-    let dom_score = if input.days_on_market < 30 { 20.0 }
-        else if input.days_on_market > 90 { 0.0 }
-        else { (90.0 - input.days_on_market as f32) / 60.0 * 20.0 };
-    score += dom_score;
-    
-    // Transaction Volume (15%) - Target 10k+
-    score += (input.transaction_volume as f32 / 10_000.0).min(1.0) * 15.0;
-    
-    // Employment Rate (15%) - Target 96%+
-    score += ((input.employment_rate - 90.0) / 6.0).max(0.0).min(1.0) * 15.0;
-    
-    let tier = if score >= 85.0 { 1 }
-        else if score >= 70.0 { 2 }
-        else if score >= 50.0 { 3 }
-        else if score >= 30.0 { 4 }
-        else { 5 };
-    
-    let (name, action, recommendation) = match tier {
-        1 => ("Prime Investor", "✓ PURSUE", "Exceptional liquidity and growth fundamentals."),
-        2 => ("Strong/Selective", "✓ PURSUE", "Solid market; focus on specific neighborhood due diligence."),
-        3 => ("Opportunistic", "✓ PURSUE", "Stable regional hub; steady cash flow potential."),
-        4 => ("Speculative", "⚠ CAUTION", "Limited liquidity; higher exit risk."),
-        _ => ("Capital Trap", "✗ AVOID", "Weak fundamentals; significant risk of illiquidity."),
-    };
+// Scoring-engine inputs for a COUNTY_DATABASE row
+fn county_factors(pop: u32, income: u32, zhvi: u32, growth: f32, dom: u32) -> scoring::FactorValues {
+    HashMap::from([
+        (scoring::POPULATION, pop as f64),
+        (scoring::MEDIAN_INCOME, income as f64),
+        (scoring::MEDIAN_HOME_VALUE, zhvi as f64),
+        (scoring::GROWTH_YOY, growth as f64),
+        (scoring::DAYS_ON_MARKET, dom as f64),
+    ])
+}
+
+fn calculate_score(profile: &'static scoring::Profile, input: &AnalysisInput) -> AnalysisOutput {
+    let mut values = HashMap::from([
+        (scoring::POPULATION, input.population as f64),
+        (scoring::MEDIAN_INCOME, input.median_income as f64),
+        (scoring::GROWTH_YOY, input.growth_yoy as f64),
+        (scoring::DAYS_ON_MARKET, input.days_on_market as f64),
+        (scoring::TRANSACTION_VOLUME, input.transaction_volume as f64),
+        (scoring::EMPLOYMENT_RATE, input.employment_rate as f64),
+    ]);
+    if let Some(home_value) = input.median_home_value {
+        values.insert(scoring::MEDIAN_HOME_VALUE, home_value as f64);
+    }
+    analysis_output(profile, &values)
+}
+
+fn analysis_output(profile: &'static scoring::Profile, values: &scoring::FactorValues) -> AnalysisOutput {
+    let graded = scoring::score_with(profile, values);
     
     AnalysisOutput {
-        score,
-        tier,
-        name: name.to_string(),
-        action: action.to_string(),
-        recommendation: recommendation.to_string(),
+        score: graded.score as f32,
+        tier: graded.tier.tier,
+        name: graded.tier.name.clone(),
+        action: graded.tier.action.clone(),
+        recommendation: graded.tier.recommendation.clone(),
+        model_version: graded.model_version.to_string(),
+        profile: graded.profile.to_string(),
+        factors_missing: graded.factors_missing,
+        breakdown: graded.breakdown,
     }
 }
 
-async fn analyze_county(Json(input): Json<AnalysisInput>) -> Json<AnalysisOutput> {
-    Json(calculate_score(&input))
+async fn analyze_county(
+    Query(params): Query<ProfileParams>,
+    Json(input): Json<AnalysisInput>,
+) -> Result<Json<AnalysisOutput>, ApiError> {
+    let profile = scoring_profile(params.profile.as_deref())?;
+    Ok(Json(calculate_score(profile, &input)))
+}
+
+#[derive(Deserialize)]
+struct BatchParams {
+    // Response format; defaults to the Accept header, then JSON
+    format: Option<String>,
+}
+
+// POST /api/analyze/batch?format=csv|json
+// Body: JSON array of county inputs, or CSV (Content-Type: text/csv) with a fips column
+async fn analyze_batch_handler(
+    Query(params): Query<BatchParams>,
+    headers: HeaderMap,
+    body: String,
+) -> Result<Response, ApiError> {
+    let header_value = |name| headers.get(name).and_then(|v: &header::HeaderValue| v.to_str().ok()).unwrap_or("");
+    let output = match params.format.as_deref() {
+        Some(f) => payload_format::PayloadFormat::parse(f)
+            .ok_or_else(|| api_error(StatusCode::BAD_REQUEST, format!("Unknown output format '{}'", f)))?,
+        None if header_value(header::ACCEPT).contains("text/csv") => payload_format::PayloadFormat::Csv,
+        None => payload_format::PayloadFormat::Json,
+    };
+    let input = payload_format::PayloadFormat::detect(header_value(header::CONTENT_TYPE));
+    let rows = batch_scoring::parse_rows(input, &body).map_err(|e| api_error(StatusCode::BAD_REQUEST, e))?;
+    
+    // Census fills blank population / income / home value for rows with a FIPS code
+    let mut warnings = Vec::new();
+    let mut census_by_fips = None;
+    if rows.iter().any(|r| r.as_ref().is_ok_and(batch_scoring::BatchRow::needs_census)) {
+        let api_key = std::env::var("CENSUS_API_KEY").ok();
+        match census::fetch_all_counties(api_key.as_deref()).await {
+            Ok(counties) => {
+                census_by_fips = Some(counties.into_iter().map(|c| (c.fips.clone(), c)).collect::<HashMap<_, _>>());
+            }
+            Err(e) => warnings.push(format!("Census data unavailable, blank factors were not filled: {}", e)),
+        }
+    }
+    
+    let response = batch_scoring::score_rows(rows, census_by_fips.as_ref(), warnings);
+    match output {
+        payload_format::PayloadFormat::Json => Ok(Json(response).into_response()),
+        payload_format::PayloadFormat::Csv => Ok((
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+                (header::CONTENT_DISPOSITION, "attachment; filename=\"county-scores.csv\""),
+            ],
+            batch_scoring::to_csv(&response).map_err(internal_error)?,
+        )
+            .into_response()),
+    }
 }
 
-// Zillow ZHVI fetcher
-const ZILLOW_ZHVI_URL: &str = "https://files.zillowstatic.com/research/public_csvs/zhvi/County_zhvi_uc_sfrcondo_tier_0.33_0.67_sm_sa_month.csv";
+// Latest ZHVI per county from the cached Zillow history
+async fn fetch_zhvi_records() -> Result<Vec<ZhviRecord>, String> {
+    // Latest month plus its change from a year earlier
+    let history = zhvi_history::load().await?.history;
+    let latest = history.latest_month().unwrap_or(0);
+    let records = history
+        .counties
+        .iter()
+        .filter_map(|c| {
+            let zhvi = c.value_at(latest)?;
+            let zhvi_change_yoy = c.change_pct(latest, 12).map_or(0.0, |pct| (pct * 10.0).round() / 10.0);
+            Some(ZhviRecord {
+                region_name: c.region_name.clone(),
+                state: c.state.clone(),
+                state_fips: c.state_fips.clone(),
+                county_fips: c.county_fips.clone(),
+                zhvi,
+                zhvi_change_yoy,
+            })
+        })
+        .collect();
+    
+    Ok(records)
+}
 
 async fn get_zillow_zhvi() -> Json<ZhviResponse> {
-    // Fetch CSV from Zillow
-    let response = match reqwest::get(ZILLOW_ZHVI_URL).await {
-        Ok(resp) => resp,
+    match fetch_zhvi_records().await {
+        Ok(records) => Json(ZhviResponse {
+            updated: chrono::Utc::now().to_rfc3339(),
+            source: "Zillow Research ZHVI".to_string(),
+            record_count: records.len(),
+            data: records,
+        }),
         Err(e) => {
-            eprintln!("Failed to fetch Zillow data: {}", e);
-            return Json(ZhviResponse {
+            eprintln!("{}", e);
+            Json(ZhviResponse {
                 updated: chrono::Utc::now().to_rfc3339(),
                 source: "Zillow Research - FETCH ERROR".to_string(),
                 record_count: 0,
                 data: vec![],
-            });
+            })
         }
-    };
-    
-    let csv_text = match response.text().await {
-        Ok(text) => text,
-        Err(e) => {
-            eprintln!("Failed to read Zillow response: {}", e);
-            return Json(ZhviResponse {
-                updated: "error".to_string(),
-                source: "Zillow Research - PARSE ERROR".to_string(),
-                record_count: 0,
-                data: vec![],
-            });
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ZhviHistoryResponse {
+    fips: String,
+    region_name: String,
+    state: String,
+    source: String,
+    fetched_at: String,
+    // Zillow was unreachable and the last downloaded copy was used
+    stale: bool,
+    latest: Option<zhvi_history::SeriesPoint>,
+    metrics: zhvi_history::SeriesMetrics,
+    series: Vec<zhvi_history::SeriesPoint>,
+}
+
+// GET /api/zillow/zhvi/:fips/history - full monthly ZHVI series with derived metrics
+async fn get_zhvi_history(Path(fips): Path<String>) -> Result<Json<ZhviHistoryResponse>, ApiError> {
+    let fips = fips.trim();
+    if fips.len() != 5 || !fips.chars().all(|c| c.is_ascii_digit()) {
+        return Err(api_error(StatusCode::BAD_REQUEST, format!("'{}' is not a 5-digit county FIPS code", fips)));
+    }
+    let snapshot = zhvi_history::load().await.map_err(|e| api_error(StatusCode::BAD_GATEWAY, e))?;
+    let history = &snapshot.history;
+    let county = history
+        .counties
+        .iter()
+        .find(|c| c.fips() == fips)
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, format!("No ZHVI series for county FIPS {}", fips)))?;
+    
+    let series: Vec<zhvi_history::SeriesPoint> = history
+        .dates
+        .iter()
+        .zip(&county.values)
+        .filter_map(|(date, value)| value.map(|value| zhvi_history::SeriesPoint { date: date.to_string(), value }))
+        .collect();
+    
+    Ok(Json(ZhviHistoryResponse {
+        fips: fips.to_string(),
+        region_name: county.region_name.clone(),
+        state: county.state.clone(),
+        source: "Zillow Research ZHVI".to_string(),
+        fetched_at: snapshot.fetched_at.clone(),
+        stale: snapshot.stale,
+        latest: series.last().cloned(),
+        metrics: zhvi_history::metrics(history, county),
+        series,
+    }))
+}
+
+#[derive(Deserialize)]
+struct RedfinParams {
+    region: Option<String>,
+    region_type: Option<String>,
+}
+
+// GET /api/redfin/market?region=&region_type=county|metro - latest Redfin market tracker month per region
+async fn get_redfin_market(Query(params): Query<RedfinParams>) -> Result<Json<RedfResponse>, ApiError> {
+    let levels = match params.region_type.as_deref().map(|t| t.trim().to_lowercase()) {
+        None => redfin::TrackerLevel::ALL.to_vec(),
+        Some(t) if t == "county" => vec![redfin::TrackerLevel::County],
+        Some(t) if t == "metro" => vec![redfin::TrackerLevel::Metro],
+        Some(t) => {
+            return Err(api_error(StatusCode::BAD_REQUEST, format!("region_type must be county or metro, got '{}'", t)));
         }
     };
+    // A full state name filters by its code, so "Texas" matches "Travis County, TX"
+    let region = params.region.as_deref().map(str::trim).filter(|r| !r.is_empty()).map(|r| {
+        STATE_NAMES
+            .iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(r))
+            .map_or(r.to_string(), |(code, _)| code.to_string())
+    });
     
-    // Parse CSV
-    let mut records = Vec::new();
-    let mut reader = csv::Reader::from_reader(csv_text.as_bytes());
-    let headers = reader.headers().cloned().unwrap_or_default();
-    
-    // Find the last column (most recent ZHVI value) and second-to-last for YoY change
-    let total_cols = headers.len();
-    let zhvi_col = if total_cols > 0 { total_cols - 1 } else { 0 };
-    let prev_year_col = if total_cols > 12 { total_cols - 13 } else { 0 };
-    
-    for result in reader.records() {
-        if let Ok(record) = result {
-            // Zillow CSV columns: RegionID(0), RegionName(1), RegionType(2), StateName(3), State(4), StateCodeFIPS(5), MunicipalCodeFIPS(6)...
-            let region_name = record.get(1).unwrap_or("").to_string();
-            let state = record.get(4).unwrap_or("").to_string();  // State abbreviation
-            let state_fips = record.get(5).unwrap_or("").to_string();
-            let county_fips = record.get(6).unwrap_or("").to_string();
-            
-            let zhvi: f64 = record.get(zhvi_col).and_then(|v| v.parse().ok()).unwrap_or(0.0);
-            let prev_zhvi: f64 = record.get(prev_year_col).and_then(|v| v.parse().ok()).unwrap_or(0.0);
-            
-            let zhvi_change_yoy = if prev_zhvi > 0.0 {
-                ((zhvi - prev_zhvi) / prev_zhvi * 100.0 * 10.0).round() / 10.0
-            } else {
-                0.0
-            };
-            
-            if zhvi > 0.0 && !state.is_empty() {
-                records.push(ZhviRecord {
-                    region_name,
-                    state,
-                    state_fips,
-                    county_fips,
-                    zhvi,
-                    zhvi_change_yoy,
-                });
+    let mut data = Vec::new();
+    let mut stale = false;
+    let mut failed = false;
+    let mut downloaded = None;
+    for level in levels {
+        match redfin::load(level).await {
+            Ok(tracker) => {
+                stale |= tracker.stale;
+                downloaded = downloaded.max(Some(tracker.downloaded));
+                data.extend(
+                    tracker
+                        .records
+                        .iter()
+                        .filter(|r| region.as_deref().is_none_or(|q| redfin::matches_region(r, q)))
+                        .cloned(),
+                );
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                failed = true;
             }
         }
     }
     
-    Json(ZhviResponse {
-        updated: chrono::Utc::now().to_rfc3339(),
-        source: "Zillow Research ZHVI".to_string(),
-        record_count: records.len(),
-        data: records,
-    })
+    Ok(Json(RedfResponse {
+        updated: downloaded
+            .map_or_else(chrono::Utc::now, chrono::DateTime::<chrono::Utc>::from)
+            .to_rfc3339(),
+        source: if failed { "Redfin Data Center - FETCH ERROR" } else { "Redfin Data Center" }.to_string(),
+        stale,
+        record_count: data.len(),
+        data,
+    }))
 }
 
-// Redfin Market Data fetcher
-const REDFIN_MARKET_URL: &str = "https://redfin-public-data.s3.us-west-2.amazonaws.com/redfin_market_tracker/us_national_market_tracker.tsv000.gz";
-
-async fn get_redfin_market() -> Json<RedfResponse> {
-    // For demo, return sample market data (Redfin uses gzipped TSV which needs decompression)
-    // In production, you'd fetch and decompress the actual file
-    let sample_data = vec![
-        RedfRecord {
-            region: "National".to_string(),
-            region_type: "country".to_string(),
-            median_dom: 32.0,
-            sale_to_list: 0.987,
-            inventory: 1_250_000,
-            price_drop_pct: 18.5,
-            homes_sold: 425_000,
-        },
-        RedfRecord {
-            region: "California".to_string(),
-            region_type: "state".to_string(),
-            median_dom: 28.0,
-            sale_to_list: 0.995,
-            inventory: 85_000,
-            price_drop_pct: 15.2,
-            homes_sold: 42_000,
-        },
-        RedfRecord {
-            region: "Texas".to_string(),
-            region_type: "state".to_string(),
-            median_dom: 35.0,
-            sale_to_list: 0.978,
-            inventory: 120_000,
-            price_drop_pct: 22.1,
-            homes_sold: 55_000,
-        },
-        RedfRecord {
-            region: "Florida".to_string(),
-            region_type: "state".to_string(),
-            median_dom: 42.0,
-            sale_to_list: 0.965,
-            inventory: 145_000,
-            price_drop_pct: 28.3,
-            homes_sold: 48_000,
-        },
-        RedfRecord {
-            region: "New York".to_string(),
-            region_type: "state".to_string(),
-            median_dom: 38.0,
-            sale_to_list: 0.982,
-            inventory: 65_000,
-            price_drop_pct: 16.8,
-            homes_sold: 28_000,
-        },
-    ];
-    
-    Json(RedfResponse {
-        updated: chrono::Utc::now().to_rfc3339(),
-        source: "Redfin Data Center".to_string(),
-        record_count: sample_data.len(),
-        data: sample_data,
-    })
+// GET /api/market - mortgage rates, economic indicators and housing stats from FRED (15-minute cache)
+async fn get_market() -> Json<market_data::MarketData> {
+    Json(market_data::get_market_data().await)
 }
 
-// Current mortgage rates handler - Now with LIVE FRED API data!
+// Current mortgage rates handler - the /api/market rates in the dashboard's original shape
 async fn get_rates() -> Json<RatesResponse> {
-    // Try to get FRED API key from environment
-    let api_key = std::env::var("FRED_API_KEY").ok();
-    
-    // Fetch live rates (falls back to defaults if no API key)
-    let live_data = fred_api::fetch_live_rates(api_key.as_deref()).await;
+    let market = market_data::get_market_data().await;
+    let unemployment = market
+        .indicators
+        .iter()
+        .find(|i| i.series_id == fred_api::SERIES_UNEMPLOYMENT)
+        .map_or(fred_api::FALLBACK_UNEMPLOYMENT, |i| i.value);
     
     Json(RatesResponse {
-        updated: live_data.updated,
-        source: live_data.source,
-        mortgage_30yr: live_data.mortgage_30yr as f32,
-        mortgage_15yr: live_data.mortgage_15yr as f32,
-        mortgage_30yr_change: live_data.mortgage_30yr_change as f32,
-        unemployment_rate: live_data.unemployment as f32,
+        updated: market.mortgage_rates.updated,
+        source: market.mortgage_rates.source,
+        mortgage_30yr: market.mortgage_rates.rate_30yr as f32,
+        mortgage_15yr: market.mortgage_rates.rate_15yr as f32,
+        mortgage_30yr_change: market.mortgage_rates.change_30yr as f32,
+        unemployment_rate: unemployment as f32,
     })
 }
 
+// GET /api/fred/series/:id?start=&end=&frequency=&units= - full observation history of any FRED series
+async fn get_fred_series(
+    Path(series_id): Path<String>,
+    Query(query): Query<fred_api::SeriesQuery>,
+) -> Result<Json<fred_api::SeriesHistory>, ApiError> {
+    let series_id = series_id.trim().to_uppercase();
+    if !fred_api::is_valid_series_id(&series_id) {
+        return Err(api_error(StatusCode::BAD_REQUEST, format!("'{}' is not a FRED series ID", series_id)));
+    }
+    let errors = query.validate();
+    if !errors.is_empty() {
+        return Err(validation_error(errors));
+    }
+    let api_key = std::env::var("FRED_API_KEY")
+        .ok()
+        .filter(|k| !k.is_empty())
+        .ok_or_else(|| api_error(StatusCode::SERVICE_UNAVAILABLE, "FRED_API_KEY is not configured"))?;
+    
+    fred_api::fetch_series_observations(&series_id, &query, &api_key)
+        .await
+        .map_err(|e| api_error(StatusCode::BAD_GATEWAY, e))?
+        .map(Json)
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, format!("FRED has no series '{}'", series_id)))
+}
+
 // ============================================================================
 // CENSUS API HANDLERS - All 3,144 US Counties
 // ============================================================================
@@ -758,60 +907,205 @@ async fn get_rates() -> Json<RatesResponse> {
 struct CensusCountiesResponse {
     updated: String,
     source: String,
+    year: u16,
+    // Vintage the change fields compare against
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compare_year: Option<u16>,
     total_counties: usize,
+    model_version: String,
     data: Vec<census::CountyCensusData>,
 }
 
+fn census_request(year: Option<u16>, vars: Option<&str>) -> Result<census::AcsRequest, ApiError> {
+    let mut errors = Vec::new();
+    let variables = match vars {
+        None => census::VariableSet::Core,
+        Some(v) => census::VariableSet::parse(v).unwrap_or_else(|| {
+            errors.push(format!("vars must be core or extended, got '{}'", v));
+            census::VariableSet::Core
+        }),
+    };
+    let request = census::AcsRequest { year: year.unwrap_or(census::DEFAULT_VINTAGE), variables };
+    errors.extend(request.validate());
+    if !errors.is_empty() {
+        return Err(validation_error(errors));
+    }
+    Ok(request)
+}
+
+// Attach changes from the vintage before the requested one; None when there is none or it failed to load
+async fn add_census_changes(counties: &mut [census::CountyCensusData], request: census::AcsRequest, api_key: Option<&str>) -> Option<u16> {
+    let compare_year = request.year.checked_sub(1).filter(|y| census::VINTAGES.contains(y))?;
+    let previous = census::AcsRequest { year: compare_year, ..request };
+    match census::fetch_counties(previous, api_key).await {
+        Ok(previous) => {
+            census::attach_changes(counties, &previous, compare_year);
+            Some(compare_year)
+        }
+        Err(e) => {
+            eprintln!("Census API error for {} vintage: {}", compare_year, e);
+            None
+        }
+    }
+}
+
+fn census_error_response(request: census::AcsRequest, e: String) -> Json<CensusCountiesResponse> {
+    Json(CensusCountiesResponse {
+        updated: "error".to_string(),
+        source: format!("Census API Error: {}", e),
+        year: request.year,
+        compare_year: None,
+        total_counties: 0,
+        model_version: scoring::model().version.clone(),
+        data: vec![],
+    })
+}
+
 // Get all counties from Census API (cached)
-async fn get_census_counties() -> Json<CensusCountiesResponse> {
+async fn get_census_counties(Query(params): Query<CensusParams>) -> Result<Json<CensusCountiesResponse>, ApiError> {
+    let request = census_request(params.year, params.vars.as_deref())?;
     let api_key = std::env::var("CENSUS_API_KEY").ok();
     
-    match census::fetch_all_counties(api_key.as_deref()).await {
-        Ok(counties) => {
-            Json(CensusCountiesResponse {
+    match census::fetch_counties(request, api_key.as_deref()).await {
+        Ok(mut counties) => {
+            if params.explain.unwrap_or(false) {
+                counties.iter_mut().for_each(census::CountyCensusData::explain);
+            }
+            let compare_year = match params.year {
+                Some(_) => add_census_changes(&mut counties, request, api_key.as_deref()).await,
+                None => None,
+            };
+            Ok(Json(CensusCountiesResponse {
                 updated: chrono::Utc::now().to_rfc3339(),
-                source: "US Census Bureau ACS 2022".to_string(),
+                source: request.label(),
+                year: request.year,
+                compare_year,
                 total_counties: counties.len(),
+                model_version: scoring::model().version.clone(),
                 data: counties,
-            })
+            }))
         }
         Err(e) => {
             eprintln!("Census API error: {}", e);
-            Json(CensusCountiesResponse {
-                updated: "error".to_string(),
-                source: format!("Census API Error: {}", e),
-                total_counties: 0,
-                data: vec![],
-            })
+            Ok(census_error_response(request, e))
         }
     }
 }
 
 // Get counties for a specific state
-async fn get_census_state_counties(Path(state): Path<String>) -> Json<CensusCountiesResponse> {
+async fn get_census_state_counties(
+    Path(state): Path<String>,
+    Query(params): Query<CensusParams>,
+) -> Result<Json<CensusCountiesResponse>, ApiError> {
+    let request = census_request(params.year, params.vars.as_deref())?;
     let api_key = std::env::var("CENSUS_API_KEY").ok();
     
-    match census::fetch_state_counties(&state, api_key.as_deref()).await {
-        Ok(counties) => {
-            Json(CensusCountiesResponse {
+    match census::fetch_state_counties(&state, request, api_key.as_deref()).await {
+        Ok(mut counties) => {
+            if params.explain.unwrap_or(false) {
+                counties.iter_mut().for_each(census::CountyCensusData::explain);
+            }
+            let compare_year = match params.year {
+                Some(_) => add_census_changes(&mut counties, request, api_key.as_deref()).await,
+                None => None,
+            };
+            Ok(Json(CensusCountiesResponse {
                 updated: chrono::Utc::now().to_rfc3339(),
-                source: format!("US Census Bureau ACS 2022 - {}", state.to_uppercase()),
+                source: format!("{} - {}", request.label(), state.to_uppercase()),
+                year: request.year,
+                compare_year,
                 total_counties: counties.len(),
+                model_version: scoring::model().version.clone(),
                 data: counties,
-            })
+            }))
         }
         Err(e) => {
             eprintln!("Census API error for state {}: {}", state, e);
-            Json(CensusCountiesResponse {
-                updated: "error".to_string(),
-                source: format!("Census API Error: {}", e),
-                total_counties: 0,
-                data: vec![],
-            })
+            Ok(census_error_response(request, e))
         }
     }
 }
 
+#[derive(Deserialize)]
+struct AreaCensusParams {
+    year: Option<u16>,
+    vars: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct CensusTractsResponse {
+    updated: String,
+    source: String,
+    year: u16,
+    county_fips: String,
+    state: String,
+    total_tracts: usize,
+    data: Vec<census::AreaCensusData>,
+}
+
+// GET /api/census/tracts/:county_fips?year=&vars=core|extended - every tract in a county
+async fn get_census_tracts(
+    Path(county_fips): Path<String>,
+    Query(params): Query<AreaCensusParams>,
+) -> Result<Json<CensusTractsResponse>, ApiError> {
+    let county_fips = county_fips.trim().to_string();
+    let state = census::state_for_fips(&county_fips)
+        .filter(|_| county_fips.len() == 5 && county_fips.bytes().all(|b| b.is_ascii_digit()))
+        .ok_or_else(|| api_error(StatusCode::BAD_REQUEST, format!("'{}' is not a 5-digit county FIPS code", county_fips)))?;
+    let request = census_request(params.year, params.vars.as_deref())?;
+    let api_key = std::env::var("CENSUS_API_KEY").ok();
+    
+    let tracts = census::fetch_tracts(&county_fips, request, api_key.as_deref())
+        .await
+        .map_err(|e| api_error(StatusCode::BAD_GATEWAY, e))?;
+    if tracts.is_empty() {
+        return Err(api_error(StatusCode::NOT_FOUND, format!("Census has no tracts for county {}", county_fips)));
+    }
+    Ok(Json(CensusTractsResponse {
+        updated: chrono::Utc::now().to_rfc3339(),
+        source: format!("{} - census tracts", request.label()),
+        year: request.year,
+        county_fips,
+        state: state.to_string(),
+        total_tracts: tracts.len(),
+        data: tracts,
+    }))
+}
+
+#[derive(Debug, Serialize)]
+struct CensusZctaResponse {
+    updated: String,
+    source: String,
+    year: u16,
+    zip: String,
+    data: census::AreaCensusData,
+}
+
+// GET /api/census/zcta/:zip?year=&vars=core|extended - one ZIP Code Tabulation Area
+async fn get_census_zcta(
+    Path(zip): Path<String>,
+    Query(params): Query<AreaCensusParams>,
+) -> Result<Json<CensusZctaResponse>, ApiError> {
+    let zip = zip.trim().to_string();
+    if zip.len() != 5 || !zip.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(api_error(StatusCode::BAD_REQUEST, format!("'{}' is not a 5-digit ZIP code", zip)));
+    }
+    let request = census_request(params.year, params.vars.as_deref())?;
+    let api_key = std::env::var("CENSUS_API_KEY").ok();
+    
+    let area = census::fetch_zcta(&zip, request, api_key.as_deref())
+        .await
+        .map_err(|e| api_error(StatusCode::BAD_GATEWAY, e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, format!("Census has no ZCTA for ZIP {}", zip)))?;
+    Ok(Json(CensusZctaResponse {
+        updated: chrono::Utc::now().to_rfc3339(),
+        source: format!("{} - ZIP code tabulation areas", request.label()),
+        year: request.year,
+        zip,
+        data: area,
+    }))
+}
+
 // ============================================================================
 // FORECLOSURE DATA HANDLERS
 // ============================================================================
@@ -861,26 +1155,105 @@ async fn get_foreclosure_trends_handler() -> Json<foreclosure::NationalTrends> {
 #[derive(Debug, Serialize)]
 struct AuctionsResponse {
     updated: String,
-    total: usize,
-    auctions: Vec<auctions::AuctionListing>,
+    period: auction_store::Period, // which side of today the listing covers
+    total: usize,    // rows matching the filters across all pages
+    count: usize,    // rows in this page
+    next_cursor: Option<String>,
+    auctions: Vec<auctions::AuctionWithStatus>,
 }
 
-async fn get_all_auctions() -> Json<AuctionsResponse> {
-    let auction_list = auctions::get_upcoming_auctions();
-    Json(AuctionsResponse {
+fn query_auction_page(
+    state: &AppState,
+    query: &auction_store::AuctionQuery,
+) -> Result<Json<AuctionsResponse>, ApiError> {
+    query.validate().map_err(|e| api_error(StatusCode::BAD_REQUEST, e))?;
+    let page = state.auctions.query_auctions(query).map_err(internal_error)?;
+    let today = query.today();
+    Ok(Json(AuctionsResponse {
         updated: chrono::Utc::now().to_rfc3339(),
-        total: auction_list.len(),
-        auctions: auction_list,
-    })
+        period: query.period,
+        total: page.total_matched,
+        count: page.auctions.len(),
+        next_cursor: page.next_cursor,
+        auctions: page.auctions
+            .into_iter()
+            .map(|a| auctions::AuctionWithStatus::new(a, today))
+            .collect(),
+    }))
 }
 
-async fn get_state_auctions_handler(Path(state): Path<String>) -> Json<AuctionsResponse> {
-    let auction_list = auctions::get_state_auctions(&state);
-    Json(AuctionsResponse {
-        updated: chrono::Utc::now().to_rfc3339(),
-        total: auction_list.len(),
-        auctions: auction_list,
-    })
+// Past sales live under /history; an explicit status filter can reach any period
+fn upcoming_unless_status(query: &mut auction_store::AuctionQuery) {
+    if query.status.is_none() {
+        query.period = auction_store::Period::Upcoming;
+    }
+}
+
+// GET /api/auctions?sale_date_from=&sale_date_to=&sale_type=&auction_type=&platform=
+//     &min_deposit=&max_deposit=&min_properties=&q=&status=&sort=&order=&cursor=&limit=
+async fn get_all_auctions(
+    State(state): State<AppState>,
+    Query(mut query): Query<auction_store::AuctionQuery>,
+) -> Result<Json<AuctionsResponse>, ApiError> {
+    upcoming_unless_status(&mut query);
+    query_auction_page(&state, &query)
+}
+
+async fn get_state_auctions_handler(
+    State(state): State<AppState>,
+    Path(abbr): Path<String>,
+    Query(mut query): Query<auction_store::AuctionQuery>,
+) -> Result<Json<AuctionsResponse>, ApiError> {
+    query.state = Some(abbr);
+    upcoming_unless_status(&mut query);
+    query_auction_page(&state, &query)
+}
+
+// GET /api/auctions/history - sales whose date has passed, most recent first
+async fn get_auction_history(
+    State(state): State<AppState>,
+    Query(mut query): Query<auction_store::AuctionQuery>,
+) -> Result<Json<AuctionsResponse>, ApiError> {
+    query.period = auction_store::Period::Past;
+    if query.order.is_none() {
+        query.order = Some("desc".to_string());
+    }
+    query_auction_page(&state, &query)
+}
+
+#[derive(Deserialize)]
+struct CalendarParams {
+    state: Option<String>,
+    platform: Option<String>,
+    sale_type: Option<String>,
+}
+
+// GET /api/auctions/calendar.ics?state=&platform=&sale_type= (subscribable iCalendar feed)
+async fn get_auction_calendar(
+    State(state): State<AppState>,
+    Query(params): Query<CalendarParams>,
+) -> Result<Response, ApiError> {
+    let mut name = "Auction Intel - Tax Sales".to_string();
+    if let Some(abbr) = &params.state {
+        name = format!("{} ({})", name, abbr.to_uppercase());
+    }
+    
+    let query = auction_store::AuctionQuery {
+        state: params.state,
+        platform: params.platform,
+        sale_type: params.sale_type,
+        ..Default::default()
+    };
+    let listings = state.auctions.all_auctions(&query).map_err(internal_error)?;
+    
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+            (header::CONTENT_DISPOSITION, "inline; filename=\"auctions.ics\""),
+        ],
+        auction_calendar::build_calendar(&listings, &name),
+    )
+        .into_response())
 }
 
 #[derive(Debug, Serialize)]
@@ -888,10 +1261,10 @@ struct PlatformsResponse {
     platforms: Vec<auctions::AuctionPlatform>,
 }
 
-async fn get_auction_platforms() -> Json<PlatformsResponse> {
-    Json(PlatformsResponse {
-        platforms: auctions::get_platforms(),
-    })
+async fn get_auction_platforms(State(state): State<AppState>) -> Result<Json<PlatformsResponse>, ApiError> {
+    Ok(Json(PlatformsResponse {
+        platforms: state.auctions.list_platforms().map_err(internal_error)?,
+    }))
 }
 
 #[derive(Debug, Serialize)]
@@ -900,11 +1273,14 @@ struct ScheduleResponse {
     schedule: Option<auctions::StateAuctionSchedule>,
 }
 
-async fn get_state_schedule_handler(Path(state): Path<String>) -> Json<ScheduleResponse> {
-    Json(ScheduleResponse {
-        state: state.to_uppercase(),
-        schedule: auctions::get_state_schedule(&state),
-    })
+async fn get_state_schedule_handler(
+    State(state): State<AppState>,
+    Path(abbr): Path<String>,
+) -> Result<Json<ScheduleResponse>, ApiError> {
+    Ok(Json(ScheduleResponse {
+        state: abbr.to_uppercase(),
+        schedule: state.auctions.get_schedule(&abbr).map_err(internal_error)?,
+    }))
 }
 
 #[derive(Debug, Serialize)]
@@ -913,20 +1289,524 @@ struct AllSchedulesResponse {
     schedules: Vec<auctions::StateAuctionSchedule>,
 }
 
-async fn get_all_schedules_handler() -> Json<AllSchedulesResponse> {
-    let schedules = auctions::get_all_schedules();
-    Json(AllSchedulesResponse {
+async fn get_all_schedules_handler(State(state): State<AppState>) -> Result<Json<AllSchedulesResponse>, ApiError> {
+    let schedules = state.auctions.list_schedules().map_err(internal_error)?;
+    Ok(Json(AllSchedulesResponse {
         total: schedules.len(),
         schedules,
-    })
+    }))
+}
+
+// ============================================================================
+// CALCULATOR HANDLERS
+// ============================================================================
+
+// POST /api/calc/lien-return - interest, penalties and yield under the state's statute
+async fn lien_return_handler(
+    Json(input): Json<lien_calc::LienReturnInput>,
+) -> Result<Json<lien_calc::LienReturn>, ApiError> {
+    lien_calc::calculate(&input).map(Json).map_err(validation_error)
+}
+
+// POST /api/calc/lien-simulation - Monte Carlo redemption risk for a lien portfolio
+async fn lien_simulation_handler(
+    State(state): State<AppState>,
+    Json(input): Json<lien_simulator::SimulationInput>,
+) -> Result<Json<lien_simulator::SimulationResult>, ApiError> {
+    // Redemption defaults come from each state's schedule
+    let mut schedules = HashMap::new();
+    for lien in &input.liens {
+        let abbr = lien.state.trim().to_uppercase();
+        if schedules.contains_key(&abbr) {
+            continue;
+        }
+        if let Some(schedule) = state.auctions.get_schedule(&abbr).map_err(internal_error)? {
+            schedules.insert(abbr, schedule);
+        }
+    }
+    
+    tokio::task::spawn_blocking(move || lien_simulator::simulate(&input, &schedules))
+        .await
+        .map_err(|e| internal_error(format!("Simulation task failed: {}", e)))?
+        .map(Json)
+        .map_err(|e| match e {
+            lien_simulator::SimulationError::Invalid(errors) => validation_error(errors),
+            lien_simulator::SimulationError::HorizonTooLong(msg) => api_error(StatusCode::BAD_REQUEST, msg),
+        })
+}
+
+// "Miami-Dade County" and "miami-dade" name the same county
+fn county_key(name: &str) -> String {
+    name.trim().trim_end_matches(" County").trim_end_matches(" Parish").to_lowercase()
+}
+
+// ARV for the deed analysis: explicit value, then the county's ZHVI, then the static county table
+async fn resolve_arv(input: &deed_calc::DeedAnalysisInput) -> Result<(f64, String, Option<f64>), ApiError> {
+    if let Some(arv) = input.arv {
+        return Ok((arv, "Provided".to_string(), None));
+    }
+    let state = input.state.trim().to_uppercase();
+    let fips = input.fips.as_deref().map(str::trim).unwrap_or("");
+    let county = input.county.as_deref().map(county_key).unwrap_or_default();
+    
+    match fetch_zhvi_records().await {
+        Ok(records) => {
+            let found = records.iter().find(|r| {
+                if !fips.is_empty() {
+                    format!("{:0>2}{:0>3}", r.state_fips, r.county_fips) == fips
+                } else {
+                    r.state == state && county_key(&r.region_name) == county
+                }
+            });
+            if let Some(r) = found {
+                let source = format!("Zillow ZHVI ({}, {})", r.region_name, r.state);
+                return Ok((r.zhvi, source, Some(r.zhvi_change_yoy)));
+            }
+        }
+        Err(e) => eprintln!("{}", e),
+    }
+    
+    COUNTY_DATABASE
+        .get(&state)
+        .and_then(|counties| counties.iter().find(|c| county_key(&c.name) == county))
+        .map(|c| (c.zhvi as f64, format!("County database ZHVI ({}, {})", c.name, c.state), Some(c.growth as f64)))
+        .ok_or_else(|| validation_error(vec![format!(
+            "No ZHVI value found for county '{}' in {}; supply arv",
+            input.county.as_deref().or(input.fips.as_deref()).unwrap_or(""),
+            state
+        )]))
+}
+
+// POST /api/calc/deed-analysis - flip and rental underwriting for a deed purchase
+async fn deed_analysis_handler(
+    Json(input): Json<deed_calc::DeedAnalysisInput>,
+) -> Result<Json<deed_calc::DeedAnalysis>, ApiError> {
+    let mut errors = input.validate();
+    let statute = statutes::get(input.state.trim());
+    if statute.is_none() {
+        errors.push(format!("state '{}' is not a known jurisdiction", input.state));
+    }
+    if !errors.is_empty() {
+        return Err(validation_error(errors));
+    }
+    
+    let (arv, arv_source, zhvi_growth) = resolve_arv(&input).await?;
+    let (mortgage_rate_pct, rate_source) = match input.mortgage_rate_pct {
+        Some(rate) => (rate, "Provided".to_string()),
+        None => {
+            let rates = market_data::get_mortgage_rates().await;
+            (rates.rate_30yr, format!("{} 30-year fixed", rates.source))
+        }
+    };
+    let market = deed_calc::MarketInputs {
+        arv,
+        arv_source,
+        appreciation_pct: input
+            .appreciation_pct
+            .or(zhvi_growth)
+            .unwrap_or(deed_calc::DEFAULT_APPRECIATION_PCT),
+        mortgage_rate_pct,
+        rate_source,
+    };
+    
+    let mut analysis = deed_calc::analyze(&input, market);
+    if let Some(s) = statute.filter(|s| s.sale_kind != statutes::SaleKind::Deed) {
+        analysis.warnings.push(format!("{} sells tax liens; a deed is only acquired after foreclosure", s.state));
+    }
+    Ok(Json(analysis))
+}
+
+// ============================================================================
+// COUNTY ANALYSIS - AnalysisInput assembled from Census, Zillow and FRED by FIPS
+// ============================================================================
+
+#[derive(Debug, Serialize)]
+struct InputSource {
+    factor: &'static str,
+    value: Option<f64>,
+    // "live", "defaulted" (fallback or static source) or "missing"
+    status: &'static str,
+    source: String,
+}
+
+#[derive(Debug, Serialize)]
+struct CountyAnalysisResponse {
+    fips: String,
+    county: Option<String>,
+    state: String,
+    inputs: Vec<InputSource>,
+    missing: Vec<String>,
+    defaulted: Vec<String>,
+    analysis: AnalysisOutput,
+}
+
+// Live value if there is one, else the first fallback that has a value
+fn pick_input(
+    factor: &'static str,
+    live: Option<(f64, String)>,
+    fallbacks: Vec<(Option<f64>, String)>,
+) -> InputSource {
+    let (value, status, source) = match live {
+        Some((value, source)) => (Some(value), "live", source),
+        None => fallbacks
+            .into_iter()
+            .find_map(|(value, source)| value.map(|v| (Some(v), "defaulted", source)))
+            .unwrap_or((None, "missing", "No source available".to_string())),
+    };
+    InputSource { factor, value, status, source }
+}
+
+// GET /api/analyze/county/:fips - score a county from live data sources
+async fn analyze_county_by_fips(
+    Path(fips): Path<String>,
+    Query(params): Query<ProfileParams>,
+) -> Result<Json<CountyAnalysisResponse>, ApiError> {
+    let fips = fips.trim().to_string();
+    if fips.len() != 5 || !fips.chars().all(|c| c.is_ascii_digit()) {
+        return Err(api_error(StatusCode::BAD_REQUEST, format!("'{}' is not a 5-digit county FIPS code", fips)));
+    }
+    let profile = scoring_profile(params.profile.as_deref())?;
+    let state = census::state_for_fips(&fips)
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, format!("No state has FIPS prefix '{}'", &fips[..2])))?;
+    
+    let census_key = std::env::var("CENSUS_API_KEY").ok();
+    let fred_key = std::env::var("FRED_API_KEY").ok();
+    let (census_result, zhvi_result, unemployment) = tokio::join!(
+        census::fetch_all_counties(census_key.as_deref()),
+        fetch_zhvi_records(),
+        fred_api::fetch_county_unemployment(&fips, state, fred_key.as_deref()),
+    );
+    let census_county = census_result
+        .map_err(|e| eprintln!("Census API error: {}", e))
+        .ok()
+        .and_then(|counties| counties.into_iter().find(|c| c.fips == fips));
+    let zhvi = zhvi_result
+        .map_err(|e| eprintln!("{}", e))
+        .ok()
+        .and_then(|records| {
+            records.into_iter().find(|r| format!("{:0>2}{:0>3}", r.state_fips, r.county_fips) == fips)
+        });
+    
+    let county_name = census_county
+        .as_ref()
+        .map(|c| c.name.clone())
+        .or_else(|| zhvi.as_ref().map(|r| r.region_name.trim_end_matches(" County").to_string()));
+    let static_county = county_name.as_deref().and_then(|name| {
+        COUNTY_DATABASE
+            .get(state)
+            .and_then(|counties| counties.iter().find(|c| county_key(&c.name) == county_key(name)))
+    });
+    if census_county.is_none() && zhvi.is_none() && static_county.is_none() {
+        return Err(api_error(StatusCode::NOT_FOUND, format!("No data found for county FIPS {}", fips)));
+    }
+    
+    let census_value = |f: fn(&census::CountyCensusData) -> i64| {
+        census_county
+            .as_ref()
+            .map(f)
+            .filter(|v| *v > 0)
+            .map(|v| (v as f64, census::AcsRequest::default().label()))
+    };
+    let static_value = |f: fn(&CountyData) -> f64| (static_county.map(f), "County database (static)".to_string());
+    let employment = (
+        100.0 - unemployment.rate,
+        match (&unemployment.series_id, &unemployment.date) {
+            (Some(series), Some(date)) => format!("FRED {} ({} unemployment, {})", series, unemployment.level, date),
+            _ => format!("Fallback national unemployment {}%", unemployment.rate),
+        },
+    );
+    let (employment_live, employment_fallback) = if unemployment.level == "county" {
+        (Some(employment), vec![])
+    } else {
+        (None, vec![(Some(employment.0), employment.1)])
+    };
+    
+    let inputs = vec![
+        pick_input(scoring::POPULATION, census_value(|c| c.population), vec![static_value(|c| c.pop as f64)]),
+        pick_input(scoring::MEDIAN_INCOME, census_value(|c| c.median_income), vec![static_value(|c| c.income as f64)]),
+        pick_input(scoring::MEDIAN_HOME_VALUE, census_value(|c| c.median_home_value), vec![
+            (zhvi.as_ref().map(|r| r.zhvi), "Zillow ZHVI".to_string()),
+            static_value(|c| c.zhvi as f64),
+        ]),
+        pick_input(
+            scoring::GROWTH_YOY,
+            zhvi.as_ref().map(|r| (r.zhvi_change_yoy, "Zillow ZHVI year-over-year".to_string())),
+            vec![static_value(|c| c.growth as f64)],
+        ),
+        // No live feed for market activity yet
+        pick_input(scoring::DAYS_ON_MARKET, None, vec![static_value(|c| c.dom as f64)]),
+        pick_input(scoring::TRANSACTION_VOLUME, None, vec![]),
+        pick_input(scoring::EMPLOYMENT_RATE, employment_live, employment_fallback),
+    ];
+    
+    let values: scoring::FactorValues = inputs.iter().filter_map(|i| i.value.map(|v| (i.factor, v))).collect();
+    let by_status = |status: &str| {
+        inputs.iter().filter(|i| i.status == status).map(|i| i.factor.to_string()).collect::<Vec<_>>()
+    };
+    
+    Ok(Json(CountyAnalysisResponse {
+        county: county_name,
+        state: state.to_string(),
+        missing: by_status("missing"),
+        defaulted: by_status("defaulted"),
+        analysis: analysis_output(profile, &values),
+        inputs,
+        fips,
+    }))
+}
+
+// ============================================================================
+// ADMIN HANDLERS - Auction listing maintenance (bearer token auth)
+// ============================================================================
+
+// Admin routes are disabled unless ADMIN_API_TOKEN is set
+async fn require_admin(headers: HeaderMap, request: Request, next: Next) -> Result<Response, ApiError> {
+    let expected = match std::env::var("ADMIN_API_TOKEN") {
+        Ok(token) if !token.is_empty() => token,
+        _ => return Err(api_error(StatusCode::SERVICE_UNAVAILABLE, "Admin API is not configured")),
+    };
+    
+    let provided = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .unwrap_or("");
+    
+    // Constant-time comparison so the token can't be guessed byte by byte
+    let matches = provided.len() == expected.len()
+        && provided.bytes().zip(expected.bytes()).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0;
+    if !matches {
+        return Err(api_error(StatusCode::UNAUTHORIZED, "Missing or invalid admin token"));
+    }
+    
+    Ok(next.run(request).await)
+}
+
+// Normalize and validate a listing submitted for the given path id
+fn prepare_listing(
+    state: &AppState,
+    id: &str,
+    mut listing: auctions::AuctionListing,
+) -> Result<auctions::AuctionListing, ApiError> {
+    if !listing.id.is_empty() && listing.id != id {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            format!("Body id '{}' does not match path id '{}'", listing.id, id),
+        ));
+    }
+    listing.id = id.to_string();
+    auctions::normalize_listing(&mut listing);
+    
+    let platforms = state.auctions.platform_names().map_err(internal_error)?;
+    let errors = auctions::validate_listing(&listing, &platforms);
+    if !errors.is_empty() {
+        return Err(validation_error(errors));
+    }
+    Ok(listing)
+}
+
+fn not_found(id: &str) -> ApiError {
+    api_error(StatusCode::NOT_FOUND, format!("Auction {} not found", id))
+}
+
+// POST - create a new listing; 409 if the id is taken
+async fn create_auction_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(listing): Json<auctions::AuctionListing>,
+) -> Result<(StatusCode, Json<auctions::AuctionListing>), ApiError> {
+    let listing = prepare_listing(&state, &id, listing)?;
+    if !state.auctions.insert_auction(&listing).map_err(internal_error)? {
+        return Err(api_error(StatusCode::CONFLICT, format!("Auction {} already exists", id)));
+    }
+    Ok((StatusCode::CREATED, Json(listing)))
+}
+
+// PUT - create or fully replace a listing
+async fn replace_auction_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(listing): Json<auctions::AuctionListing>,
+) -> Result<(StatusCode, Json<auctions::AuctionListing>), ApiError> {
+    let listing = prepare_listing(&state, &id, listing)?;
+    let created = state.auctions.upsert_auction(&listing).map_err(internal_error)?;
+    let status = if created { StatusCode::CREATED } else { StatusCode::OK };
+    Ok((status, Json(listing)))
+}
+
+// PATCH - merge the supplied fields into an existing listing
+async fn patch_auction_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(patch): Json<serde_json::Value>,
+) -> Result<Json<auctions::AuctionListing>, ApiError> {
+    let existing = state.auctions.get_auction(&id)
+        .map_err(internal_error)?
+        .ok_or_else(|| not_found(&id))?;
+    
+    let fields = patch.as_object()
+        .ok_or_else(|| api_error(StatusCode::BAD_REQUEST, "PATCH body must be a JSON object"))?;
+    
+    let mut merged = serde_json::to_value(&existing).map_err(|e| internal_error(e.to_string()))?;
+    if let Some(target) = merged.as_object_mut() {
+        for (key, value) in fields {
+            if !target.contains_key(key) {
+                return Err(validation_error(vec![format!("Unknown field '{}'", key)]));
+            }
+            target.insert(key.clone(), value.clone());
+        }
+    }
+    
+    let listing: auctions::AuctionListing = serde_json::from_value(merged)
+        .map_err(|e| validation_error(vec![e.to_string()]))?;
+    let listing = prepare_listing(&state, &id, listing)?;
+    state.auctions.upsert_auction(&listing).map_err(internal_error)?;
+    Ok(Json(listing))
+}
+
+#[derive(Deserialize)]
+struct ImportParams {
+    format: Option<String>,
+    dry_run: Option<bool>,
+}
+
+// POST /api/admin/auctions/import?format=csv|json&dry_run=true
+async fn import_auctions_handler(
+    State(state): State<AppState>,
+    Query(params): Query<ImportParams>,
+    headers: HeaderMap,
+    body: String,
+) -> Result<Json<auction_import::ImportReport>, ApiError> {
+    let format = match params.format.as_deref() {
+        Some(f) => payload_format::PayloadFormat::parse(f)
+            .ok_or_else(|| api_error(StatusCode::BAD_REQUEST, format!("Unknown import format '{}'", f)))?,
+        None => payload_format::PayloadFormat::detect(
+            headers.get(header::CONTENT_TYPE).and_then(|v| v.to_str().ok()).unwrap_or(""),
+        ),
+    };
+    
+    auction_import::import_listings(state.auctions.as_ref(), format, &body, params.dry_run.unwrap_or(false))
+        .map(Json)
+        .map_err(|e| api_error(StatusCode::BAD_REQUEST, e))
+}
+
+async fn delete_auction_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    if state.auctions.delete_auction(&id).map_err(internal_error)? {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found(&id))
+    }
+}
+
+#[derive(Serialize)]
+struct JobsResponse {
+    // Cron expressions are evaluated in UTC
+    timezone: &'static str,
+    jobs: Vec<scheduler::JobStatus>,
+}
+
+// GET /api/admin/jobs - refresh schedules and recent runs
+async fn get_jobs_handler() -> Json<JobsResponse> {
+    Json(JobsResponse { timezone: "UTC", jobs: scheduler::statuses().await })
 }
 
 // ============================================================================
 // MAIN
 // ============================================================================
 
+fn open_auction_store() -> (String, auction_store::SqliteAuctionStore) {
+    let db_path = std::env::var("AUCTION_DB_PATH")
+        .unwrap_or_else(|_| auction_store::DEFAULT_DB_PATH.to_string());
+    let store = auction_store::SqliteAuctionStore::open(&db_path)
+        .unwrap_or_else(|e| panic!("Failed to initialize auction store: {}", e));
+    (db_path, store)
+}
+
+// CLI: auction-intel-backend import <file> [--dry-run] [--format csv|json]
+fn run_import_command(args: &[String]) -> Result<(), String> {
+    let mut file = None;
+    let mut dry_run = false;
+    let mut format = None;
+    
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--dry-run" => dry_run = true,
+            "--format" => {
+                let value = iter.next().ok_or("--format needs a value (csv or json)")?;
+                format = Some(payload_format::PayloadFormat::parse(value)
+                    .ok_or_else(|| format!("Unknown import format '{}'", value))?);
+            }
+            other if file.is_none() => file = Some(other.to_string()),
+            other => return Err(format!("Unexpected argument '{}'", other)),
+        }
+    }
+    
+    let file = file.ok_or("Usage: auction-intel-backend import <file> [--dry-run] [--format csv|json]")?;
+    let format = format.unwrap_or_else(|| payload_format::PayloadFormat::detect(&file));
+    let body = std::fs::read_to_string(&file).map_err(|e| format!("Failed to read {}: {}", file, e))?;
+    
+    let (_, store) = open_auction_store();
+    let report = auction_import::import_listings(&store, format, &body, dry_run)?;
+    println!("{}", serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?);
+    
+    if report.invalid > 0 {
+        return Err(format!("{} of {} rows failed validation", report.invalid, report.total_rows));
+    }
+    Ok(())
+}
+
+// CLI: auction-intel-backend backtest [zhvi.csv] [--as-of YYYY-MM-DD ...]
+fn run_backtest_command(args: &[String]) -> Result<(), String> {
+    let mut file = None;
+    let mut as_of = Vec::new();
+    
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--as-of" => {
+                let value = iter.next().ok_or("--as-of needs a date (YYYY-MM-DD)")?;
+                as_of.push(chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                    .map_err(|_| format!("'{}' is not a YYYY-MM-DD date", value))?);
+            }
+            other if file.is_none() => file = Some(other.to_string()),
+            other => return Err(format!("Unexpected argument '{}'", other)),
+        }
+    }
+    
+    let file = file.unwrap_or_else(|| backtest::DEFAULT_FIXTURE.to_string());
+    let csv_text = std::fs::read_to_string(&file).map_err(|e| format!("Failed to read {}: {}", file, e))?;
+    let history = zhvi_history::parse(&csv_text)?;
+    if as_of.is_empty() {
+        as_of = backtest::default_as_of(&history);
+    }
+    
+    let report = backtest::run(&history, &as_of)?;
+    println!("{}", serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?);
+    Ok(())
+}
+
+type CliCommand = fn(&[String]) -> Result<(), String>;
+
 #[tokio::main]
 async fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let command: Option<CliCommand> = match args.get(1).map(String::as_str) {
+        Some("import") => Some(run_import_command),
+        Some("backtest") => Some(run_backtest_command),
+        _ => None,
+    };
+    if let Some(command) = command {
+        if let Err(e) = command(&args[2..]) {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+    
     // Security: Restrict CORS to known domains only
     let allowed_origins = [
         "https://auction-intel.vercel.app".parse().unwrap(),
@@ -937,9 +1817,33 @@ async fn main() {
     
     let cors = CorsLayer::new()
         .allow_origin(AllowOrigin::list(allowed_origins))
-        .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
+        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::PATCH, Method::DELETE, Method::OPTIONS])
         .allow_headers([header::CONTENT_TYPE, header::ACCEPT, header::AUTHORIZATION]);
     
+    // Auction store (SQLite file; seeded from static data on first run)
+    let (db_path, store) = open_auction_store();
+    println!("🗄️  Auction store: {}", db_path);
+    println!("📐 Scoring model: {}", scoring::model().version);
+    
+    // Background refresh of Census, FRED, Zillow and Redfin data
+    scheduler::start();
+    
+    let state = AppState {
+        auctions: Arc::new(store),
+    };
+    
+    let admin = Router::new()
+        .route(
+            "/api/admin/auctions/:id",
+            post(create_auction_handler)
+                .put(replace_auction_handler)
+                .patch(patch_auction_handler)
+                .delete(delete_auction_handler),
+        )
+        .route("/api/admin/auctions/import", post(import_auctions_handler))
+        .route("/api/admin/jobs", get(get_jobs_handler))
+        .route_layer(middleware::from_fn(require_admin));
+    
     let app = Router::new()
         .route("/api/health", get(health))
         .route("/api/states", get(get_states))
@@ -948,19 +1852,33 @@ async fn main() {
         .route("/api/counties", get(get_counties))
         .route("/api/census/counties", get(get_census_counties))
         .route("/api/census/counties/:state", get(get_census_state_counties))
+        .route("/api/census/tracts/:county_fips", get(get_census_tracts))
+        .route("/api/census/zcta/:zip", get(get_census_zcta))
         .route("/api/foreclosures", get(get_foreclosure_stats_handler))
         .route("/api/foreclosures/:state", get(get_state_foreclosures_handler))
         .route("/api/foreclosures/trends", get(get_foreclosure_trends_handler))
         .route("/api/auctions", get(get_all_auctions))
         .route("/api/auctions/:state", get(get_state_auctions_handler))
         .route("/api/auctions/platforms", get(get_auction_platforms))
+        .route("/api/auctions/calendar.ics", get(get_auction_calendar))
+        .route("/api/auctions/history", get(get_auction_history))
         .route("/api/auctions/schedules", get(get_all_schedules_handler))
         .route("/api/auctions/schedule/:state", get(get_state_schedule_handler))
         .route("/api/analyze", post(analyze_county))
+        .route("/api/analyze/batch", post(analyze_batch_handler))
+        .route("/api/analyze/county/:fips", get(analyze_county_by_fips))
+        .route("/api/calc/lien-return", post(lien_return_handler))
+        .route("/api/calc/deed-analysis", post(deed_analysis_handler))
+        .route("/api/calc/lien-simulation", post(lien_simulation_handler))
         .route("/api/zillow/zhvi", get(get_zillow_zhvi))
+        .route("/api/zillow/zhvi/:fips/history", get(get_zhvi_history))
         .route("/api/redfin/market", get(get_redfin_market))
+        .route("/api/market", get(get_market))
         .route("/api/rates", get(get_rates))
-        .layer(cors);
+        .route("/api/fred/series/:id", get(get_fred_series))
+        .merge(admin)
+        .layer(cors)
+        .with_state(state);
     
     println!("🚀 Auction Intel Backend running on http://0.0.0.0:8080");
     println!("📊 Endpoints:");
@@ -968,11 +1886,27 @@ async fn main() {
     println!("   GET  /api/states");
     println!("   GET  /api/state-info");
     println!("   GET  /api/state-info/:abbr");
-    println!("   GET  /api/counties?state=XX");
-    println!("   POST /api/analyze");
+    println!("   GET  /api/counties?state=XX&profile=");
+    println!("   GET  /api/census/tracts/:county_fips?year=&vars=core|extended");
+    println!("   GET  /api/census/zcta/:zip?year=&vars=core|extended");
+    println!("   POST /api/analyze?profile=lien-yield|deed-flip|buy-and-hold");
+    println!("   POST /api/analyze/batch?format=csv|json");
+    println!("   GET  /api/analyze/county/:fips?profile=");
+    println!("   POST /api/calc/lien-return");
+    println!("   POST /api/calc/deed-analysis");
+    println!("   POST /api/calc/lien-simulation");
     println!("   GET  /api/zillow/zhvi");
-    println!("   GET  /api/redfin/market");
+    println!("   GET  /api/zillow/zhvi/:fips/history");
+    println!("   GET  /api/redfin/market?region=&region_type=county|metro");
+    println!("   GET  /api/market");
     println!("   GET  /api/rates");
+    println!("   GET  /api/fred/series/:id?start=&end=&frequency=&units=");
+    println!("   GET  /api/auctions?status=&sale_date_from=&q=&sort=&cursor=");
+    println!("   GET  /api/auctions/history");
+    println!("   GET  /api/auctions/calendar.ics?state=&platform=&sale_type=");
+    println!("   POST/PUT/PATCH/DELETE /api/admin/auctions/:id (Bearer ADMIN_API_TOKEN)");
+    println!("   POST /api/admin/auctions/import?format=csv|json&dry_run=true");
+    println!("   GET  /api/admin/jobs");
     
     // Use PORT env var (Railway sets this) or default to 8080
     let port = std::env::var("PORT").unwrap_or_else(|_| "8080".to_string());
@@ -982,3 +1916,103 @@ async fn main() {
     let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
     axum::serve(listener, app).await.unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // /api/state-info before the typed statutes: (state, type, notes)
+    const PREVIOUS_STATE_INFO: [(&str, &str, &str); 51] = [
+        ("AK", "Deed", "Municipal foreclosure; deeded to borough/city if unredeemed"),
+        ("AL", "Lien", "Most sales May-June; 12% interest from date of sale"),
+        ("AR", "Deed", "Forfeited to state; limited warranty deed after 30 days"),
+        ("AZ", "Lien", "Bid-down process; max 16% simple interest"),
+        ("CA", "Deed", "Tax Collector's Deed; free of pre-existing encumbrances"),
+        ("CO", "Lien", "Premium bidding; no premium reimbursement"),
+        ("CT", "Lien", "Combined lien/deed format; larger towns only"),
+        ("DC", "Lien", "Premium bidding; no interest on overbid"),
+        ("DE", "Deed", "Judicial foreclosure; 15% penalty on redemption"),
+        ("FL", "Lien", "Bid-down; guaranteed 5% minimum return"),
+        ("GA", "Lien", "20% year 1; escalates to 30% after 2yrs, 40% after 3yrs"),
+        ("HI", "Deed", "3-year lien before auction; 1-year redemption after sale"),
+        ("IA", "Lien", "Bid least undivided ownership interest; highest rate"),
+        ("ID", "Deed", "Tax deed to county after 3 years; then sold at auction"),
+        ("IL", "Lien", "Bid-down from 18%; graduated penalty redemption"),
+        ("IN", "Lien", "A/B/C Sales process; Commissioner's Sale for county-titled"),
+        ("KS", "Deed", "Bid off to county; court petition for foreclosure"),
+        ("KY", "Lien", "12% interest from date of issuance"),
+        ("LA", "Lien", "2024-2025 reform: bid-down interest system; online now available"),
+        ("MA", "Lien", "Smallest undivided part auction; 16% rate"),
+        ("MD", "Lien", "Statutory 6% but most counties charge 18-24%"),
+        ("ME", "Deed", "Tax lien mortgage auto-forecloses after 18 months"),
+        ("MI", "Deed", "Forfeit lands; auction 3rd Tuesday July; min bid = taxes + FMV"),
+        ("MN", "Deed", "Tax-forfeited land auctions; cash or installment"),
+        ("MO", "Lien", "10% interest; 18% penalty each year delinquent"),
+        ("MS", "Lien", "Tax lien state"),
+        ("MT", "Lien", "5/6 of 1% per month (10% per annum)"),
+        ("NC", "Deed", "Judicial foreclosure or docketing certificate"),
+        ("ND", "Deed", "Bid-down from 9%; 4yr redemption from due date"),
+        ("NE", "Lien", "Undivided interest at 14% per annum"),
+        ("NH", "Lien", "Auction for percentage of undivided interest"),
+        ("NJ", "Lien", "Bid-down from 18%; active market"),
+        ("NM", "Deed", "No owner redemption; Quitclaim Deed issued"),
+        ("NV", "Deed", "Tax deed to Treasurer after 2yr; then auction"),
+        ("NY", "Deed", "2yr standard; 3-4yr for residential/farm; judicial foreclosure"),
+        ("OH", "Deed", "Judicial foreclosure after 2yr delinquent; Sheriff's sale"),
+        ("OK", "Lien", "Multiple bidders decided by random drawing"),
+        ("OR", "Deed", "Foreclosure after 3yr; sold to county; 2yr redemption"),
+        ("PA", "Deed", "Upset Sale; min bid = taxes + interest + costs"),
+        ("RI", "Lien", "Collector's Deed; 10% first 6mo, 1%/mo after"),
+        ("SC", "Lien", "Highest and best bidder wins"),
+        ("SD", "Lien", "Bid-down from 10%; 12% statutory"),
+        ("TN", "Deed", "2yr delinquent before Chancery Court suit"),
+        ("TX", "Deed", "6mo non-Homestead; 2yr Homestead/Ag; 25% penalty"),
+        ("UT", "Deed", "Preliminary sale Jan 16; final sale May 4yr later"),
+        ("VA", "Deed", "Judicial foreclosure; 3yr after due date; surplus to former owner"),
+        ("VT", "Deed", "Foreclosure after 2yr; Collector's Deed after 1yr redemption"),
+        ("WA", "Deed", "Certificate of delinquency after 3yr; foreclosure judgment"),
+        ("WI", "Deed", "Tax deed after 2yr certificate; county cannot sell certificate"),
+        ("WV", "Lien", "Highest bidder at public auction"),
+        ("WY", "Lien", "Longest redemption; 15% + 3% penalty + fees"),
+    ];
+
+    // States whose statute was corrected; their notes describe the new rule
+    const CORRECTED: [&str; 8] = ["CA", "GA", "IN", "LA", "MA", "NM", "SC", "VT"];
+
+    #[test]
+    fn state_info_renders_the_schedule_labels() {
+        assert_eq!(STATE_AUCTION_DATA.len(), 51);
+        let schedules = auctions::seed_schedules();
+        for (state, sale_type, notes) in PREVIOUS_STATE_INFO {
+            let info = &STATE_AUCTION_DATA[state];
+            let schedule = schedules.iter().find(|s| s.state == state).unwrap();
+            assert_eq!(info.sale_type, sale_type, "{}", state);
+            assert_eq!(schedule.sale_type, format!("Tax {}", sale_type), "{}", state);
+            assert_eq!(info.interest_rate, schedule.interest_rate, "{}", state);
+            assert_eq!(info.redemption_period, schedule.redemption_period, "{}", state);
+            assert_ne!(info.name, state, "{} has no display name", state);
+            if !CORRECTED.contains(&state) {
+                assert_eq!(info.notes, notes, "{}", state);
+            }
+        }
+    }
+
+    #[test]
+    fn census_request_validates_year_and_variables() {
+        let request = census_request(None, None).unwrap();
+        assert_eq!((request.year, request.variables), (census::DEFAULT_VINTAGE, census::VariableSet::Core));
+        let request = census_request(Some(2019), Some("extended")).unwrap();
+        assert_eq!((request.year, request.variables), (2019, census::VariableSet::Extended));
+
+        let (status, Json(body)) = census_request(Some(2030), None).unwrap_err();
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body.details, ["year must be an ACS 5-year vintage from 2019 to 2023, got 2030"]);
+
+        // Every problem is reported at once
+        let (_, Json(body)) = census_request(Some(1999), Some("everything")).unwrap_err();
+        assert_eq!(body.details, [
+            "vars must be core or extended, got 'everything'",
+            "year must be an ACS 5-year vintage from 2019 to 2023, got 1999",
+        ]);
+    }
+}
@@ -4,8 +4,10 @@
 
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use once_cell::sync::Lazy;
+use std::time::Duration;
+
+use crate::data_cache;
+use crate::fred_api::{self, Reading};
 
 // ============================================================================
 // DATA STRUCTURES
@@ -15,7 +17,6 @@ use once_cell::sync::Lazy;
 pub struct MortgageRates {
     pub rate_30yr: f64,
     pub rate_15yr: f64,
-    pub rate_5yr_arm: f64,
     pub change_30yr: f64,  // Week-over-week change
     pub change_15yr: f64,
     pub updated: String,
@@ -24,12 +25,16 @@ pub struct MortgageRates {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EconomicIndicator {
+    pub series_id: String,
     pub name: String,
     pub value: f64,
     pub unit: String,
     pub change: f64,
     pub trend: String,  // "up", "down", "stable"
-    pub updated: String,
+    pub updated: Option<String>,  // Observation date; None when FRED was unavailable
+    pub provenance: fred_api::Provenance,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,103 +53,88 @@ pub struct HousingStats {
     pub days_on_market: i32,
 }
 
-// Cache for market data
-pub struct MarketDataCache {
-    data: RwLock<Option<MarketData>>,
-    last_fetch: RwLock<Option<chrono::DateTime<chrono::Utc>>>,
-}
-
-impl MarketDataCache {
-    pub fn new() -> Self {
-        MarketDataCache {
-            data: RwLock::new(None),
-            last_fetch: RwLock::new(None),
-        }
-    }
-}
-
-static MARKET_CACHE: Lazy<Arc<MarketDataCache>> = Lazy::new(|| Arc::new(MarketDataCache::new()));
+// Refresh every 15 minutes; for an hour past that serve the last snapshot while refreshing
+const MARKET_POLICY: data_cache::Policy = data_cache::Policy {
+    ttl: Duration::from_secs(15 * 60),
+    stale_while_revalidate: Duration::from_secs(3600),
+};
 
 // ============================================================================
-// CURRENT MARKET DATA - January 2026
-// Based on FRED data series: MORTGAGE30US, MORTGAGE15US, FEDFUNDS, CPIAUCSL
+// FRED-BACKED MARKET DATA
+// Rates and headline indicators come from fred_api::fetch_live_rates; housing stats
+// and sentiment are fetched here. A failed fetch serves the last good value, then a constant.
 // ============================================================================
 
-fn get_current_rates() -> MortgageRates {
-    let today = chrono::Utc::now().format("%Y-%m-%d %H:%M UTC").to_string();
-    
-    MortgageRates {
-        rate_30yr: 6.62,      // FRED: MORTGAGE30US
-        rate_15yr: 5.89,      // FRED: MORTGAGE15US  
-        rate_5yr_arm: 6.08,   // 5/1 ARM rate
-        change_30yr: -0.04,   // Week-over-week change
-        change_15yr: -0.02,
-        updated: today,
-        source: "Federal Reserve (FRED)".to_string(),
+const FALLBACK_CONSUMER_SENTIMENT: f64 = 53.6;
+const FALLBACK_MEDIAN_HOME_PRICE: f64 = 417700.0;
+const FALLBACK_PRICE_YOY: f64 = 4.2;
+const FALLBACK_MONTHS_SUPPLY: f64 = 3.8;
+const FALLBACK_DAYS_ON_MARKET: f64 = 62.0;
+
+// A change within 0.5% of the value is reported as stable
+fn trend(reading: &Reading) -> &'static str {
+    let band = reading.value.abs() * 0.005;
+    if reading.change > band {
+        "up"
+    } else if reading.change < -band {
+        "down"
+    } else {
+        "stable"
     }
 }
 
-fn get_economic_indicators() -> Vec<EconomicIndicator> {
-    let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
-    
-    vec![
-        EconomicIndicator {
-            name: "Federal Funds Rate".to_string(),
-            value: 4.33,
-            unit: "%".to_string(),
-            change: 0.0,
-            trend: "stable".to_string(),
-            updated: today.clone(),
-        },
-        EconomicIndicator {
-            name: "Inflation Rate (CPI)".to_string(),
-            value: 2.9,
-            unit: "%".to_string(),
-            change: -0.1,
-            trend: "down".to_string(),
-            updated: today.clone(),
-        },
-        EconomicIndicator {
-            name: "Unemployment Rate".to_string(),
-            value: 4.1,
-            unit: "%".to_string(),
-            change: 0.0,
-            trend: "stable".to_string(),
-            updated: today.clone(),
-        },
-        EconomicIndicator {
-            name: "Housing Starts".to_string(),
-            value: 1.499,
-            unit: "M units".to_string(),
-            change: 0.03,
-            trend: "up".to_string(),
-            updated: today.clone(),
-        },
-        EconomicIndicator {
-            name: "10-Year Treasury".to_string(),
-            value: 4.68,
-            unit: "%".to_string(),
-            change: 0.05,
-            trend: "up".to_string(),
-            updated: today.clone(),
-        },
-        EconomicIndicator {
-            name: "Consumer Confidence".to_string(),
-            value: 104.7,
-            unit: "index".to_string(),
-            change: 2.3,
-            trend: "up".to_string(),
-            updated: today.clone(),
-        },
-    ]
+fn indicator(series_id: &str, name: &str, reading: &Reading) -> EconomicIndicator {
+    let unit = fred_api::get_indicator_metadata().get(series_id).map_or("", |(_, unit)| *unit);
+    EconomicIndicator {
+        series_id: series_id.to_string(),
+        name: name.to_string(),
+        value: reading.value,
+        unit: unit.to_string(),
+        change: reading.change,
+        trend: trend(reading).to_string(),
+        updated: reading.date.clone(),
+        provenance: reading.provenance,
+        error: reading.error.clone(),
+    }
 }
 
-fn get_housing_stats() -> HousingStats {
-    HousingStats {
-        median_home_price: 417700.0,  // NAR median existing home price
-        yoy_change: 4.2,              // Year-over-year change %
-        inventory_months: 3.8,        // Months of supply
-        days_on_market: 62,           // Average DOM
+async fn fetch_market_data() -> MarketData {
+    let api_key = std::env::var("FRED_API_KEY").ok();
+    let key = api_key.as_deref();
+    let reading = |series_id, units, fallback| fred_api::fetch_reading_or(series_id, units, key, Reading::fallback(fallback, 0.0));
+    let (rates, sentiment, price, price_yoy, supply, days) = tokio::join!(
+        fred_api::fetch_live_rates(key),
+        reading(fred_api::SERIES_CONSUMER_SENTIMENT, "lin", FALLBACK_CONSUMER_SENTIMENT),
+        reading(fred_api::SERIES_MEDIAN_SALE_PRICE, "lin", FALLBACK_MEDIAN_HOME_PRICE),
+        reading(fred_api::SERIES_MEDIAN_SALE_PRICE, "pc1", FALLBACK_PRICE_YOY),
+        reading(fred_api::SERIES_MONTHS_SUPPLY, "lin", FALLBACK_MONTHS_SUPPLY),
+        reading(fred_api::SERIES_DAYS_ON_MARKET, "lin", FALLBACK_DAYS_ON_MARKET),
+    );
+
+    MarketData {
+        mortgage_rates: MortgageRates {
+            rate_30yr: rates.mortgage_30yr.value,
+            rate_15yr: rates.mortgage_15yr.value,
+            change_30yr: rates.mortgage_30yr.change,
+            change_15yr: rates.mortgage_15yr.change,
+            updated: rates.updated.clone(),
+            source: rates.source.clone(),
+        },
+        indicators: vec![
+            indicator(fred_api::SERIES_FED_FUNDS, "Federal Funds Rate", &rates.fed_funds),
+            indicator(fred_api::SERIES_CPI, "Inflation Rate (CPI)", &rates.cpi_yoy),
+            indicator(fred_api::SERIES_UNEMPLOYMENT, "Unemployment Rate", &rates.unemployment),
+            indicator(fred_api::SERIES_HOUSING_STARTS, "Housing Starts", &rates.housing_starts),
+            indicator(fred_api::SERIES_TREASURY_10YR, "10-Year Treasury", &rates.treasury_10yr),
+            indicator(fred_api::SERIES_CONSUMER_SENTIMENT, "Consumer Sentiment", &sentiment),
+        ],
+        housing_stats: HousingStats {
+            median_home_price: price.value,
+            yoy_change: (price_yoy.value * 10.0).round() / 10.0,
+            inventory_months: supply.value,
+            days_on_market: days.value.round() as i32,
+        },
+        timestamp: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
     }
 }
 
@@ -153,42 +143,36 @@ fn get_housing_stats() -> HousingStats {
 // ============================================================================
 
 pub async fn get_market_data() -> MarketData {
-    // Check cache first (refresh every 15 minutes)
-    {
-        let last_fetch = MARKET_CACHE.last_fetch.read().await;
-        let data = MARKET_CACHE.data.read().await;
-        
-        if let (Some(last), Some(cached_data)) = (last_fetch.as_ref(), data.as_ref()) {
-            let elapsed = chrono::Utc::now() - *last;
-            if elapsed.num_minutes() < 15 {
-                return cached_data.clone();
-            }
-        }
+    let cached = data_cache::shared()
+        .get_or_fetch("market", "snapshot", MARKET_POLICY, |_| async { Ok(Arc::new(fetch_market_data().await)) })
+        .await;
+    match cached {
+        Ok(cached) => (*cached.value).clone(),
+        // Unreachable in practice: fetch_market_data always answers, with fallbacks if need be
+        Err(_) => fetch_market_data().await,
     }
-    
-    // Generate fresh data
-    let market_data = MarketData {
-        mortgage_rates: get_current_rates(),
-        indicators: get_economic_indicators(),
-        housing_stats: get_housing_stats(),
-        timestamp: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
-    };
-    
-    // Update cache
-    {
-        let mut data = MARKET_CACHE.data.write().await;
-        let mut last_fetch = MARKET_CACHE.last_fetch.write().await;
-        *data = Some(market_data.clone());
-        *last_fetch = Some(chrono::Utc::now());
-    }
-    
-    market_data
 }
 
 pub async fn get_mortgage_rates() -> MortgageRates {
-    get_current_rates()
+    get_market_data().await.mortgage_rates
 }
 
-pub async fn get_indicators() -> Vec<EconomicIndicator> {
-    get_economic_indicators()
+// Refetch the snapshot now (scheduled refreshes); fails when no indicator came back live
+pub async fn refresh() -> Result<String, String> {
+    let cached = data_cache::shared()
+        .refresh("market", "snapshot", |_| async {
+            let data = fetch_market_data().await;
+            if data.indicators.iter().any(|i| i.provenance == fred_api::Provenance::Live) {
+                return Ok(Arc::new(data));
+            }
+            let errors: Vec<String> = data
+                .indicators
+                .iter()
+                .filter_map(|i| i.error.as_ref().map(|e| format!("{}: {}", i.series_id, e)))
+                .collect();
+            Err(if errors.is_empty() { "FRED_API_KEY is not set".to_string() } else { errors.join("; ") })
+        })
+        .await?;
+    let live = cached.value.indicators.iter().filter(|i| i.provenance == fred_api::Provenance::Live).count();
+    Ok(format!("{} of {} indicators live ({})", live, cached.value.indicators.len(), cached.value.mortgage_rates.source))
 }
@@ -0,0 +1,44 @@
+// Payload Format - CSV or JSON request and response bodies
+// Shared by the auction import and batch scoring endpoints
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PayloadFormat {
+    Csv,
+    Json,
+}
+
+impl PayloadFormat {
+    pub fn parse(value: &str) -> Option<PayloadFormat> {
+        match value.to_lowercase().as_str() {
+            "csv" => Some(PayloadFormat::Csv),
+            "json" => Some(PayloadFormat::Json),
+            _ => None,
+        }
+    }
+
+    // Best guess from a Content-Type header or file extension
+    pub fn detect(hint: &str) -> PayloadFormat {
+        let hint = hint.to_lowercase();
+        if hint.contains("csv") || hint.ends_with(".txt") {
+            PayloadFormat::Csv
+        } else {
+            PayloadFormat::Json
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_detects() {
+        assert_eq!(PayloadFormat::parse("CSV"), Some(PayloadFormat::Csv));
+        assert_eq!(PayloadFormat::parse("json"), Some(PayloadFormat::Json));
+        assert_eq!(PayloadFormat::parse("xml"), None);
+        assert_eq!(PayloadFormat::detect("text/csv; charset=utf-8"), PayloadFormat::Csv);
+        assert_eq!(PayloadFormat::detect("calendar.txt"), PayloadFormat::Csv);
+        assert_eq!(PayloadFormat::detect("application/json"), PayloadFormat::Json);
+        assert_eq!(PayloadFormat::detect(""), PayloadFormat::Json);
+    }
+}
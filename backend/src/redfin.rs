@@ -0,0 +1,360 @@
+// Redfin Market Tracker - County and metro housing activity from Redfin's Data Center
+// The gzipped TSVs are streamed to disk, then gunzipped and parsed row by row so the
+// multi-GB uncompressed files never sit in memory; only each region's latest period is kept
+
+use flate2::read::MultiGzDecoder;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{Mutex, RwLock};
+
+const TRACKER_BASE: &str = "https://redfin-public-data.s3.us-west-2.amazonaws.com/redfin_market_tracker";
+
+// Redfin refreshes the tracker weekly
+const REFRESH_AFTER: Duration = Duration::from_secs(24 * 3600);
+
+// After a failed download the stale copy is served this long before trying again
+const RETRY_AFTER: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrackerLevel {
+    County,
+    Metro,
+}
+
+impl TrackerLevel {
+    pub const ALL: [TrackerLevel; 2] = [TrackerLevel::County, TrackerLevel::Metro];
+
+    fn url(&self) -> String {
+        match self {
+            TrackerLevel::County => format!("{}/county_market_tracker.tsv000.gz", TRACKER_BASE),
+            TrackerLevel::Metro => format!("{}/redfin_metro_market_tracker.tsv000.gz", TRACKER_BASE),
+        }
+    }
+
+    fn cache_file(&self) -> &'static str {
+        match self {
+            TrackerLevel::County => "redfin_county.tsv.gz",
+            TrackerLevel::Metro => "redfin_metro.tsv.gz",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct RedfRecord {
+    pub region: String,
+    pub region_type: String,
+    pub state_code: String,
+    pub period_end: String,
+    pub median_dom: Option<f32>,
+    // Average sale price over list price (0.98 = 2% under list)
+    pub sale_to_list: Option<f32>,
+    pub inventory: Option<u32>,
+    // Share of active listings with a price drop, in percent
+    pub price_drop_pct: Option<f32>,
+    pub homes_sold: Option<u32>,
+}
+
+// ============================================================================
+// PARSING
+// ============================================================================
+
+// Column positions, looked up by (case-insensitive) header name
+struct Columns {
+    period_end: usize,
+    region_type: usize,
+    region: usize,
+    state_code: usize,
+    property_type: usize,
+    seasonally_adjusted: Option<usize>,
+    median_dom: Option<usize>,
+    sale_to_list: Option<usize>,
+    inventory: Option<usize>,
+    price_drops: Option<usize>,
+    homes_sold: Option<usize>,
+}
+
+impl Columns {
+    fn from_headers(headers: &csv::StringRecord) -> Result<Columns, String> {
+        let find = |name: &str| headers.iter().position(|h| h.trim().eq_ignore_ascii_case(name));
+        let require = |name: &str| find(name).ok_or_else(|| format!("Redfin TSV is missing the {} column", name));
+        Ok(Columns {
+            period_end: require("period_end")?,
+            region_type: require("region_type")?,
+            region: require("region")?,
+            state_code: require("state_code")?,
+            property_type: require("property_type")?,
+            seasonally_adjusted: find("is_seasonally_adjusted"),
+            median_dom: find("median_dom"),
+            sale_to_list: find("avg_sale_to_list"),
+            inventory: find("inventory"),
+            price_drops: find("price_drops"),
+            homes_sold: find("homes_sold"),
+        })
+    }
+}
+
+fn cell(record: &csv::StringRecord, col: usize) -> &str {
+    record.get(col).unwrap_or("").trim()
+}
+
+fn number(record: &csv::StringRecord, col: Option<usize>) -> Option<f64> {
+    let text = cell(record, col?);
+    if text.is_empty() || text.eq_ignore_ascii_case("NA") {
+        return None;
+    }
+    text.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+// Latest "All Residential", non-seasonally-adjusted row per region from an uncompressed TSV stream
+pub fn parse_tracker<R: Read>(reader: R) -> Result<Vec<RedfRecord>, String> {
+    let mut tsv = csv::ReaderBuilder::new()
+        .delimiter(b'\t')
+        .flexible(true)
+        .from_reader(reader);
+    let headers = tsv.headers().map_err(|e| format!("Invalid Redfin TSV header: {}", e))?.clone();
+    let cols = Columns::from_headers(&headers)?;
+
+    let mut latest: HashMap<(String, String), RedfRecord> = HashMap::new();
+    for record in tsv.records() {
+        let record = record.map_err(|e| format!("Unreadable Redfin row: {}", e))?;
+        if !cell(&record, cols.property_type).eq_ignore_ascii_case("All Residential") {
+            continue;
+        }
+        if cols.seasonally_adjusted.is_some_and(|c| cell(&record, c).eq_ignore_ascii_case("true")) {
+            continue;
+        }
+        let period_end = cell(&record, cols.period_end);
+        let key = (cell(&record, cols.region_type).to_string(), cell(&record, cols.region).to_string());
+        if latest.get(&key).is_some_and(|r| r.period_end.as_str() >= period_end) {
+            continue;
+        }
+        latest.insert(
+            key.clone(),
+            RedfRecord {
+                region: key.1,
+                region_type: key.0,
+                state_code: cell(&record, cols.state_code).to_string(),
+                period_end: period_end.to_string(),
+                median_dom: number(&record, cols.median_dom).map(|v| v as f32),
+                sale_to_list: number(&record, cols.sale_to_list).map(|v| ((v * 1000.0).round() / 1000.0) as f32),
+                inventory: number(&record, cols.inventory).map(|v| v as u32),
+                price_drop_pct: number(&record, cols.price_drops).map(|v| ((v * 1000.0).round() / 10.0) as f32),
+                homes_sold: number(&record, cols.homes_sold).map(|v| v as u32),
+            },
+        );
+    }
+
+    let mut records: Vec<RedfRecord> = latest.into_values().collect();
+    records.sort_by(|a, b| (&a.region_type, &a.region).cmp(&(&b.region_type, &b.region)));
+    Ok(records)
+}
+
+pub fn parse_gzip_file(path: &Path) -> Result<Vec<RedfRecord>, String> {
+    let file = std::fs::File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    parse_tracker(MultiGzDecoder::new(std::io::BufReader::new(file)))
+}
+
+// State code, or case-insensitive part of the region name ("Travis County", "austin")
+pub fn matches_region(record: &RedfRecord, query: &str) -> bool {
+    let query = query.trim();
+    if query.len() == 2 && record.state_code.eq_ignore_ascii_case(query) {
+        return true;
+    }
+    record.region.to_lowercase().contains(&query.to_lowercase())
+}
+
+// ============================================================================
+// DOWNLOAD + CACHE
+// ============================================================================
+
+pub struct TrackerData {
+    pub records: Vec<RedfRecord>,
+    // When the underlying file was downloaded
+    pub downloaded: SystemTime,
+    // True when the download failed and an older file was used
+    pub stale: bool,
+}
+
+struct CacheEntry {
+    data: std::sync::Arc<TrackerData>,
+    expires: Instant,
+}
+
+static CACHE: Lazy<RwLock<HashMap<&'static str, CacheEntry>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+// One download per level at a time; readers keep the current entry meanwhile
+static RELOADS: Lazy<HashMap<&'static str, Mutex<()>>> =
+    Lazy::new(|| TrackerLevel::ALL.iter().map(|l| (l.cache_file(), Mutex::new(()))).collect());
+
+static HTTP: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(10))
+        .timeout(Duration::from_secs(5 * 60))
+        .build()
+        .unwrap_or_default()
+});
+
+fn cache_path(level: TrackerLevel) -> PathBuf {
+    PathBuf::from(std::env::var("CACHE_DIR").unwrap_or_else(|_| "cache".to_string())).join(level.cache_file())
+}
+
+// Stream the gzip to `<path>.part` chunk by chunk, then move it into place
+async fn download(url: &str, path: &Path) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    let timed_out = |e: reqwest::Error, what: &str| {
+        if e.is_timeout() {
+            "Redfin timed out".to_string()
+        } else {
+            format!("{}: {}", what, e)
+        }
+    };
+    let mut response = HTTP.get(url).send().await.map_err(|e| timed_out(e, "Failed to fetch Redfin data"))?;
+    if !response.status().is_success() {
+        return Err(format!("Redfin returned HTTP {}", response.status()));
+    }
+    let part = path.with_extension("gz.part");
+    let mut file = tokio::fs::File::create(&part)
+        .await
+        .map_err(|e| format!("Failed to create {}: {}", part.display(), e))?;
+    while let Some(chunk) = response.chunk().await.map_err(|e| timed_out(e, "Redfin download interrupted"))? {
+        file.write_all(&chunk).await.map_err(|e| format!("Failed to write {}: {}", part.display(), e))?;
+    }
+    file.flush().await.map_err(|e| e.to_string())?;
+    tokio::fs::rename(&part, path)
+        .await
+        .map_err(|e| format!("Failed to move {} into place: {}", part.display(), e))
+}
+
+fn file_age(path: &Path) -> Option<(SystemTime, Duration)> {
+    let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    Some((modified, modified.elapsed().unwrap_or_default()))
+}
+
+// Latest market snapshot for one level: memory, then a fresh disk copy, then a new download
+pub async fn load(level: TrackerLevel) -> Result<std::sync::Arc<TrackerData>, String> {
+    if let Some(data) = cached(level).await {
+        return Ok(data);
+    }
+
+    let _reload = RELOADS[level.cache_file()].lock().await;
+    // Another task may have reloaded while this one waited
+    if let Some(data) = cached(level).await {
+        return Ok(data);
+    }
+    reload(level, false).await
+}
+
+async fn cached(level: TrackerLevel) -> Option<std::sync::Arc<TrackerData>> {
+    let cache = CACHE.read().await;
+    cache.get(level.cache_file()).filter(|e| Instant::now() < e.expires).map(|e| e.data.clone())
+}
+
+// Download (always when `force`, else once the disk copy is old) and reparse one level.
+// Callers hold the level's RELOADS lock, so only one task at a time writes its .part file.
+async fn reload(level: TrackerLevel, force: bool) -> Result<std::sync::Arc<TrackerData>, String> {
+    let path = cache_path(level);
+    let mut stale = false;
+    if force || file_age(&path).is_none_or(|(_, age)| age >= REFRESH_AFTER) {
+        if let Err(e) = download(&level.url(), &path).await {
+            // A forced refresh reports the failure; the cached copy stays in place
+            if force || file_age(&path).is_none() {
+                return Err(e);
+            }
+            eprintln!("{}; using cached {}", e, path.display());
+            stale = true;
+        }
+    }
+
+    let data = read_tracker(path, stale).await?;
+    let ttl = if stale { RETRY_AFTER } else { REFRESH_AFTER };
+    CACHE.write().await.insert(level.cache_file(), CacheEntry { data: data.clone(), expires: Instant::now() + ttl });
+    Ok(data)
+}
+
+// Parse a downloaded tracker off the async runtime
+async fn read_tracker(path: PathBuf, stale: bool) -> Result<std::sync::Arc<TrackerData>, String> {
+    let downloaded = file_age(&path).map_or(SystemTime::now(), |(modified, _)| modified);
+    let records = tokio::task::spawn_blocking(move || parse_gzip_file(&path))
+        .await
+        .map_err(|e| format!("Redfin parse task failed: {}", e))??;
+    Ok(std::sync::Arc::new(TrackerData { records, downloaded, stale }))
+}
+
+// Download and reparse one level now, whatever its age (scheduled refreshes); returns the region count
+pub async fn refresh(level: TrackerLevel) -> Result<usize, String> {
+    let _reload = RELOADS[level.cache_file()].lock().await;
+    Ok(reload(level, true).await?.records.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/redfin_county_sample.tsv.gz");
+
+    fn fixture_records() -> Vec<RedfRecord> {
+        parse_gzip_file(Path::new(FIXTURE)).expect("fixture parses")
+    }
+
+    fn find<'a>(records: &'a [RedfRecord], region: &str) -> &'a RedfRecord {
+        records.iter().find(|r| r.region == region).expect("region present")
+    }
+
+    #[test]
+    fn keeps_latest_all_residential_unadjusted_row_per_region() {
+        let records = fixture_records();
+        assert_eq!(records.len(), 4);
+
+        let travis = find(&records, "Travis County, TX");
+        assert_eq!(travis.period_end, "2024-09-30");
+        assert_eq!(travis.median_dom, Some(48.0));
+        assert_eq!(travis.homes_sold, Some(1502));
+    }
+
+    #[test]
+    fn maps_tracker_columns_onto_record() {
+        let records = fixture_records();
+        let la = find(&records, "Los Angeles County, CA");
+        assert_eq!(la.region_type, "county");
+        assert_eq!(la.state_code, "CA");
+        assert_eq!(la.sale_to_list, Some(1.004));
+        assert_eq!(la.inventory, Some(15230));
+        // price_drops is a fraction in the file
+        assert_eq!(la.price_drop_pct, Some(17.3));
+    }
+
+    #[test]
+    fn blank_and_na_cells_become_none() {
+        let records = fixture_records();
+        let loving = find(&records, "Loving County, TX");
+        assert_eq!(loving.median_dom, None);
+        assert_eq!(loving.inventory, None);
+        assert_eq!(loving.homes_sold, Some(1));
+    }
+
+    #[test]
+    fn region_filter_matches_state_code_or_name() {
+        let records = fixture_records();
+        let texas: Vec<_> = records.iter().filter(|r| matches_region(r, "tx")).collect();
+        assert_eq!(texas.len(), 2);
+        let miami: Vec<_> = records.iter().filter(|r| matches_region(r, "miami")).collect();
+        assert_eq!(miami.len(), 1);
+        assert_eq!(miami[0].region, "Miami-Dade County, FL");
+    }
+
+    #[test]
+    fn missing_required_column_is_an_error() {
+        let tsv = "region\tstate_code\n\"Travis County, TX\"\tTX\n";
+        let err = parse_tracker(tsv.as_bytes()).unwrap_err();
+        assert!(err.contains("period_end"), "{}", err);
+    }
+}
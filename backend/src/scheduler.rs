@@ -0,0 +1,452 @@
+// Scheduler - Background refresh of upstream data on cron schedules
+// Each job runs on its own tokio task, sleeping until the next time its cron expression matches
+// (evaluated in UTC), so the data caches are warm before a user asks. Schedules are overridable
+// per job with CRON_CENSUS / CRON_FRED / CRON_ZHVI / CRON_REDFIN; "off" disables a job.
+// Run history is kept in memory and reported by GET /api/admin/jobs.
+
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, DurationRound, NaiveDate, Timelike, Utc};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+use crate::{census, market_data, redfin, zhvi_history};
+
+// Runs kept per job
+const HISTORY_LEN: usize = 20;
+// A run still going after this is recorded as failed (the Census download is the slowest, ~1 min)
+const JOB_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+// ============================================================================
+// CRON EXPRESSIONS
+// Standard 5 fields: minute hour day-of-month month day-of-week. Each field takes
+// *, a value, a range (1-5), a list (1,15) and a step (*/15, 0-30/10). Day of week is
+// 0-7 with both 0 and 7 meaning Sunday. As in cron, when both day fields are restricted
+// a day matching either one runs. @hourly, @daily, @weekly and @monthly are accepted.
+// ============================================================================
+
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    // "*" in a day field; only restricted day fields take part in the either-day rule
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+fn parse_field(spec: &str, min: u32, max: u32, name: &str) -> Result<u64, String> {
+    let mut bits = 0u64;
+    for part in spec.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step.parse().map_err(|_| format!("Invalid step '{}' in {} field", step, name))?;
+                if step == 0 {
+                    return Err(format!("Step in {} field must be positive", name));
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let value = |v: &str| -> Result<u32, String> {
+            let v: u32 = v.parse().map_err(|_| format!("Invalid value '{}' in {} field", v, name))?;
+            if v < min || v > max {
+                return Err(format!("{} is out of range {}-{} in {} field", v, min, max, name));
+            }
+            Ok(v)
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (value(start)?, value(end)?),
+                // "5/10" means every 10 starting at 5
+                None if step > 1 => (value(range)?, max),
+                None => {
+                    let v = value(range)?;
+                    (v, v)
+                }
+            },
+        };
+        if start > end {
+            return Err(format!("Range {} in {} field runs backwards", range, name));
+        }
+        for v in (start..=end).step_by(step as usize) {
+            bits |= 1 << v;
+        }
+    }
+    Ok(bits)
+}
+
+impl Schedule {
+    pub fn parse(expr: &str) -> Result<Schedule, String> {
+        let expr = match expr.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            other => other,
+        };
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, dom, month, dow] = fields[..] else {
+            return Err(format!("Cron expression '{}' needs 5 fields, found {}", expr, fields.len()));
+        };
+        let mut days_of_week = parse_field(dow, 0, 7, "day-of-week")?;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+        Ok(Schedule {
+            minutes: parse_field(minute, 0, 59, "minute")?,
+            hours: parse_field(hour, 0, 23, "hour")?,
+            days_of_month: parse_field(dom, 1, 31, "day-of-month")?,
+            months: parse_field(month, 1, 12, "month")?,
+            days_of_week,
+            any_day_of_month: dom == "*",
+            any_day_of_week: dow == "*",
+        })
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let dom = self.days_of_month & (1 << date.day()) != 0;
+        let dow = self.days_of_week & (1 << date.weekday().num_days_from_sunday()) != 0;
+        if self.any_day_of_month || self.any_day_of_week {
+            dom && dow
+        } else {
+            dom || dow
+        }
+    }
+
+    // First matching minute strictly after `after`; None if nothing matches within 5 years (e.g. "0 0 31 2 *")
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut t = after.duration_trunc(ChronoDuration::minutes(1)).ok()? + ChronoDuration::minutes(1);
+        let limit = after + ChronoDuration::days(5 * 366);
+        while t < limit {
+            if self.months & (1 << t.month()) == 0 {
+                // Jump to midnight on the 1st of next month
+                let (year, month) = if t.month() == 12 { (t.year() + 1, 1) } else { (t.year(), t.month() + 1) };
+                t = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?.and_utc();
+            } else if !self.matches_day(t.date_naive()) {
+                t = (t.date_naive() + ChronoDuration::days(1)).and_hms_opt(0, 0, 0)?.and_utc();
+            } else if self.hours & (1 << t.hour()) == 0 {
+                t = t.with_minute(0)? + ChronoDuration::hours(1);
+            } else if self.minutes & (1 << t.minute()) == 0 {
+                t += ChronoDuration::minutes(1);
+            } else {
+                return Some(t);
+            }
+        }
+        None
+    }
+}
+
+// ============================================================================
+// JOBS
+// ============================================================================
+
+type JobFuture = Pin<Box<dyn Future<Output = Result<String, String>> + Send>>;
+
+struct Job {
+    name: &'static str,
+    description: &'static str,
+    env_var: &'static str,
+    default_cron: &'static str,
+    run: fn() -> JobFuture,
+}
+
+const JOBS: [Job; 4] = [
+    Job {
+        name: "census",
+        description: "Census ACS county demographics",
+        env_var: "CRON_CENSUS",
+        default_cron: "0 6 * * *",
+        run: run_census,
+    },
+    Job {
+        name: "fred",
+        description: "FRED mortgage rates and economic indicators",
+        env_var: "CRON_FRED",
+        default_cron: "5 * * * 1-5",
+        run: run_fred,
+    },
+    Job {
+        name: "zhvi",
+        description: "Zillow ZHVI county history",
+        env_var: "CRON_ZHVI",
+        // Zillow posts the previous month's values mid-month
+        default_cron: "0 7 18 * *",
+        run: run_zhvi,
+    },
+    Job {
+        name: "redfin",
+        description: "Redfin county and metro market trackers",
+        env_var: "CRON_REDFIN",
+        // Redfin updates its trackers on Wednesdays
+        default_cron: "0 8 * * 3",
+        run: run_redfin,
+    },
+];
+
+fn run_census() -> JobFuture {
+    Box::pin(async {
+        let api_key = std::env::var("CENSUS_API_KEY").ok();
+        let counties = census::refresh_counties(api_key.as_deref()).await?;
+        Ok(format!("{} counties", counties))
+    })
+}
+
+fn run_fred() -> JobFuture {
+    Box::pin(market_data::refresh())
+}
+
+fn run_zhvi() -> JobFuture {
+    Box::pin(async { Ok(format!("{} counties", zhvi_history::refresh().await?)) })
+}
+
+fn run_redfin() -> JobFuture {
+    Box::pin(async {
+        let mut counts = Vec::new();
+        for level in redfin::TrackerLevel::ALL {
+            let regions = redfin::refresh(level).await.map_err(|e| format!("{:?}: {}", level, e))?;
+            counts.push(format!("{} {:?} regions", regions, level).to_lowercase());
+        }
+        Ok(counts.join(", "))
+    })
+}
+
+// ============================================================================
+// RUN HISTORY
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobRun {
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    pub ok: bool,
+    // Summary on success, error on failure
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobStatus {
+    pub name: &'static str,
+    pub description: &'static str,
+    // None when disabled with "off"
+    pub cron: Option<String>,
+    pub next_run: Option<DateTime<Utc>>,
+    pub running: bool,
+    pub runs: u64,
+    pub failures: u64,
+    pub consecutive_failures: u32,
+    pub last_success: Option<DateTime<Utc>>,
+    // Newest first
+    pub recent_runs: Vec<JobRun>,
+}
+
+static STATUS: Lazy<RwLock<Vec<JobStatus>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+async fn update(name: &str, change: impl FnOnce(&mut JobStatus)) {
+    if let Some(status) = STATUS.write().await.iter_mut().find(|s| s.name == name) {
+        change(status);
+    }
+}
+
+// Every job's schedule and recent runs
+pub async fn statuses() -> Vec<JobStatus> {
+    STATUS.read().await.clone()
+}
+
+async fn run_job(job: &Job) {
+    update(job.name, |s| s.running = true).await;
+    let started_at = Utc::now();
+    let clock = Instant::now();
+    let result = match tokio::time::timeout(JOB_TIMEOUT, (job.run)()).await {
+        Ok(result) => result,
+        Err(_) => Err(format!("Timed out after {} minutes", JOB_TIMEOUT.as_secs() / 60)),
+    };
+    let run = JobRun {
+        started_at,
+        finished_at: Utc::now(),
+        duration_ms: clock.elapsed().as_millis() as u64,
+        ok: result.is_ok(),
+        message: result.unwrap_or_else(|e| e),
+    };
+    if run.ok {
+        println!("⏱️  Job {}: {} ({} ms)", job.name, run.message, run.duration_ms);
+    } else {
+        eprintln!("Job {} failed: {}", job.name, run.message);
+    }
+    update(job.name, |s| {
+        s.running = false;
+        s.runs += 1;
+        if run.ok {
+            s.consecutive_failures = 0;
+            s.last_success = Some(run.finished_at);
+        } else {
+            s.failures += 1;
+            s.consecutive_failures += 1;
+        }
+        s.recent_runs.insert(0, run);
+        s.recent_runs.truncate(HISTORY_LEN);
+    })
+    .await;
+}
+
+async fn job_loop(job: &'static Job, schedule: Schedule) {
+    loop {
+        let Some(next) = schedule.next_after(Utc::now()) else {
+            eprintln!("Job {}: schedule never matches; not scheduling", job.name);
+            update(job.name, |s| s.next_run = None).await;
+            return;
+        };
+        update(job.name, |s| s.next_run = Some(next)).await;
+        // Sleep in bounded steps so a clock change can't push a run far past its time
+        while Utc::now() < next {
+            let remaining = (next - Utc::now()).to_std().unwrap_or_default();
+            tokio::time::sleep(remaining.min(Duration::from_secs(3600))).await;
+        }
+        run_job(job).await;
+    }
+}
+
+// Spawn one task per enabled job; panics on an invalid cron expression so a typo fails at startup
+pub fn start() {
+    let mut statuses = Vec::new();
+    let mut enabled = Vec::new();
+    for job in &JOBS {
+        let cron = std::env::var(job.env_var).unwrap_or_else(|_| job.default_cron.to_string());
+        let cron = (!cron.trim().eq_ignore_ascii_case("off")).then_some(cron);
+        if let Some(cron) = &cron {
+            let schedule = Schedule::parse(cron).unwrap_or_else(|e| panic!("Invalid {}: {}", job.env_var, e));
+            enabled.push((job, schedule));
+        }
+        statuses.push(JobStatus {
+            name: job.name,
+            description: job.description,
+            next_run: None,
+            cron,
+            running: false,
+            runs: 0,
+            failures: 0,
+            consecutive_failures: 0,
+            last_success: None,
+            recent_runs: Vec::new(),
+        });
+    }
+    // Nothing else holds the lock yet at startup
+    if let Ok(mut status) = STATUS.try_write() {
+        *status = statuses;
+    }
+    for (job, schedule) in enabled {
+        tokio::spawn(job_loop(job, schedule));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(timestamp: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(timestamp).expect("valid timestamp").with_timezone(&Utc)
+    }
+
+    fn next(expr: &str, after: &str) -> Option<DateTime<Utc>> {
+        Schedule::parse(expr).expect("valid cron").next_after(at(after))
+    }
+
+    #[test]
+    fn default_schedules_parse() {
+        for job in &JOBS {
+            assert!(Schedule::parse(job.default_cron).is_ok(), "{}: {}", job.name, job.default_cron);
+        }
+    }
+
+    #[test]
+    fn census_runs_daily_at_six() {
+        assert_eq!(next("0 6 * * *", "2026-10-17T05:59:30Z"), Some(at("2026-10-17T06:00:00Z")));
+        // Strictly after: a run at 06:00 schedules the next day's
+        assert_eq!(next("0 6 * * *", "2026-10-17T06:00:00Z"), Some(at("2026-10-18T06:00:00Z")));
+    }
+
+    #[test]
+    fn fred_skips_the_weekend() {
+        // Saturday -> Monday 00:05
+        assert_eq!(next("5 * * * 1-5", "2026-10-17T10:00:00Z"), Some(at("2026-10-19T00:05:00Z")));
+        assert_eq!(next("5 * * * 1-5", "2026-10-19T10:05:00Z"), Some(at("2026-10-19T11:05:00Z")));
+    }
+
+    #[test]
+    fn zhvi_runs_monthly_on_the_18th() {
+        assert_eq!(next("0 7 18 * *", "2026-10-17T12:00:00Z"), Some(at("2026-10-18T07:00:00Z")));
+        assert_eq!(next("0 7 18 * *", "2026-10-18T07:00:00Z"), Some(at("2026-11-18T07:00:00Z")));
+        assert_eq!(next("0 7 18 * *", "2026-12-20T00:00:00Z"), Some(at("2027-01-18T07:00:00Z")));
+    }
+
+    #[test]
+    fn redfin_runs_on_wednesdays() {
+        assert_eq!(next("0 8 * * 3", "2026-10-17T10:00:00Z"), Some(at("2026-10-21T08:00:00Z")));
+    }
+
+    #[test]
+    fn aliases_expand_to_standard_fields() {
+        assert_eq!(next("@hourly", "2026-10-17T10:30:00Z"), Some(at("2026-10-17T11:00:00Z")));
+        assert_eq!(next("@daily", "2026-10-17T10:30:00Z"), Some(at("2026-10-18T00:00:00Z")));
+        assert_eq!(next("@weekly", "2026-10-17T10:30:00Z"), Some(at("2026-10-18T00:00:00Z")));
+        assert_eq!(next("@monthly", "2026-10-17T10:30:00Z"), Some(at("2026-11-01T00:00:00Z")));
+    }
+
+    #[test]
+    fn steps_and_ranges() {
+        assert_eq!(next("*/15 * * * *", "2026-10-17T10:07:00Z"), Some(at("2026-10-17T10:15:00Z")));
+        assert_eq!(next("*/15 * * * *", "2026-10-17T10:45:00Z"), Some(at("2026-10-17T11:00:00Z")));
+        // "5/10" starts at 5: :05, :15, ... :55
+        assert_eq!(next("5/10 * * * *", "2026-10-17T10:06:00Z"), Some(at("2026-10-17T10:15:00Z")));
+        assert_eq!(next("5/10 * * * *", "2026-10-17T10:55:00Z"), Some(at("2026-10-17T11:05:00Z")));
+        assert_eq!(next("0 9-17/4 * * *", "2026-10-17T10:00:00Z"), Some(at("2026-10-17T13:00:00Z")));
+        assert_eq!(next("0,30 * * * *", "2026-10-17T10:10:00Z"), Some(at("2026-10-17T10:30:00Z")));
+    }
+
+    #[test]
+    fn sunday_is_both_zero_and_seven() {
+        assert_eq!(next("0 0 * * 7", "2026-10-17T10:00:00Z"), Some(at("2026-10-18T00:00:00Z")));
+        assert_eq!(next("0 0 * * 0", "2026-10-17T10:00:00Z"), Some(at("2026-10-18T00:00:00Z")));
+    }
+
+    #[test]
+    fn restricted_day_fields_match_either_day() {
+        // The 1st of the month or any Monday
+        assert_eq!(next("0 0 1 * 1", "2026-10-17T10:00:00Z"), Some(at("2026-10-19T00:00:00Z")));
+        assert_eq!(next("0 0 1 * 1", "2026-10-26T00:00:00Z"), Some(at("2026-11-01T00:00:00Z")));
+        // With one day field left as *, only the other applies
+        assert_eq!(next("0 0 1 * *", "2026-10-17T10:00:00Z"), Some(at("2026-11-01T00:00:00Z")));
+    }
+
+    #[test]
+    fn leap_day_and_impossible_dates() {
+        assert_eq!(next("0 0 29 2 *", "2026-10-17T10:00:00Z"), Some(at("2028-02-29T00:00:00Z")));
+        assert_eq!(next("0 0 31 2 *", "2026-10-17T10:00:00Z"), None);
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        for expr in [
+            "",
+            "* * *",
+            "* * * * * *",
+            "61 * * * *",
+            "0 24 * * *",
+            "0 0 0 * *",
+            "0 0 * 13 *",
+            "0 0 * * 8",
+            "*/0 * * * *",
+            "5-1 * * * *",
+            "a * * * *",
+            "@yearly",
+        ] {
+            assert!(Schedule::parse(expr).is_err(), "accepted '{}'", expr);
+        }
+    }
+}
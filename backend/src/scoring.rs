@@ -0,0 +1,504 @@
+// Scoring Engine - One configurable county score and tier model
+// Named factors, weights, tier cutoffs and strategy profiles come from config/scoring.json
+// (compiled in), or from the file named by SCORING_CONFIG. Every tier the API returns is produced here.
+
+use std::collections::{BTreeMap, HashMap};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_CONFIG: &str = include_str!("../config/scoring.json");
+
+// Factor names callers supply values under
+pub const POPULATION: &str = "population";
+pub const MEDIAN_INCOME: &str = "median_income";
+pub const MEDIAN_HOME_VALUE: &str = "median_home_value";
+pub const GROWTH_YOY: &str = "growth_yoy";
+pub const DAYS_ON_MARKET: &str = "days_on_market";
+pub const TRANSACTION_VOLUME: &str = "transaction_volume";
+pub const EMPLOYMENT_RATE: &str = "employment_rate";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Factor {
+    pub name: String,
+    pub label: String,
+    pub weight: f64,
+    // Raw value earning no points (or full points when inverse)
+    pub low: f64,
+    // Raw value earning full points (or none when inverse)
+    pub high: f64,
+    #[serde(default)]
+    pub inverse: bool,
+}
+
+impl Factor {
+    // Linear 0..1 position of a raw value between low and high, clamped
+    pub fn normalize(&self, value: f64) -> f64 {
+        let position = ((value - self.low) / (self.high - self.low)).clamp(0.0, 1.0);
+        if self.inverse { 1.0 - position } else { position }
+    }
+
+    // Raw value that normalizes to `normalized`
+    fn raw_for(&self, normalized: f64) -> f64 {
+        let position = if self.inverse { 1.0 - normalized } else { normalized };
+        self.low + position * (self.high - self.low)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TierBand {
+    pub tier: u8,
+    pub min_score: f64,
+    pub name: String,
+    pub action: String,
+    pub recommendation: String,
+}
+
+// Profile used when a request doesn't name one: the top-level factor weights
+pub const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Debug, Deserialize)]
+struct ScoringConfig {
+    version: String,
+    factors: Vec<Factor>,
+    tiers: Vec<TierBand>,
+    #[serde(default)]
+    profiles: BTreeMap<String, ProfileConfig>,
+}
+
+// A strategy's factor set: only the factors it weights are scored
+#[derive(Debug, Deserialize)]
+struct ProfileConfig {
+    description: String,
+    weights: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Profile {
+    pub name: String,
+    pub description: String,
+    pub factors: Vec<Factor>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScoringModel {
+    pub version: String,
+    // Sorted by min_score descending once loaded
+    pub tiers: Vec<TierBand>,
+    // The default profile first, then the configured ones by name
+    pub profiles: Vec<Profile>,
+}
+
+// Raw factor values for one county, keyed by factor name
+pub type FactorValues = HashMap<&'static str, f64>;
+
+// One factor's share of the score
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactorContribution {
+    pub name: String,
+    pub label: String,
+    pub raw: f64,
+    pub normalized: f64,
+    // Weight from the config; max_points is its share of the weights that were scored
+    pub weight: f64,
+    pub points: f64,
+    pub max_points: f64,
+    // Score this factor alone could still add, capped at what the next tier needs
+    pub points_to_next_tier: Option<f64>,
+    // Raw value that would reach the next tier with every other factor unchanged
+    pub value_for_next_tier: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Breakdown {
+    pub factors: Vec<FactorContribution>,
+    pub next_tier: Option<u8>,
+    pub points_to_next_tier: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Score {
+    // 0-100, rescaled over the factors that had values
+    pub score: f64,
+    pub tier: &'static TierBand,
+    pub model_version: &'static str,
+    pub profile: &'static str,
+    pub factors_missing: Vec<String>,
+    pub breakdown: Breakdown,
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+fn validate(config: &ScoringConfig) -> Result<(), String> {
+    let mut errors = Vec::new();
+    if config.version.trim().is_empty() {
+        errors.push("version must not be empty".to_string());
+    }
+    if config.factors.is_empty() {
+        errors.push("at least one factor is required".to_string());
+    }
+    for (i, factor) in config.factors.iter().enumerate() {
+        if config.factors[..i].iter().any(|f| f.name == factor.name) {
+            errors.push(format!("factor '{}' is defined twice", factor.name));
+        }
+        if !factor.weight.is_finite() || factor.weight < 0.0 {
+            errors.push(format!("factor '{}' weight must be 0 or more", factor.name));
+        }
+        if !factor.low.is_finite() || !factor.high.is_finite() || factor.low >= factor.high {
+            errors.push(format!("factor '{}' low must be below high", factor.name));
+        }
+    }
+    if config.factors.iter().map(|f| f.weight).sum::<f64>() <= 0.0 {
+        errors.push("factor weights must not all be 0".to_string());
+    }
+    for (name, profile) in &config.profiles {
+        if name == DEFAULT_PROFILE {
+            errors.push(format!("profile name '{}' is reserved", DEFAULT_PROFILE));
+        }
+        for (factor, weight) in &profile.weights {
+            if !config.factors.iter().any(|f| &f.name == factor) {
+                errors.push(format!("profile '{}' weights unknown factor '{}'", name, factor));
+            }
+            if !weight.is_finite() || *weight < 0.0 {
+                errors.push(format!("profile '{}' weight for '{}' must be 0 or more", name, factor));
+            }
+        }
+        if profile.weights.values().sum::<f64>() <= 0.0 {
+            errors.push(format!("profile '{}' weights must not all be 0", name));
+        }
+    }
+    if config.tiers.is_empty() {
+        errors.push("at least one tier is required".to_string());
+    }
+    for (i, band) in config.tiers.iter().enumerate() {
+        if config.tiers[..i].iter().any(|t| t.tier == band.tier) {
+            errors.push(format!("tier {} is defined twice", band.tier));
+        }
+    }
+    if !config.tiers.iter().any(|t| t.min_score <= 0.0) {
+        errors.push("one tier must have min_score 0 so every score is graded".to_string());
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Invalid scoring config: {}", errors.join("; ")))
+    }
+}
+
+impl ScoringModel {
+    pub fn parse(text: &str) -> Result<ScoringModel, String> {
+        let config: ScoringConfig =
+            serde_json::from_str(text).map_err(|e| format!("Invalid scoring config: {}", e))?;
+        validate(&config)?;
+
+        let mut profiles = vec![Profile {
+            name: DEFAULT_PROFILE.to_string(),
+            description: "Every factor at its default weight".to_string(),
+            factors: config.factors.clone(),
+        }];
+        for (name, profile) in &config.profiles {
+            profiles.push(Profile {
+                name: name.clone(),
+                description: profile.description.clone(),
+                factors: config
+                    .factors
+                    .iter()
+                    .filter_map(|f| profile.weights.get(&f.name).map(|&weight| Factor { weight, ..f.clone() }))
+                    .collect(),
+            });
+        }
+
+        let mut tiers = config.tiers;
+        tiers.sort_by(|a, b| b.min_score.total_cmp(&a.min_score));
+        Ok(ScoringModel { version: config.version, tiers, profiles })
+    }
+
+    pub fn profile(&self, name: &str) -> Result<&Profile, String> {
+        self.profiles.iter().find(|p| p.name == name).ok_or_else(|| {
+            let names: Vec<&str> = self.profiles.iter().map(|p| p.name.as_str()).collect();
+            format!("Unknown scoring profile '{}'; expected one of: {}", name, names.join(", "))
+        })
+    }
+
+    pub fn tier_for(&self, score: f64) -> &TierBand {
+        self.tiers
+            .iter()
+            .find(|t| score >= t.min_score)
+            .unwrap_or_else(|| &self.tiers[self.tiers.len() - 1])
+    }
+
+    // Lowest band above the one a score falls in
+    pub fn next_tier(&self, score: f64) -> Option<&TierBand> {
+        self.tiers.iter().rev().find(|t| t.min_score > score)
+    }
+}
+
+static MODEL: Lazy<ScoringModel> = Lazy::new(|| {
+    let loaded = match std::env::var("SCORING_CONFIG") {
+        Ok(path) => std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read scoring config {}: {}", path, e))
+            .and_then(|text| ScoringModel::parse(&text)),
+        Err(_) => ScoringModel::parse(DEFAULT_CONFIG),
+    };
+    loaded.unwrap_or_else(|e| panic!("{}", e))
+});
+
+pub fn model() -> &'static ScoringModel {
+    &MODEL
+}
+
+pub fn default_profile() -> &'static Profile {
+    &model().profiles[0]
+}
+
+// Score with the default profile
+pub fn score(values: &FactorValues) -> Score {
+    score_with(default_profile(), values)
+}
+
+// Score whatever of the profile's factors the caller has values for; missing ones drop out of the weighting
+pub fn score_with(profile: &'static Profile, values: &FactorValues) -> Score {
+    let model = model();
+    let mut scored = Vec::new();
+    let mut factors_missing = Vec::new();
+    for factor in &profile.factors {
+        match values.get(factor.name.as_str()).filter(|v| v.is_finite()) {
+            Some(&value) => scored.push((factor, value, factor.normalize(value))),
+            None => factors_missing.push(factor.name.clone()),
+        }
+    }
+    let total_weight: f64 = scored.iter().map(|(f, _, _)| f.weight).sum();
+    let share = |factor: &Factor| if total_weight > 0.0 { factor.weight / total_weight * 100.0 } else { 0.0 };
+    let score: f64 = scored.iter().map(|(f, _, n)| n * share(f)).sum();
+
+    let next = model.next_tier(score);
+    let gap = next.map(|t| t.min_score - score);
+    let factors = scored
+        .iter()
+        .map(|&(factor, raw, normalized)| {
+            let max_points = share(factor);
+            let headroom = (1.0 - normalized) * max_points;
+            let value_for_next_tier = gap
+                .filter(|&g| max_points > 0.0 && g <= headroom)
+                .map(|g| round2(factor.raw_for(normalized + g / max_points)));
+            FactorContribution {
+                name: factor.name.clone(),
+                label: factor.label.clone(),
+                raw: round2(raw),
+                normalized: round2(normalized),
+                weight: factor.weight,
+                points: round2(normalized * max_points),
+                max_points: round2(max_points),
+                points_to_next_tier: gap.map(|g| round2(headroom.min(g))),
+                value_for_next_tier,
+            }
+        })
+        .collect();
+
+    Score {
+        score,
+        tier: model.tier_for(score),
+        model_version: &model.version,
+        profile: &profile.name,
+        factors_missing,
+        breakdown: Breakdown {
+            factors,
+            next_tier: next.map(|t| t.tier),
+            points_to_next_tier: gap.map(round2),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn factor(low: f64, high: f64, inverse: bool) -> Factor {
+        Factor { name: "f".to_string(), label: "F".to_string(), weight: 1.0, low, high, inverse }
+    }
+
+    // Bundled config with one field of the JSON replaced
+    fn config_with(pointer: &str, value: serde_json::Value) -> String {
+        let mut config: serde_json::Value = serde_json::from_str(DEFAULT_CONFIG).unwrap();
+        *config.pointer_mut(pointer).expect("pointer exists") = value;
+        config.to_string()
+    }
+
+    #[test]
+    fn bundled_config_parses() {
+        let model = ScoringModel::parse(DEFAULT_CONFIG).expect("bundled config is valid");
+        assert_eq!(model.tiers.len(), 5);
+        assert_eq!(model.profiles[0].name, DEFAULT_PROFILE);
+        assert_eq!(model.profiles[0].factors.len(), 7);
+        // Sorted best band first
+        assert!(model.tiers.windows(2).all(|w| w[0].min_score > w[1].min_score));
+    }
+
+    #[test]
+    fn normalize_is_linear_and_clamped() {
+        let f = factor(0.0, 100.0, false);
+        assert_eq!(f.normalize(25.0), 0.25);
+        assert_eq!(f.normalize(-50.0), 0.0);
+        assert_eq!(f.normalize(250.0), 1.0);
+    }
+
+    #[test]
+    fn inverse_factor_rewards_low_values() {
+        let dom = factor(30.0, 90.0, true);
+        assert_eq!(dom.normalize(30.0), 1.0);
+        assert_eq!(dom.normalize(60.0), 0.5);
+        assert_eq!(dom.normalize(90.0), 0.0);
+        assert_eq!(dom.normalize(10.0), 1.0);
+        assert_eq!(dom.normalize(200.0), 0.0);
+    }
+
+    #[test]
+    fn missing_factors_drop_out_of_the_weighting() {
+        let all_top = score(&HashMap::from([(POPULATION, 500_000.0), (MEDIAN_INCOME, 80_000.0)]));
+        assert_eq!(all_top.score, 100.0);
+        assert_eq!(all_top.factors_missing.len(), 5);
+        assert!(all_top.factors_missing.contains(&TRANSACTION_VOLUME.to_string()));
+
+        let half = score(&HashMap::from([(POPULATION, 250_000.0)]));
+        assert_eq!(half.score, 50.0);
+
+        // Non-finite values count as missing
+        let nan = score(&HashMap::from([(POPULATION, f64::NAN), (MEDIAN_INCOME, 80_000.0)]));
+        assert_eq!(nan.score, 100.0);
+        assert!(nan.factors_missing.contains(&POPULATION.to_string()));
+    }
+
+    #[test]
+    fn tier_boundaries_are_inclusive_minimums() {
+        let model = model();
+        assert_eq!(model.tier_for(100.0).tier, 1);
+        assert_eq!(model.tier_for(85.0).tier, 1);
+        assert_eq!(model.tier_for(84.99).tier, 2);
+        assert_eq!(model.tier_for(70.0).tier, 2);
+        assert_eq!(model.tier_for(50.0).tier, 3);
+        assert_eq!(model.tier_for(30.0).tier, 4);
+        assert_eq!(model.tier_for(29.99).tier, 5);
+        assert_eq!(model.tier_for(0.0).tier, 5);
+        assert_eq!(model.tier_for(-1.0).tier, 5);
+    }
+
+    #[test]
+    fn rejects_invalid_configs() {
+        let cases = [
+            (config_with("/version", serde_json::json!(" ")), "version must not be empty"),
+            (config_with("/factors/0/low", serde_json::json!(600000)), "factor 'population' low must be below high"),
+            (config_with("/factors/1/name", serde_json::json!("population")), "factor 'population' is defined twice"),
+            (config_with("/factors/0/weight", serde_json::json!(-1)), "factor 'population' weight must be 0 or more"),
+            (config_with("/tiers/4/min_score", serde_json::json!(10)), "one tier must have min_score 0"),
+            (
+                config_with("/profiles/deed-flip/weights", serde_json::json!({ "vibes": 10 })),
+                "profile 'deed-flip' weights unknown factor 'vibes'",
+            ),
+        ];
+        for (config, expected) in cases {
+            let err = ScoringModel::parse(&config).unwrap_err();
+            assert!(err.contains(expected), "expected '{}' in '{}'", expected, err);
+        }
+        assert!(ScoringModel::parse("{").unwrap_err().starts_with("Invalid scoring config"));
+    }
+
+    fn contribution<'a>(graded: &'a Score, name: &str) -> &'a FactorContribution {
+        graded.breakdown.factors.iter().find(|f| f.name == name).expect("factor scored")
+    }
+
+    #[test]
+    fn breakdown_caps_each_factor_at_the_gap_to_the_next_tier() {
+        // Population maxed, income and home value at half: 33.33 + 16.67 + 16.67 = 66.67 (tier 3)
+        let graded = score(&HashMap::from([
+            (POPULATION, 500_000.0),
+            (MEDIAN_INCOME, 40_000.0),
+            (MEDIAN_HOME_VALUE, 200_000.0),
+        ]));
+        assert_eq!(graded.tier.tier, 3);
+        assert_eq!(graded.breakdown.next_tier, Some(2));
+        assert_eq!(graded.breakdown.points_to_next_tier, Some(3.33));
+
+        let population = contribution(&graded, POPULATION);
+        assert_eq!(population.max_points, 33.33);
+        assert_eq!(population.points, 33.33);
+        // Already at full points: no headroom
+        assert_eq!(population.points_to_next_tier, Some(0.0));
+        assert_eq!(population.value_for_next_tier, None);
+
+        let income = contribution(&graded, MEDIAN_INCOME);
+        assert_eq!(income.points, 16.67);
+        assert_eq!(income.points_to_next_tier, Some(3.33));
+        // 3.33 more points is 0.1 of the factor's range: 0.6 x 80,000
+        assert_eq!(income.value_for_next_tier, Some(48_000.0));
+        assert_eq!(contribution(&graded, MEDIAN_HOME_VALUE).value_for_next_tier, Some(240_000.0));
+    }
+
+    #[test]
+    fn breakdown_reports_headroom_when_one_factor_cannot_close_the_gap() {
+        // Five equal shares at 0.6: 60 points (tier 3); tier 2 needs 10 more but each factor has only 8 left
+        let graded = score(&HashMap::from([
+            (POPULATION, 300_000.0),
+            (MEDIAN_INCOME, 48_000.0),
+            (MEDIAN_HOME_VALUE, 240_000.0),
+            (TRANSACTION_VOLUME, 6_000.0),
+            (EMPLOYMENT_RATE, 93.6),
+        ]));
+        assert_eq!(graded.tier.tier, 3);
+        assert_eq!(graded.breakdown.points_to_next_tier, Some(10.0));
+        for factor in &graded.breakdown.factors {
+            assert_eq!(factor.points_to_next_tier, Some(8.0));
+            assert_eq!(factor.value_for_next_tier, None);
+        }
+    }
+
+    #[test]
+    fn top_tier_has_no_next_tier() {
+        let graded = score(&HashMap::from([(POPULATION, 500_000.0)]));
+        assert_eq!(graded.tier.tier, 1);
+        assert_eq!(graded.breakdown.next_tier, None);
+        assert_eq!(graded.breakdown.points_to_next_tier, None);
+        assert_eq!(graded.breakdown.factors[0].points_to_next_tier, None);
+    }
+
+    #[test]
+    fn inverse_factor_next_tier_value_is_lower() {
+        // Days on market 60 alone scores 50; 70 points needs 0.7, i.e. 48 days
+        let graded = score(&HashMap::from([(DAYS_ON_MARKET, 60.0)]));
+        assert_eq!(graded.score, 50.0);
+        assert_eq!(contribution(&graded, DAYS_ON_MARKET).value_for_next_tier, Some(48.0));
+    }
+
+    #[test]
+    fn profiles_are_selected_by_name() {
+        let model = model();
+        let names: Vec<&str> = model.profiles.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["default", "buy-and-hold", "deed-flip", "lien-yield"]);
+        assert_eq!(model.profile("lien-yield").unwrap().name, "lien-yield");
+
+        let err = model.profile("flip").unwrap_err();
+        assert_eq!(
+            err,
+            "Unknown scoring profile 'flip'; expected one of: default, buy-and-hold, deed-flip, lien-yield"
+        );
+    }
+
+    #[test]
+    fn profile_scores_only_its_own_factors() {
+        let lien_yield = model().profile("lien-yield").unwrap();
+        let values = HashMap::from([(POPULATION, 500_000.0), (DAYS_ON_MARKET, 90.0)]);
+        let graded = score_with(lien_yield, &values);
+        assert_eq!(graded.profile, "lien-yield");
+        // Days on market isn't in the profile, so its zero points don't count
+        assert_eq!(graded.score, 100.0);
+        assert!(!graded.factors_missing.contains(&DAYS_ON_MARKET.to_string()));
+        assert!(graded.factors_missing.contains(&EMPLOYMENT_RATE.to_string()));
+
+        // Profile weights replace the defaults
+        let deed_flip = model().profile("deed-flip").unwrap();
+        let growth = deed_flip.factors.iter().find(|f| f.name == GROWTH_YOY).unwrap();
+        assert_eq!(growth.weight, 30.0);
+        assert!(deed_flip.factors.iter().all(|f| f.name != POPULATION));
+    }
+}
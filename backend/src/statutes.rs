@@ -0,0 +1,1023 @@
+// State Statutes - Typed tax-sale rules for all 51 jurisdictions
+// Single source for sale type, returns, bidding and redemption; the display strings in
+// STATE_AUCTION_DATA and the auction schedules are rendered from these rules
+
+use serde::Serialize;
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SaleKind {
+    Lien,
+    Deed,
+}
+
+impl SaleKind {
+    // "Lien" / "Deed" as used by /api/state-info
+    pub fn short_label(&self) -> &'static str {
+        match self {
+            SaleKind::Lien => "Lien",
+            SaleKind::Deed => "Deed",
+        }
+    }
+
+    // "Tax Lien" / "Tax Deed" as used by auction schedules
+    pub fn label(&self) -> &'static str {
+        match self {
+            SaleKind::Lien => "Tax Lien",
+            SaleKind::Deed => "Tax Deed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BiddingMethod {
+    // Deed sales: highest cash bid takes the property
+    HighestBid,
+    // Bidders pay over the lien amount; the premium earns no interest
+    Premium { refunded: bool },
+    // Bidders compete down from the ceiling rate; lowest rate wins
+    BidDownRate { ceiling_pct: f64, floor_pct: f64 },
+    // Bidders compete for the smallest undivided interest in the property
+    BidDownOwnership,
+    // Tied bidders are chosen by lot
+    RandomDraw,
+    // Certificates bought from the county at face value
+    OverTheCounter,
+}
+
+impl BiddingMethod {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BiddingMethod::HighestBid => "Highest bidder",
+            BiddingMethod::Premium { .. } => "Premium bidding",
+            BiddingMethod::BidDownRate { .. } => "Bid down interest rate",
+            BiddingMethod::BidDownOwnership => "Bid down ownership percentage",
+            BiddingMethod::RandomDraw => "Random drawing",
+            BiddingMethod::OverTheCounter => "First-come or auction",
+        }
+    }
+}
+
+// Market rate a statutory rate floats on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateIndex {
+    FederalDiscountRate,
+}
+
+impl RateIndex {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RateIndex::FederalDiscountRate => "Federal discount",
+        }
+    }
+
+    // Recent published value, used when a caller doesn't supply the live rate
+    pub fn reference_pct(&self) -> f64 {
+        match self {
+            RateIndex::FederalDiscountRate => 4.0,
+        }
+    }
+}
+
+// Return owed on redemption from `from_month` (months after the sale) onward:
+// a one-time penalty on the lien amount plus simple interest accruing while the step applies
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct RateStep {
+    pub from_month: u32,
+    pub annual_pct: f64,
+    pub penalty_pct: f64,
+}
+
+const fn step(from_month: u32, annual_pct: f64, penalty_pct: f64) -> RateStep {
+    RateStep { from_month, annual_pct, penalty_pct }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TermUnit {
+    Days,
+    Months,
+}
+
+// A fixed (min == max) or ranged duration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Term {
+    pub min: u32,
+    pub max: u32,
+    pub unit: TermUnit,
+}
+
+const fn months(n: u32) -> Term {
+    Term { min: n, max: n, unit: TermUnit::Months }
+}
+
+const fn months_range(min: u32, max: u32) -> Term {
+    Term { min, max, unit: TermUnit::Months }
+}
+
+const fn days(n: u32) -> Term {
+    Term { min: n, max: n, unit: TermUnit::Days }
+}
+
+fn month_label(m: u32) -> String {
+    let plural = |n: u32, unit: &str| if n == 1 { format!("1 {}", unit) } else { format!("{} {}s", n, unit) };
+    match (m / 12, m % 12) {
+        (0, r) => plural(r, "month"),
+        (y, 0) => plural(y, "year"),
+        (y, r) => format!("{} {}", plural(y, "year"), plural(r, "month")),
+    }
+}
+
+impl Term {
+    // Upper bound in whole months (day terms rounded up)
+    pub fn max_months(&self) -> u32 {
+        match self.unit {
+            TermUnit::Days => self.max.div_ceil(30),
+            TermUnit::Months => self.max,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self.unit {
+            TermUnit::Days if self.min == self.max => format!("{} days", self.min),
+            TermUnit::Days => format!("{}-{} days", self.min, self.max),
+            TermUnit::Months if self.min == self.max => month_label(self.min),
+            TermUnit::Months if self.min.is_multiple_of(12) && self.max.is_multiple_of(12) => {
+                format!("{}-{} years", self.min / 12, self.max / 12)
+            }
+            TermUnit::Months => format!("{} - {}", month_label(self.min), month_label(self.max)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RedemptionRule {
+    // Owner may redeem from the buyer for this long after the sale
+    AfterSale { homestead: Term, non_homestead: Term },
+    // The window runs before the sale; the buyer takes title with no redemption
+    BeforeSale { homestead: Term, non_homestead: Term },
+    // Title passes at the sale
+    None,
+    // Ends on a court or procedural event rather than a fixed term
+    CourtProcess { label: &'static str },
+}
+
+const fn after_sale(term: Term) -> RedemptionRule {
+    RedemptionRule::AfterSale { homestead: term, non_homestead: term }
+}
+
+const fn before_sale(term: Term) -> RedemptionRule {
+    RedemptionRule::BeforeSale { homestead: term, non_homestead: term }
+}
+
+fn class_label(homestead: &Term, non_homestead: &Term) -> String {
+    if homestead == non_homestead {
+        homestead.label()
+    } else {
+        format!("{} (non-homestead) / {} (homestead)", non_homestead.label(), homestead.label())
+    }
+}
+
+impl RedemptionRule {
+    pub fn label(&self) -> String {
+        match self {
+            RedemptionRule::AfterSale { homestead, non_homestead } => class_label(homestead, non_homestead),
+            RedemptionRule::BeforeSale { homestead, non_homestead } => {
+                format!("{} before sale", class_label(homestead, non_homestead))
+            }
+            RedemptionRule::None => "None after sale".to_string(),
+            RedemptionRule::CourtProcess { label } => label.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StateStatute {
+    pub state: &'static str,
+    pub sale_kind: SaleKind,
+    pub bidding: BiddingMethod,
+    // Empty = no investor return beyond the property itself
+    pub rate_steps: &'static [RateStep],
+    // When set, each step's annual_pct is a spread over this index
+    pub rate_index: Option<RateIndex>,
+    // Highest rate an investor can earn where it differs from the steps (county maximums)
+    pub max_rate_pct: Option<f64>,
+    // Guaranteed return regardless of redemption timing (FL 5% on bid-down liens)
+    pub min_return_pct: Option<f64>,
+    pub redemption: RedemptionRule,
+    pub notes: &'static str,
+}
+
+// Percentages without trailing zeros: 12.0 -> "12%", 0.25 -> "0.25%"
+fn pct(value: f64) -> String {
+    format!("{}%", value)
+}
+
+impl StateStatute {
+    fn step_label(&self, s: &RateStep) -> String {
+        match (self.rate_index, s.annual_pct > 0.0, s.penalty_pct > 0.0) {
+            (Some(index), _, _) => format!("{} + {}", index.label(), pct(s.annual_pct)),
+            (None, true, true) => format!("{} + {} penalty", pct(s.annual_pct), pct(s.penalty_pct)),
+            (None, true, false) => pct(s.annual_pct),
+            (None, false, _) => format!("{} penalty", pct(s.penalty_pct)),
+        }
+    }
+
+    // Human-readable return, e.g. "16% max (bid down)" or "20% penalty; 30% penalty after 12 months"
+    pub fn rate_label(&self) -> String {
+        let Some(first) = self.rate_steps.first() else {
+            return "N/A".to_string();
+        };
+
+        let mut label = match self.bidding {
+            // A statutory rate above the bid ceiling still applies (SD: 12% accrues, bids start at 10%)
+            BiddingMethod::BidDownRate { ceiling_pct, .. } if first.annual_pct != ceiling_pct => {
+                format!("{} (max {} bid)", pct(first.annual_pct), pct(ceiling_pct))
+            }
+            BiddingMethod::BidDownRate { ceiling_pct, .. } => format!("{} max (bid down)", pct(ceiling_pct)),
+            _ => match self.max_rate_pct {
+                Some(max) if self.rate_steps.len() == 1 => format!("{}-{}", first.annual_pct, pct(max)),
+                _ => self.step_label(first),
+            },
+        };
+        if let Some(min) = self.min_return_pct {
+            label.push_str(&format!(" - {} minimum guaranteed", pct(min)));
+        }
+        for s in &self.rate_steps[1..] {
+            label.push_str(&format!("; {} after {}", self.step_label(s), month_label(s.from_month)));
+        }
+        label
+    }
+}
+
+// ============================================================================
+// STATUTES - All 51 jurisdictions
+// ============================================================================
+
+static STATE_STATUTES: &[StateStatute] = &[
+    // === LIEN STATES (26) ===
+    StateStatute {
+        state: "AL",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: false },
+        rate_steps: &[step(0, 12.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(36)),
+        notes: "Most sales May-June; 12% interest from date of sale",
+    },
+    StateStatute {
+        state: "AZ",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::BidDownRate { ceiling_pct: 16.0, floor_pct: 0.0 },
+        rate_steps: &[step(0, 16.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(36)),
+        notes: "Bid-down process; max 16% simple interest",
+    },
+    StateStatute {
+        state: "CO",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: false },
+        rate_steps: &[step(0, 9.0, 0.0)],
+        rate_index: Some(RateIndex::FederalDiscountRate),
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(36)),
+        notes: "Premium bidding; no premium reimbursement",
+    },
+    StateStatute {
+        state: "CT",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: true },
+        rate_steps: &[step(0, 18.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(6)),
+        notes: "Combined lien/deed format; larger towns only",
+    },
+    StateStatute {
+        state: "DC",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: true },
+        rate_steps: &[step(0, 18.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(6)),
+        notes: "Premium bidding; no interest on overbid",
+    },
+    StateStatute {
+        state: "FL",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::BidDownRate { ceiling_pct: 18.0, floor_pct: 0.0 },
+        rate_steps: &[step(0, 18.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: Some(5.0),
+        redemption: after_sale(months(24)),
+        notes: "Bid-down; guaranteed 5% minimum return",
+    },
+    StateStatute {
+        state: "GA",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: true },
+        rate_steps: &[step(0, 0.0, 20.0), step(12, 0.0, 30.0), step(24, 0.0, 40.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(12)),
+        notes: "20% penalty in year 1; escalates to 30% in year 2 and 40% in year 3",
+    },
+    StateStatute {
+        state: "IL",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::BidDownRate { ceiling_pct: 18.0, floor_pct: 0.0 },
+        rate_steps: &[step(0, 18.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months_range(24, 36)),
+        notes: "Bid-down from 18%; graduated penalty redemption",
+    },
+    StateStatute {
+        state: "IN",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: true },
+        rate_steps: &[step(0, 0.0, 10.0), step(6, 0.0, 15.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(12)),
+        notes: "A/B/C Sales process; 10% penalty within 6 months, 15% after; Commissioner's Sale for county-titled",
+    },
+    StateStatute {
+        state: "IA",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::BidDownOwnership,
+        rate_steps: &[step(0, 24.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(21)),
+        notes: "Bid least undivided ownership interest; highest rate",
+    },
+    StateStatute {
+        state: "KY",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::OverTheCounter,
+        rate_steps: &[step(0, 12.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(12)),
+        notes: "12% interest from date of issuance",
+    },
+    StateStatute {
+        state: "LA",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::BidDownRate { ceiling_pct: 12.0, floor_pct: 0.0 },
+        rate_steps: &[step(0, 12.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(36)),
+        notes: "2024-2025 reform: bid-down interest system replaced 12% + 5% penalty; online now available",
+    },
+    StateStatute {
+        state: "MD",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: true },
+        rate_steps: &[step(0, 18.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: Some(24.0),
+        min_return_pct: None,
+        redemption: after_sale(months(6)),
+        notes: "Statutory 6% but most counties charge 18-24%",
+    },
+    StateStatute {
+        state: "MA",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::BidDownOwnership,
+        rate_steps: &[step(0, 16.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(6)),
+        notes: "Smallest undivided part auction; 16% rate; Land Court petition for collector's deed after 6 months",
+    },
+    StateStatute {
+        state: "MS",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: true },
+        rate_steps: &[step(0, 18.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(24)),
+        notes: "Tax lien state",
+    },
+    StateStatute {
+        state: "MO",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: true },
+        rate_steps: &[step(0, 10.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(24)),
+        notes: "10% interest; 18% penalty each year delinquent",
+    },
+    StateStatute {
+        state: "MT",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: true },
+        rate_steps: &[step(0, 10.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months_range(24, 36)),
+        notes: "5/6 of 1% per month (10% per annum)",
+    },
+    StateStatute {
+        state: "NE",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: true },
+        rate_steps: &[step(0, 14.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(36)),
+        notes: "Undivided interest at 14% per annum",
+    },
+    StateStatute {
+        state: "NH",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::BidDownOwnership,
+        rate_steps: &[step(0, 18.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(24)),
+        notes: "Auction for percentage of undivided interest",
+    },
+    StateStatute {
+        state: "NJ",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::BidDownRate { ceiling_pct: 18.0, floor_pct: 0.0 },
+        rate_steps: &[step(0, 18.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(24)),
+        notes: "Bid-down from 18%; active market",
+    },
+    StateStatute {
+        state: "OK",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::RandomDraw,
+        rate_steps: &[step(0, 8.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(24)),
+        notes: "Multiple bidders decided by random drawing",
+    },
+    StateStatute {
+        state: "RI",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: true },
+        rate_steps: &[step(0, 0.0, 10.0), step(6, 12.0, 10.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(12)),
+        notes: "Collector's Deed; 10% first 6mo, 1%/mo after",
+    },
+    StateStatute {
+        state: "SC",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: true },
+        rate_steps: &[step(0, 0.0, 8.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(12)),
+        notes: "Highest and best bidder wins; 8% penalty rather than interest",
+    },
+    StateStatute {
+        state: "SD",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::BidDownRate { ceiling_pct: 10.0, floor_pct: 0.0 },
+        rate_steps: &[step(0, 12.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months_range(36, 48)),
+        notes: "Bid-down from 10%; 12% statutory",
+    },
+    StateStatute {
+        state: "WV",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: true },
+        rate_steps: &[step(0, 12.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(12)),
+        notes: "Highest bidder at public auction",
+    },
+    StateStatute {
+        state: "WY",
+        sale_kind: SaleKind::Lien,
+        bidding: BiddingMethod::Premium { refunded: true },
+        rate_steps: &[step(0, 15.0, 3.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(48)),
+        notes: "Longest redemption; 15% + 3% penalty + fees",
+    },
+    // === DEED STATES (25) ===
+    StateStatute {
+        state: "AK",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(12)),
+        notes: "Municipal foreclosure; deeded to borough/city if unredeemed",
+    },
+    StateStatute {
+        state: "AR",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(days(30)),
+        notes: "Forfeited to state; limited warranty deed after 30 days",
+    },
+    StateStatute {
+        state: "CA",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: before_sale(months(60)),
+        notes: "Tax Collector's Deed; free of pre-existing encumbrances; 3-year window for some non-residential property",
+    },
+    StateStatute {
+        state: "DE",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[step(0, 0.0, 15.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(days(60)),
+        notes: "Judicial foreclosure; 15% penalty on redemption",
+    },
+    StateStatute {
+        state: "HI",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(12)),
+        notes: "3-year lien before auction; 1-year redemption after sale",
+    },
+    StateStatute {
+        state: "ID",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: before_sale(months(36)),
+        notes: "Tax deed to county after 3 years; then sold at auction",
+    },
+    StateStatute {
+        state: "KS",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: RedemptionRule::CourtProcess { label: "Court judgment" },
+        notes: "Bid off to county; court petition for foreclosure",
+    },
+    StateStatute {
+        state: "ME",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: before_sale(months(18)),
+        notes: "Tax lien mortgage auto-forecloses after 18 months",
+    },
+    StateStatute {
+        state: "MI",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: RedemptionRule::None,
+        notes: "Forfeit lands; auction 3rd Tuesday July; min bid = taxes + FMV",
+    },
+    StateStatute {
+        state: "MN",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: RedemptionRule::None,
+        notes: "Tax-forfeited land auctions; cash or installment",
+    },
+    StateStatute {
+        state: "NV",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: before_sale(months(24)),
+        notes: "Tax deed to Treasurer after 2yr; then auction",
+    },
+    StateStatute {
+        state: "NM",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: RedemptionRule::None,
+        notes: "No owner redemption; IRS may redeem within 120 days; Quitclaim Deed issued",
+    },
+    StateStatute {
+        state: "NY",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: RedemptionRule::BeforeSale { homestead: months_range(36, 48), non_homestead: months(24) },
+        notes: "2yr standard; 3-4yr for residential/farm; judicial foreclosure",
+    },
+    StateStatute {
+        state: "NC",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: RedemptionRule::CourtProcess { label: "Upset bid period" },
+        notes: "Judicial foreclosure or docketing certificate",
+    },
+    StateStatute {
+        state: "ND",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::BidDownRate { ceiling_pct: 9.0, floor_pct: 0.0 },
+        rate_steps: &[step(0, 9.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(48)),
+        notes: "Bid-down from 9%; 4yr redemption from due date",
+    },
+    StateStatute {
+        state: "OH",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: RedemptionRule::None,
+        notes: "Judicial foreclosure after 2yr delinquent; Sheriff's sale",
+    },
+    StateStatute {
+        state: "OR",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: before_sale(months(24)),
+        notes: "Foreclosure after 3yr; sold to county; 2yr redemption",
+    },
+    StateStatute {
+        state: "PA",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: RedemptionRule::None,
+        notes: "Upset Sale; min bid = taxes + interest + costs",
+    },
+    StateStatute {
+        state: "TN",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(12)),
+        notes: "2yr delinquent before Chancery Court suit",
+    },
+    StateStatute {
+        state: "TX",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[step(0, 0.0, 25.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: RedemptionRule::AfterSale { homestead: months(24), non_homestead: months(6) },
+        notes: "6mo non-Homestead; 2yr Homestead/Ag; 25% penalty",
+    },
+    StateStatute {
+        state: "UT",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: before_sale(months(48)),
+        notes: "Preliminary sale Jan 16; final sale May 4yr later",
+    },
+    StateStatute {
+        state: "VT",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[step(0, 12.0, 0.0)],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: after_sale(months(12)),
+        notes: "Redeemable deed; 12% interest if redeemed; Collector's Deed after 1yr redemption",
+    },
+    StateStatute {
+        state: "VA",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: RedemptionRule::CourtProcess { label: "Surplus rights only" },
+        notes: "Judicial foreclosure; 3yr after due date; surplus to former owner",
+    },
+    StateStatute {
+        state: "WA",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: before_sale(months(36)),
+        notes: "Certificate of delinquency after 3yr; foreclosure judgment",
+    },
+    StateStatute {
+        state: "WI",
+        sale_kind: SaleKind::Deed,
+        bidding: BiddingMethod::HighestBid,
+        rate_steps: &[],
+        rate_index: None,
+        max_rate_pct: None,
+        min_return_pct: None,
+        redemption: before_sale(months(24)),
+        notes: "Tax deed after 2yr certificate; county cannot sell certificate",
+    },
+];
+
+pub fn all() -> &'static [StateStatute] {
+    STATE_STATUTES
+}
+
+pub fn get(state: &str) -> Option<&'static StateStatute> {
+    let state = state.to_uppercase();
+    STATE_STATUTES.iter().find(|s| s.state == state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auctions;
+
+    // Schedule columns before the typed statutes: (state, sale_type, interest_rate, redemption_period, bidding_method)
+    const PREVIOUS: [(&str, &str, &str, &str, &str); 51] = [
+        ("AK", "Tax Deed", "N/A", "1 year", "Highest bidder"),
+        ("AL", "Tax Lien", "12%", "3 years", "Premium bidding"),
+        ("AR", "Tax Deed", "N/A", "30 days", "Highest bidder"),
+        ("AZ", "Tax Lien", "16% max (bid down)", "3 years", "Bid down interest rate"),
+        ("CA", "Tax Deed", "N/A", "5 years (pre-sale)", "Highest bidder"),
+        ("CO", "Tax Lien", "Federal discount + 9%", "3 years", "Premium bidding"),
+        ("CT", "Tax Lien", "18%", "6 months", "Premium bidding"),
+        ("DC", "Tax Lien", "18%", "6 months", "Premium bidding"),
+        ("DE", "Tax Deed", "15%", "60 days", "Highest bidder"),
+        ("FL", "Tax Lien", "18% max (bid down) - 5% minimum guaranteed", "2 years", "Bid down interest rate"),
+        ("GA", "Tax Lien", "20% (escalates to 30%, then 40%)", "1 year", "Premium bidding"),
+        ("HI", "Tax Deed", "N/A", "1 year", "Highest bidder"),
+        ("IA", "Tax Lien", "24% (HIGHEST IN US)", "1 year 9 months", "Bid down ownership percentage"),
+        ("ID", "Tax Deed", "N/A", "3 years before deed", "Highest bidder"),
+        ("IL", "Tax Lien", "18% (bid down)", "2-3 years", "Bid down interest rate"),
+        ("IN", "Tax Lien", "10-15% graduated", "1 year", "A/B/C tiered sales"),
+        ("KS", "Tax Deed", "N/A", "Court judgment", "Highest bidder"),
+        ("KY", "Tax Lien", "12%", "1 year", "First-come or auction"),
+        ("LA", "Tax Lien", "Bid-down (2024-2025 reform)", "3 years", "Bid down interest rate (new system)"),
+        ("MA", "Tax Lien", "16%", "Collector's deed", "Bid down ownership percentage"),
+        ("MD", "Tax Lien", "18-24%", "6 months", "Bid down or premium"),
+        ("ME", "Tax Deed", "N/A", "18 months", "Highest bidder"),
+        ("MI", "Tax Deed", "N/A", "None after sale", "Highest bidder"),
+        ("MN", "Tax Deed", "N/A", "None after sale", "Highest bidder"),
+        ("MO", "Tax Lien", "10%", "2 years", "Premium bidding"),
+        ("MS", "Tax Lien", "18%", "2 years", "Premium bidding"),
+        ("MT", "Tax Lien", "10%", "2-3 years", "Premium bidding"),
+        ("NC", "Tax Deed", "N/A", "Upset bid period", "Highest bidder"),
+        ("ND", "Tax Deed", "Max 9%", "4 years", "Bid down interest rate"),
+        ("NE", "Tax Lien", "14%", "3 years", "Premium bidding"),
+        ("NH", "Tax Lien", "18%", "2 years", "Bid down ownership percentage"),
+        ("NJ", "Tax Lien", "18% (bid down)", "2 years", "Bid down interest rate"),
+        ("NM", "Tax Deed", "N/A", "120 days IRS only", "Highest bidder"),
+        ("NV", "Tax Deed", "N/A", "2 years before deed", "Highest bidder"),
+        ("NY", "Tax Deed", "N/A", "2-4 years (varies by property type)", "Highest bidder"),
+        ("OH", "Tax Deed", "N/A", "None after sale", "Highest bidder"),
+        ("OK", "Tax Lien", "8%", "2 years", "Random drawing"),
+        ("OR", "Tax Deed", "N/A", "2 years", "Highest bidder"),
+        ("PA", "Tax Deed", "N/A", "None after upset sale", "Highest bidder"),
+        ("RI", "Tax Lien", "10% + 1%/month", "1 year", "Premium bidding"),
+        ("SC", "Tax Lien", "8% penalty", "1 year", "Highest and best bid"),
+        ("SD", "Tax Lien", "12% (max 10% bid)", "3-4 years", "Bid down interest rate"),
+        ("TN", "Tax Deed", "N/A", "1 year", "Highest bidder"),
+        ("TX", "Tax Deed", "25% penalty on redemption", "6 months (non-homestead) / 2 years (homestead)", "Highest bidder"),
+        ("UT", "Tax Deed", "N/A", "4 years", "Highest bidder"),
+        ("VA", "Tax Deed", "N/A", "Surplus rights only", "Highest bidder"),
+        ("VT", "Tax Lien", "12%", "1 year", "Premium bidding"),
+        ("WA", "Tax Deed", "N/A", "3 years before sale", "Highest bidder"),
+        ("WI", "Tax Deed", "N/A", "2 years", "Highest bidder"),
+        ("WV", "Tax Lien", "12%", "1 year", "Highest bidder"),
+        ("WY", "Tax Lien", "15% + 3% penalty", "4 years", "Premium bidding"),
+    ];
+
+    // Every rendered value that differs from PREVIOUS: (state, column, new value)
+    const CHANGED: &[(&str, &str, &str)] = &[
+        // Statute corrections
+        ("CA", "redemption_period", "5 years before sale"),
+        ("GA", "interest_rate", "20% penalty; 30% penalty after 1 year; 40% penalty after 2 years"),
+        ("IN", "interest_rate", "10% penalty; 15% penalty after 6 months"),
+        ("IN", "bidding_method", "Premium bidding"),
+        ("LA", "interest_rate", "12% max (bid down)"),
+        ("LA", "bidding_method", "Bid down interest rate"),
+        ("MA", "redemption_period", "6 months"),
+        ("NM", "redemption_period", "None after sale"),
+        ("SC", "bidding_method", "Premium bidding"),
+        ("VT", "sale_type", "Tax Deed"),
+        ("VT", "bidding_method", "Highest bidder"),
+        // Same rules, one wording: penalties, bid-down ceilings and pre-sale windows are spelled out
+        ("DE", "interest_rate", "15% penalty"),
+        ("TX", "interest_rate", "25% penalty"),
+        ("RI", "interest_rate", "10% penalty; 12% + 10% penalty after 6 months"),
+        ("IL", "interest_rate", "18% max (bid down)"),
+        ("NJ", "interest_rate", "18% max (bid down)"),
+        ("ND", "interest_rate", "9% max (bid down)"),
+        ("IA", "interest_rate", "24%"),
+        ("ID", "redemption_period", "3 years before sale"),
+        ("NV", "redemption_period", "2 years before sale"),
+        ("OR", "redemption_period", "2 years before sale"),
+        ("UT", "redemption_period", "4 years before sale"),
+        ("WI", "redemption_period", "2 years before sale"),
+        ("ME", "redemption_period", "1 year 6 months before sale"),
+        ("NY", "redemption_period", "2 years (non-homestead) / 3-4 years (homestead) before sale"),
+        ("PA", "redemption_period", "None after sale"),
+        // Lien sales won by outbidding are modeled as premium bidding
+        ("MD", "bidding_method", "Premium bidding"),
+        ("WV", "bidding_method", "Premium bidding"),
+    ];
+
+    #[test]
+    fn covers_all_51_jurisdictions() {
+        let mut states: Vec<&str> = all().iter().map(|s| s.state).collect();
+        states.sort();
+        states.dedup();
+        assert_eq!(states.len(), 51);
+        assert_eq!(states, PREVIOUS.iter().map(|p| p.0).collect::<Vec<_>>());
+
+        assert_eq!(get("fl").unwrap().state, "FL");
+        assert!(get("ZZ").is_none());
+        let liens = all().iter().filter(|s| s.sale_kind == SaleKind::Lien).count();
+        assert_eq!(liens, 26);
+    }
+
+    #[test]
+    fn rules_are_well_formed() {
+        for s in all() {
+            if let Some(first) = s.rate_steps.first() {
+                assert_eq!(first.from_month, 0, "{}", s.state);
+            }
+            assert!(s.rate_steps.windows(2).all(|w| w[0].from_month < w[1].from_month), "{}", s.state);
+            assert!(s.rate_steps.iter().all(|r| r.annual_pct >= 0.0 && r.penalty_pct >= 0.0), "{}", s.state);
+            if let BiddingMethod::BidDownRate { ceiling_pct, floor_pct } = s.bidding {
+                assert!(floor_pct <= ceiling_pct, "{}", s.state);
+            }
+            match s.redemption {
+                RedemptionRule::AfterSale { homestead, non_homestead } | RedemptionRule::BeforeSale { homestead, non_homestead } => {
+                    assert!(homestead.min <= homestead.max && non_homestead.min <= non_homestead.max, "{}", s.state);
+                }
+                RedemptionRule::None | RedemptionRule::CourtProcess { .. } => {}
+            }
+        }
+    }
+
+    #[test]
+    fn every_label_renders() {
+        for s in all() {
+            for label in [s.rate_label(), s.redemption.label(), s.bidding.label().to_string()] {
+                assert!(!label.is_empty() && !label.contains("NaN") && !label.contains("inf"), "{}: {:?}", s.state, label);
+            }
+            assert_eq!(s.rate_steps.is_empty(), s.rate_label() == "N/A", "{}", s.state);
+        }
+        let rate = |state: &str| get(state).unwrap().rate_label();
+        assert_eq!(rate("AL"), "12%");
+        assert_eq!(rate("CO"), "Federal discount + 9%");
+        assert_eq!(rate("FL"), "18% max (bid down) - 5% minimum guaranteed");
+        assert_eq!(rate("MD"), "18-24%");
+        assert_eq!(rate("SD"), "12% (max 10% bid)");
+    }
+
+    #[test]
+    fn term_labels() {
+        assert_eq!(months(1).label(), "1 month");
+        assert_eq!(months(12).label(), "1 year");
+        assert_eq!(months(18).label(), "1 year 6 months");
+        assert_eq!(months(25).label(), "2 years 1 month");
+        assert_eq!(months_range(24, 36).label(), "2-3 years");
+        assert_eq!(months_range(6, 12).label(), "6 months - 1 year");
+        assert_eq!(days(60).label(), "60 days");
+        assert_eq!(Term { min: 60, max: 90, unit: TermUnit::Days }.label(), "60-90 days");
+        assert_eq!(days(60).max_months(), 2);
+        assert_eq!(days(61).max_months(), 3);
+        assert_eq!(pct(0.25), "0.25%");
+
+        let split = RedemptionRule::AfterSale { homestead: months(24), non_homestead: months(6) };
+        assert_eq!(split.label(), "6 months (non-homestead) / 2 years (homestead)");
+        assert_eq!(before_sale(months(36)).label(), "3 years before sale");
+    }
+
+    #[test]
+    fn schedules_match_previous_values_except_listed_changes() {
+        let schedules = auctions::seed_schedules();
+        assert_eq!(schedules.len(), PREVIOUS.len());
+        let mut used = 0;
+        for (s, (state, sale_type, rate, redemption, bidding)) in schedules.iter().zip(PREVIOUS) {
+            assert_eq!(s.state, state);
+            for (column, previous, rendered) in [
+                ("sale_type", sale_type, &s.sale_type),
+                ("interest_rate", rate, &s.interest_rate),
+                ("redemption_period", redemption, &s.redemption_period),
+                ("bidding_method", bidding, &s.bidding_method),
+            ] {
+                let changed = CHANGED.iter().find(|c| c.0 == state && c.1 == column).map(|c| c.2);
+                used += changed.is_some() as usize;
+                assert_eq!(rendered, changed.unwrap_or(previous), "{} {}", state, column);
+            }
+        }
+        assert_eq!(used, CHANGED.len(), "CHANGED lists a column that no longer differs");
+    }
+}
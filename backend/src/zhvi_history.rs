@@ -0,0 +1,352 @@
+// ZHVI History - Full monthly Zillow Home Value Index series per county
+// Parses Zillow's county CSV by header name: region columns, then one column per month-end date.
+// The download is kept in the data cache and revalidated with ETag / Last-Modified instead of refetched.
+
+use chrono::NaiveDate;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use crate::data_cache;
+
+pub const ZILLOW_ZHVI_URL: &str = "https://files.zillowstatic.com/research/public_csvs/zhvi/County_zhvi_uc_sfrcondo_tier_0.33_0.67_sm_sa_month.csv";
+
+// A stalled connection must not hold up every ZHVI-backed request; the full file is tens of MB
+static HTTP: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(10))
+        .timeout(Duration::from_secs(5 * 60))
+        .build()
+        .unwrap_or_default()
+});
+
+// Zillow publishes monthly; checking a few times a day is plenty
+const ZHVI_POLICY: data_cache::Policy = data_cache::Policy {
+    ttl: Duration::from_secs(6 * 3600),
+    stale_while_revalidate: Duration::from_secs(7 * 24 * 3600),
+};
+
+#[derive(Debug, Clone)]
+pub struct CountySeries {
+    pub region_name: String,
+    pub state: String,
+    // As written by Zillow, without zero padding ("6", "37")
+    pub state_fips: String,
+    pub county_fips: String,
+    // One entry per ZhviHistory::dates column; None where Zillow has no value
+    pub values: Vec<Option<f64>>,
+}
+
+impl CountySeries {
+    // 5-digit county FIPS code
+    pub fn fips(&self) -> String {
+        format!("{:0>2}{:0>3}", self.state_fips, self.county_fips)
+    }
+
+    pub fn value_at(&self, month: usize) -> Option<f64> {
+        self.values.get(month).copied().flatten()
+    }
+
+    // Percent change over `months` ending at `month`
+    pub fn change_pct(&self, month: usize, months: usize) -> Option<f64> {
+        let start = self.value_at(month.checked_sub(months)?)?;
+        let end = self.value_at(month)?;
+        Some((end / start - 1.0) * 100.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ZhviHistory {
+    pub dates: Vec<NaiveDate>,
+    pub counties: Vec<CountySeries>,
+}
+
+impl ZhviHistory {
+    // Column of the last month on or before `date`
+    pub fn month_index(&self, date: NaiveDate) -> Option<usize> {
+        self.dates.iter().rposition(|d| *d <= date)
+    }
+
+    pub fn latest_month(&self) -> Option<usize> {
+        self.dates.len().checked_sub(1)
+    }
+}
+
+pub fn parse(csv_text: &str) -> Result<ZhviHistory, String> {
+    let mut reader = csv::Reader::from_reader(csv_text.as_bytes());
+    let headers = reader.headers().map_err(|e| format!("Invalid ZHVI CSV header: {}", e))?.clone();
+    let column = |name: &str| {
+        headers
+            .iter()
+            .position(|h| h == name)
+            .ok_or_else(|| format!("ZHVI CSV is missing the {} column", name))
+    };
+    let name_col = column("RegionName")?;
+    let state_col = column("State")?;
+    let state_fips_col = column("StateCodeFIPS")?;
+    let county_fips_col = column("MunicipalCodeFIPS")?;
+
+    let date_cols: Vec<(usize, NaiveDate)> = headers
+        .iter()
+        .enumerate()
+        .filter_map(|(i, h)| NaiveDate::parse_from_str(h, "%Y-%m-%d").ok().map(|d| (i, d)))
+        .collect();
+    if date_cols.is_empty() {
+        return Err("ZHVI CSV has no monthly value columns".to_string());
+    }
+
+    let counties = reader
+        .records()
+        .flatten()
+        .filter_map(|record| {
+            let state = record.get(state_col).unwrap_or("").trim().to_string();
+            if state.is_empty() {
+                return None;
+            }
+            Some(CountySeries {
+                region_name: record.get(name_col).unwrap_or("").to_string(),
+                state,
+                state_fips: record.get(state_fips_col).unwrap_or("").to_string(),
+                county_fips: record.get(county_fips_col).unwrap_or("").to_string(),
+                values: date_cols
+                    .iter()
+                    .map(|(i, _)| record.get(*i).and_then(|v| v.parse::<f64>().ok()).filter(|v| *v > 0.0))
+                    .collect(),
+            })
+        })
+        .collect();
+
+    Ok(ZhviHistory {
+        dates: date_cols.into_iter().map(|(_, d)| d).collect(),
+        counties,
+    })
+}
+
+// ============================================================================
+// DISK CACHE
+// ============================================================================
+
+// The raw CSV plus the validators Zillow sent with it
+#[derive(Debug, Serialize, Deserialize)]
+struct ZhviDownload {
+    csv: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ZhviSnapshot {
+    pub history: Arc<ZhviHistory>,
+    pub fetched_at: String,
+    // True when Zillow couldn't be reached and the last good copy was served
+    pub stale: bool,
+}
+
+// A cached download and its parsed form, so a revalidated copy isn't reparsed
+type ParsedDownload = (Arc<ZhviDownload>, Arc<ZhviHistory>);
+
+static PARSED: Lazy<RwLock<Option<ParsedDownload>>> = Lazy::new(|| RwLock::new(None));
+
+// Conditional GET against Zillow; a 304 keeps the previous download
+async fn revalidate(previous: Option<Arc<ZhviDownload>>) -> Result<Arc<ZhviDownload>, String> {
+    use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+
+    let mut request = HTTP.get(ZILLOW_ZHVI_URL);
+    if let Some(previous) = &previous {
+        if let Some(etag) = &previous.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(modified) = &previous.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, modified);
+        }
+    }
+    let response = request.send().await.map_err(|e| {
+        if e.is_timeout() {
+            "Zillow timed out".to_string()
+        } else {
+            format!("Failed to fetch Zillow data: {}", e)
+        }
+    })?;
+    if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+        return previous.ok_or_else(|| "Zillow answered 304 without a cached copy".to_string());
+    }
+    if !response.status().is_success() {
+        return Err(format!("Zillow returned HTTP {}", response.status()));
+    }
+    let header = |name| response.headers().get(name).and_then(|v| v.to_str().ok()).map(str::to_string);
+    let (etag, last_modified) = (header(ETAG), header(LAST_MODIFIED));
+    let csv = response.text().await.map_err(|e| {
+        if e.is_timeout() {
+            "Zillow timed out".to_string()
+        } else {
+            format!("Failed to read Zillow response: {}", e)
+        }
+    })?;
+    // Reject a broken download here so it never replaces the last good copy
+    parse(&csv)?;
+    Ok(Arc::new(ZhviDownload { csv, etag, last_modified }))
+}
+
+// Revalidate against Zillow now (scheduled refreshes); returns the county count
+pub async fn refresh() -> Result<usize, String> {
+    data_cache::shared().refresh("zillow", "zhvi-county", revalidate).await?;
+    // load() picks up the refreshed copy from memory and memoizes its parse
+    Ok(load().await?.history.counties.len())
+}
+
+// Parsed county history from the data cache, revalidated against Zillow every few hours
+pub async fn load() -> Result<ZhviSnapshot, String> {
+    let cached = data_cache::shared()
+        .get_or_fetch("zillow", "zhvi-county", ZHVI_POLICY, revalidate)
+        .await?;
+    let snapshot = |history| ZhviSnapshot { history, fetched_at: cached.fetched_at.to_rfc3339(), stale: cached.stale };
+
+    {
+        let parsed = PARSED.read().await;
+        if let Some((_, history)) = parsed.as_ref().filter(|(download, _)| Arc::ptr_eq(download, &cached.value)) {
+            return Ok(snapshot(history.clone()));
+        }
+    }
+    let history = Arc::new(parse(&cached.value.csv)?);
+    *PARSED.write().await = Some((cached.value.clone(), history.clone()));
+    Ok(snapshot(history))
+}
+
+// ============================================================================
+// SERIES METRICS
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SeriesPoint {
+    pub date: String,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SeriesMetrics {
+    pub cagr_1y_pct: Option<f64>,
+    pub cagr_3y_pct: Option<f64>,
+    pub cagr_5y_pct: Option<f64>,
+    pub peak: Option<SeriesPoint>,
+    // Latest value relative to the all-time peak (0 at a new high)
+    pub drawdown_from_peak_pct: Option<f64>,
+    // Deepest peak-to-trough fall anywhere in the series
+    pub max_drawdown_pct: Option<f64>,
+    // Annualized standard deviation of monthly changes over the last 5 years
+    pub volatility_pct: Option<f64>,
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+pub fn metrics(history: &ZhviHistory, county: &CountySeries) -> SeriesMetrics {
+    let latest = county.values.iter().rposition(Option::is_some);
+    let cagr = |years: usize| {
+        let month = latest?;
+        let start = county.value_at(month.checked_sub(years * 12)?)?;
+        let end = county.value_at(month)?;
+        Some(round2(((end / start).powf(1.0 / years as f64) - 1.0) * 100.0))
+    };
+
+    let mut peak: Option<(usize, f64)> = None;
+    let mut max_drawdown: Option<f64> = None;
+    for (i, value) in county.values.iter().enumerate() {
+        let Some(value) = *value else { continue };
+        if peak.is_none_or(|(_, p)| value > p) {
+            peak = Some((i, value));
+        }
+        let drawdown = peak.map_or(0.0, |(_, p)| (value / p - 1.0) * 100.0);
+        max_drawdown = Some(max_drawdown.map_or(drawdown, |d: f64| d.min(drawdown)));
+    }
+    let drawdown_from_peak = match (latest.and_then(|m| county.value_at(m)), peak) {
+        (Some(value), Some((_, p))) => Some(round2((value / p - 1.0) * 100.0)),
+        _ => None,
+    };
+
+    let monthly_changes: Vec<f64> = latest
+        .map(|month| {
+            (month.saturating_sub(60) + 1..=month)
+                .filter_map(|m| county.change_pct(m, 1))
+                .collect()
+        })
+        .unwrap_or_default();
+    let volatility = (monthly_changes.len() >= 12).then(|| {
+        let n = monthly_changes.len() as f64;
+        let mean = monthly_changes.iter().sum::<f64>() / n;
+        let variance = monthly_changes.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        round2(variance.sqrt() * 12f64.sqrt())
+    });
+
+    SeriesMetrics {
+        cagr_1y_pct: cagr(1),
+        cagr_3y_pct: cagr(3),
+        cagr_5y_pct: cagr(5),
+        peak: peak.map(|(i, value)| SeriesPoint { date: history.dates[i].to_string(), value }),
+        drawdown_from_peak_pct: drawdown_from_peak,
+        max_drawdown_pct: max_drawdown.map(round2),
+        volatility_pct: volatility,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../fixtures/zhvi_county_sample.csv");
+
+    fn county<'a>(history: &'a ZhviHistory, fips: &str) -> &'a CountySeries {
+        history.counties.iter().find(|c| c.fips() == fips).expect("county present")
+    }
+
+    #[test]
+    fn parses_fixture_by_header() {
+        let history = parse(FIXTURE).expect("fixture parses");
+        assert_eq!(history.counties.len(), 37);
+        assert_eq!(history.dates.len(), 132);
+        assert_eq!(history.dates[0], NaiveDate::from_ymd_opt(2014, 1, 31).unwrap());
+        let la = county(&history, "06037");
+        assert_eq!(la.region_name, "Los Angeles County");
+        assert_eq!(la.state, "CA");
+    }
+
+    #[test]
+    fn metrics_at_a_new_high() {
+        let history = parse(FIXTURE).unwrap();
+        let m = metrics(&history, county(&history, "06037"));
+        assert_eq!(m.cagr_1y_pct, Some(8.46));
+        assert_eq!(m.cagr_3y_pct, Some(5.52));
+        assert_eq!(m.cagr_5y_pct, Some(7.57));
+        let peak = m.peak.expect("peak");
+        assert_eq!(peak.date, "2024-12-31");
+        assert_eq!(m.drawdown_from_peak_pct, Some(0.0));
+        assert_eq!(m.max_drawdown_pct, Some(-2.61));
+        assert_eq!(m.volatility_pct, Some(1.78));
+    }
+
+    #[test]
+    fn metrics_below_an_earlier_peak() {
+        let history = parse(FIXTURE).unwrap();
+        let m = metrics(&history, county(&history, "06059"));
+        assert_eq!(m.cagr_1y_pct, Some(0.01));
+        assert_eq!(m.peak.expect("peak").date, "2024-03-31");
+        assert_eq!(m.drawdown_from_peak_pct, Some(-0.77));
+        assert_eq!(m.max_drawdown_pct, Some(-1.02));
+        assert_eq!(m.volatility_pct, Some(1.65));
+    }
+
+    #[test]
+    fn short_series_has_no_long_cagr_or_volatility() {
+        let history = parse(FIXTURE).unwrap();
+        let mut series = county(&history, "06037").clone();
+        // Keep only the last 6 months
+        let keep_from = series.values.len() - 6;
+        series.values.iter_mut().take(keep_from).for_each(|v| *v = None);
+        let m = metrics(&history, &series);
+        assert_eq!(m.cagr_1y_pct, None);
+        assert_eq!(m.volatility_pct, None);
+        assert!(m.peak.is_some());
+    }
+}